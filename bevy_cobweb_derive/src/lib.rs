@@ -14,7 +14,7 @@ pub fn derive_react_component(input: TokenStream) -> TokenStream
 
 //-------------------------------------------------------------------------------------------------------------------
 
-#[proc_macro_derive(ReactResource)]
+#[proc_macro_derive(ReactResource, attributes(react))]
 pub fn derive_react_resource(input: TokenStream) -> TokenStream
 {
     react::derive_react_resource_impl(input)
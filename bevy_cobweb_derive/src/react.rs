@@ -4,8 +4,8 @@
 
 //standard shortcuts
 use proc_macro::TokenStream;
-use quote::quote;
-use syn::{parse_macro_input, parse_quote, DeriveInput};
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, parse_quote, Data, DeriveInput, Fields};
 
 //-------------------------------------------------------------------------------------------------------------------
 
@@ -26,6 +26,73 @@ pub(crate) fn derive_react_component_impl(input: TokenStream) -> TokenStream
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Returns `true` if the input has a `#[react(track_fields)]` attribute.
+fn has_track_fields_attr(ast: &DeriveInput) -> syn::Result<bool>
+{
+    let mut track_fields = false;
+    for attr in ast.attrs.iter().filter(|attr| attr.path().is_ident("react"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("track_fields")
+            {
+                track_fields = true;
+                return Ok(());
+            }
+            Err(meta.error("unsupported react attribute, expected `track_fields`"))
+        })?;
+    }
+    Ok(track_fields)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Generates the `TrackFields` impl and the `{Name}Fields` mask constants.
+fn track_fields_impl(ast: &DeriveInput) -> syn::Result<proc_macro2::TokenStream>
+{
+    let Data::Struct(data) = &ast.data
+    else { return Err(syn::Error::new_spanned(&ast.ident, "track_fields is only supported on structs")); };
+    let field_names: Vec<_> = match &data.fields
+    {
+        Fields::Named(fields) => fields.named
+            .iter()
+            .map(|field| format_ident!("{}", field.ident.as_ref().unwrap().to_string().to_uppercase()))
+            .collect(),
+        Fields::Unnamed(fields) => (0..fields.unnamed.len()).map(|i| format_ident!("_{}", i)).collect(),
+        Fields::Unit => Vec::default(),
+    };
+    if field_names.len() > 64
+    {
+        return Err(syn::Error::new_spanned(&ast.ident, "track_fields supports at most 64 fields"));
+    }
+
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let vis = &ast.vis;
+    let struct_name = &ast.ident;
+    let fields_name = format_ident!("{}Fields", struct_name);
+    let field_count = field_names.len() as u32;
+    let bits = (0..field_names.len()).map(|i| 1u64 << i);
+    let doc = format!("[`FieldMask`] constants for the fields of [`{}`].", struct_name);
+
+    Ok(quote! {
+        impl #impl_generics TrackFields for #struct_name #ty_generics #where_clause
+        {
+            const FIELD_COUNT: u32 = #field_count;
+        }
+
+        #[doc = #doc]
+        #vis struct #fields_name;
+
+        #[allow(dead_code)]
+        impl #fields_name
+        {
+            #( pub const #field_names: FieldMask = FieldMask::from_bits(#bits); )*
+            pub const ALL: FieldMask = FieldMask::first(#field_count);
+        }
+    })
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 pub(crate) fn derive_react_resource_impl(input: TokenStream) -> TokenStream
 {
     let mut ast = parse_macro_input!(input as DeriveInput);
@@ -33,11 +100,23 @@ pub(crate) fn derive_react_resource_impl(input: TokenStream) -> TokenStream
         .make_where_clause()
         .predicates
         .push(parse_quote! { Self: Send + Sync + 'static });
+    let track_fields = match has_track_fields_attr(&ast)
+    {
+        Ok(true) => match track_fields_impl(&ast)
+        {
+            Ok(tokens) => tokens,
+            Err(err) => return err.to_compile_error().into(),
+        },
+        Ok(false) => Default::default(),
+        Err(err) => return err.to_compile_error().into(),
+    };
     let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
     let struct_name = &ast.ident;
 
     TokenStream::from(quote! {
         impl #impl_generics ReactResource for #struct_name #ty_generics #where_clause {}
+
+        #track_fields
     })
 }
 
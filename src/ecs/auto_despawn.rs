@@ -2,10 +2,11 @@
 
 //third-party shortcuts
 use bevy::prelude::*;
+use bevy::utils::HashMap;
 use crossbeam::channel::{Receiver, Sender};
 
 //standard shortcuts
-use std::sync::Arc;
+use std::sync::{Arc, Mutex, Weak};
 
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
@@ -28,11 +29,41 @@ impl Drop for AutoDespawnSignalInner
 //-------------------------------------------------------------------------------------------------------------------
 
 /// Drains [`AutoDespawner`] and recursively despawns entities that were auto-despawned.
+///
+/// See [`AutoDespawner::process_despawns`].
 pub fn garbage_collect_entities(world: &mut World)
 {
-    while let Some(entity) = world.resource::<AutoDespawner>().try_recv()
+    let despawner = world.resource::<AutoDespawner>().clone();
+    despawner.process_despawns(world);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Callback that runs after an auto-despawned entity is despawned.
+type DespawnCallback = Box<dyn FnOnce(&mut World) + Send + Sync + 'static>;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Weak references to all signals created by an [`AutoDespawner`], for leak reports.
+#[derive(Default)]
+struct SignalRegistry
+{
+    signals: Vec<Weak<AutoDespawnSignalInner>>,
+    /// Dead signals are pruned when the registry reaches this length, so its size stays proportional to the number of
+    /// live signals.
+    prune_at: usize,
+}
+
+impl SignalRegistry
+{
+    fn push(&mut self, signal: &Arc<AutoDespawnSignalInner>)
     {
-        world.get_entity_mut(entity).ok().map(|e| e.despawn_recursive());
+        if self.signals.len() >= self.prune_at
+        {
+            self.signals.retain(|signal| signal.strong_count() > 0);
+            self.prune_at = (self.signals.len() * 2).max(64);
+        }
+        self.signals.push(Arc::downgrade(signal));
     }
 }
 
@@ -44,6 +75,10 @@ pub struct AutoDespawner
 {
     sender: Sender<Entity>,
     receiver: Receiver<Entity>,
+    /// Despawn callbacks keyed by entity (shared between clones).
+    callbacks: Arc<Mutex<HashMap<Entity, Vec<DespawnCallback>>>>,
+    /// All signals created by this despawner (shared between clones).
+    signals: Arc<Mutex<SignalRegistry>>,
 }
 
 impl AutoDespawner
@@ -51,7 +86,7 @@ impl AutoDespawner
     fn new() -> Self
     {
         let (sender, receiver) = crossbeam::channel::unbounded();
-        Self{ sender, receiver }
+        Self{ sender, receiver, callbacks: Arc::default(), signals: Arc::default() }
     }
 
     /// Prepare an entity to be automatically despawned.
@@ -59,7 +94,86 @@ impl AutoDespawner
     /// When the last copy of the returned signal is dropped, the entity will be despawned in the `Last` schedule.
     pub fn prepare(&self, entity: Entity) -> AutoDespawnSignal
     {
-        AutoDespawnSignal::new(entity, self.sender.clone())
+        let signal = AutoDespawnSignal::new(entity, self.sender.clone());
+        self.signals.lock().unwrap().push(&signal.0);
+        signal
+    }
+
+    /// Prepare an entity to be automatically despawned, with a callback that runs after it is despawned.
+    ///
+    /// When the last copy of the returned signal is dropped, the entity will be despawned and then `on_despawn` will
+    /// run (in the same pass, e.g. in the `Last` schedule). The callback runs even if the entity was already
+    /// despawned by other means.
+    ///
+    /// Callbacks are stored by entity, so if an entity is prepared multiple times then all of its callbacks will run
+    /// the first time it is auto-despawned.
+    pub fn prepare_with_callback(
+        &self,
+        entity     : Entity,
+        on_despawn : impl FnOnce(&mut World) + Send + Sync + 'static
+    ) -> AutoDespawnSignal
+    {
+        self.callbacks.lock().unwrap().entry(entity).or_default().push(Box::new(on_despawn));
+        self.prepare(entity)
+    }
+
+    /// Lists every entity with at least one live [`AutoDespawnSignal`], along with the number of live signal handles
+    /// (including clones) for that entity.
+    ///
+    /// Use this to find signals that aren't being dropped (e.g. reactor handles or ref-counted system commands that
+    /// keep an entity alive). Entities are sorted.
+    ///
+    /// This walks a registry of weak references to every signal this despawner has created and locks it, so it costs
+    /// `O(n)` in the number of signals. It is intended for debugging.
+    pub fn outstanding(&self) -> Vec<(Entity, usize)>
+    {
+        let mut counts = HashMap::<Entity, usize>::default();
+        for signal in self.signals.lock().unwrap().signals.iter()
+        {
+            let Some(signal) = signal.upgrade() else { continue; };
+            // Don't count the upgraded reference.
+            *counts.entry(signal.entity).or_default() += Arc::strong_count(&signal) - 1;
+        }
+
+        let mut outstanding: Vec<(Entity, usize)> = counts.into_iter().collect();
+        outstanding.sort_unstable();
+        outstanding
+    }
+
+    /// Recursively despawns entities whose signals were dropped, then runs their despawn callbacks.
+    ///
+    /// This is what the [`AutoDespawnSet`] system does in `Last`, and can be called manually in tests and tools that
+    /// don't run the full schedule. Since the despawner is usually a resource in the world it's processing, clone it
+    /// first (clones share the same queue of pending entities):
+    /// ```no_run
+    /// let despawner = world.resource::<AutoDespawner>().clone();
+    /// let despawned = despawner.process_despawns(world);
+    /// ```
+    ///
+    /// The queue is drained until empty, including entities whose signals are dropped by despawn callbacks or by the
+    /// despawns themselves. Calling this repeatedly is safe; it does nothing if the queue is empty.
+    ///
+    /// Returns the number of entities despawned. Entities that were already despawned by other means are not
+    /// counted, but their callbacks still run.
+    pub fn process_despawns(&self, world: &mut World) -> usize
+    {
+        let mut count = 0;
+
+        while let Some(entity) = self.try_recv()
+        {
+            if let Ok(entity_mut) = world.get_entity_mut(entity)
+            {
+                entity_mut.despawn_recursive();
+                count += 1;
+            }
+
+            for callback in self.take_callbacks(entity)
+            {
+                (callback)(world);
+            }
+        }
+
+        count
     }
 
     /// Removes one pending despawned entity.
@@ -67,6 +181,12 @@ impl AutoDespawner
     {
          self.receiver.try_recv().ok()
     }
+
+    /// Removes the despawn callbacks for an entity.
+    fn take_callbacks(&self, entity: Entity) -> Vec<DespawnCallback>
+    {
+        self.callbacks.lock().unwrap().remove(&entity).unwrap_or_default()
+    }
 }
 
 //-------------------------------------------------------------------------------------------------------------------
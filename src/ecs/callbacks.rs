@@ -264,10 +264,20 @@ where
         CallbackSystem::New(Box::new(IntoSystem::into_system(system)))
     }
 
+    /// Initializes the system without running it.
+    ///
+    /// Does nothing if the system is already initialized.
     pub fn initialize(&mut self, world: &mut World)
     {
-        let CallbackSystem::New(system) = self else { return; };
-        system.initialize(world);
+        *self = match std::mem::take(self)
+        {
+            CallbackSystem::New(mut system) =>
+            {
+                system.initialize(world);
+                CallbackSystem::Initialized(system)
+            }
+            other => other,
+        };
     }
 
     pub fn run(&mut self, world: &mut World, input: <I as SystemInput>::Inner<'_>) -> Option<O>
@@ -380,10 +390,20 @@ where
         RawCallbackSystem::New(IntoSystem::into_system(system))
     }
 
+    /// Initializes the system without running it.
+    ///
+    /// Does nothing if the system is already initialized.
     pub fn initialize(&mut self, world: &mut World)
     {
-        let RawCallbackSystem::New(system) = self else { return; };
-        system.initialize(world);
+        *self = match std::mem::take(self)
+        {
+            RawCallbackSystem::New(mut system) =>
+            {
+                system.initialize(world);
+                RawCallbackSystem::Initialized(system)
+            }
+            other => other,
+        };
     }
 
     pub fn run(&mut self, world: &mut World, input: <I as SystemInput>::Inner<'_>) -> O
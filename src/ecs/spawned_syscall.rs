@@ -6,6 +6,7 @@ use bevy::prelude::*;
 
 //standard shortcuts
 use std::hash::Hash;
+use std::marker::PhantomData;
 
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
@@ -53,12 +54,103 @@ impl SysId
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// System identifier for referencing spawned systems with a known input and output type.
+///
+/// Returned by [`spawn_system`] and [`SpawnedSyscallCommandsExt::spawn_system`]. Calling [`spawned_syscall`] with a
+/// `SysIdTyped<I, O>` infers `I` and `O` from the id, so passing the wrong input type is a compile error instead of a
+/// logged runtime failure.
+///
+/// Use [`Self::untyped`] (or `SysId::from`) to get a [`SysId`] for dynamic cases (e.g. storing ids of systems with
+/// different signatures together). Code that stored the result of `spawn_system` as a [`SysId`] can migrate by
+/// calling `.untyped()`.
+///
+/*
+```rust
+let mut world = World::new();
+let sys_id = spawn_system(&mut world, |In(input): In<u16>| input);
+
+// compile error: wrong input type
+let _ = spawned_syscall(&mut world, sys_id, "hello");
+```
+*/
+pub struct SysIdTyped<I, O>
+{
+    id       : SysId,
+    _phantom : PhantomData<fn(I) -> O>,
+}
+
+impl<I, O> SysIdTyped<I, O>
+{
+    /// Makes a typed id for a spawned system.
+    ///
+    /// It is the responsibility of the caller to match the entity with the target system signature.
+    pub fn new(entity: Entity) -> Self
+    {
+        Self{ id: SysId::new(entity), _phantom: PhantomData }
+    }
+
+    pub fn entity(&self) -> Entity
+    {
+        self.id.entity()
+    }
+
+    /// Gets the untyped [`SysId`].
+    pub fn untyped(&self) -> SysId
+    {
+        self.id
+    }
+}
+
+impl<I, O> Clone for SysIdTyped<I, O> { fn clone(&self) -> Self { *self } }
+impl<I, O> Copy for SysIdTyped<I, O> {}
+impl<I, O> PartialEq for SysIdTyped<I, O> { fn eq(&self, other: &Self) -> bool { self.id == other.id } }
+impl<I, O> Eq for SysIdTyped<I, O> {}
+impl<I, O> Hash for SysIdTyped<I, O> { fn hash<H: std::hash::Hasher>(&self, state: &mut H) { self.id.hash(state); } }
+
+impl<I, O> std::fmt::Debug for SysIdTyped<I, O>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        f.debug_tuple("SysIdTyped").field(&self.id.0).finish()
+    }
+}
+
+impl<I, O> From<SysIdTyped<I, O>> for SysId
+{
+    fn from(id: SysIdTyped<I, O>) -> Self
+    {
+        id.id
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Trait for system ids that can be used to call a spawned system with input `I` and output `O`.
+///
+/// [`SysId`] can be used with any `I` and `O`, while [`SysIdTyped`] can only be used with its own.
+pub trait IntoSysId<I, O>
+{
+    fn into_sys_id(self) -> SysId;
+}
+
+impl<I, O> IntoSysId<I, O> for SysId
+{
+    fn into_sys_id(self) -> SysId { self }
+}
+
+impl<I, O> IntoSysId<I, O> for SysIdTyped<I, O>
+{
+    fn into_sys_id(self) -> SysId { self.id }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// Spawn a system as an entity.
 ///
 /// Systems are not initialized until they are first run.
 ///
 /// The system can be invoked by calling [`spawned_syscall()`].
-pub fn spawn_system<I, O, S, Marker>(world: &mut World, system: S) -> SysId
+pub fn spawn_system<I, O, S, Marker>(world: &mut World, system: S) -> SysIdTyped<I, O>
 where
     I: Send + Sync + SystemInput + 'static,
     O: Send + Sync + 'static,
@@ -70,12 +162,12 @@ where
 /// Spawn a system as an entity.
 ///
 /// The system can be invoked by calling [`spawned_syscall()`].
-pub fn spawn_system_from<I, O>(world: &mut World, system: CallbackSystem<I, O>) -> SysId
+pub fn spawn_system_from<I, O>(world: &mut World, system: CallbackSystem<I, O>) -> SysIdTyped<I, O>
 where
     I: Send + Sync + SystemInput + 'static,
     O: Send + Sync + 'static,
 {
-    SysId::new(world.spawn(SpawnedSystem::new(system)).id())
+    SysIdTyped::new(world.spawn(SpawnedSystem::new(system)).id())
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -107,7 +199,20 @@ where
     O: Send + Sync + 'static,
 {
     let sys_id = spawn_system_from(world, system);
-    world.resource::<AutoDespawner>().prepare(sys_id.0)
+    world.resource::<AutoDespawner>().prepare(sys_id.entity())
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Despawn a spawned system.
+///
+/// Returns `false` if the system does not exist.
+pub fn despawn_system(world: &mut World, sys_id: impl Into<SysId>) -> bool
+{
+    let sys_id = sys_id.into();
+    let Ok(entity_mut) = world.get_entity_mut(sys_id.0) else { return false; };
+    entity_mut.despawn();
+    true
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -116,6 +221,9 @@ where
 ///
 /// Returns `Err` if the system does not exist or if the system was called recursively.
 ///
+/// When called with a [`SysIdTyped`], `I` and `O` are inferred from the id. When called with an untyped [`SysId`], it
+/// is the responsibility of the caller to match the system's signature.
+///
 /// # Example
 ///
 /// ```
@@ -129,8 +237,8 @@ where
 /// }
 ///
 /// let mut world = World::new();
-/// let sys_id1 = spawn_system(test_system);
-/// let sys_id2 = spawn_system(test_system);
+/// let sys_id1 = spawn_system(&mut world, test_system);
+/// let sys_id2 = spawn_system(&mut world, test_system);
 ///
 /// assert_eq!(spawned_syscall(&mut world, sys_id1, 1u16), 1);
 /// assert_eq!(spawned_syscall(&mut world, sys_id1, 1u16), 2);    //Local is preserved
@@ -138,11 +246,17 @@ where
 /// assert_eq!(spawned_syscall(&mut world, sys_id2, 10u16), 20);
 /// ```
 ///
-pub fn spawned_syscall<I, O>(world: &mut World, sys_id: SysId, input: <I as SystemInput>::Inner<'_>) -> Result<O, ()>
+pub fn spawned_syscall<I, O>(
+    world  : &mut World,
+    sys_id : impl IntoSysId<I, O>,
+    input  : <I as SystemInput>::Inner<'_>
+) -> Result<O, ()>
 where
     I: Send + Sync + SystemInput + 'static, <I as SystemInput>::Inner<'static>: Send,
     O: Send + Sync + 'static,
 {
+    let sys_id = sys_id.into_sys_id();
+
     // extract the callback
     let Ok(mut entity_mut) = world.get_entity_mut(sys_id.0) else { return Err(()); };
     let Some(mut spawned_system) = entity_mut.get_mut::<SpawnedSystem<I, O>>()
@@ -172,7 +286,7 @@ pub trait SpawnedSyscallCommandsExt
     ///
     /// Returns the system id that will eventually reference the spawned system. It can be used to invoke the system with
     /// [`spawned_syscall()`] or [`SpawnedSyscallCommandsExt::spawned_syscall()`].
-    fn spawn_system<I, O, S, Marker>(&mut self, system: S) -> SysId
+    fn spawn_system<I, O, S, Marker>(&mut self, system: S) -> SysIdTyped<I, O>
     where
         I: Send + Sync + SystemInput + 'static,
         O: Send + Sync + 'static,
@@ -182,7 +296,7 @@ pub trait SpawnedSyscallCommandsExt
     ///
     /// Returns the system id that will eventually reference the spawned system. It can be used to invoke the system with
     /// [`spawned_syscall()`] or [`SpawnedSyscallCommandsExt::spawned_syscall()`].
-    fn spawn_system_from<I, O>(&mut self, system: CallbackSystem<I, O>) -> SysId
+    fn spawn_system_from<I, O>(&mut self, system: CallbackSystem<I, O>) -> SysIdTyped<I, O>
     where
         I: Send + Sync + SystemInput + 'static,
         O: Send + Sync + 'static;
@@ -201,19 +315,29 @@ pub trait SpawnedSyscallCommandsExt
 
     /// Schedule a spawned system call.
     ///
-    /// It is the responsibility of the caller to correctly match the system entity with the target system signature.
+    /// With an untyped [`SysId`], it is the responsibility of the caller to correctly match the system entity with the
+    /// target system signature.
     ///
     /// Logs a warning if the system entity doesn't exist.
     ///
     /// Syntax sugar for [`spawned_syscall()`].
-    fn spawned_syscall<I>(&mut self, sys_id: SysId, input: <I as bevy::prelude::SystemInput>::Inner<'static>)
+    fn spawned_syscall<I>(
+        &mut self,
+        sys_id: impl IntoSysId<I, ()>,
+        input: <I as bevy::prelude::SystemInput>::Inner<'static>
+    )
     where
         I: Send + Sync + SystemInput + 'static, <I as SystemInput>::Inner<'static>: Send;
+
+    /// Schedule a spawned system to be despawned.
+    ///
+    /// Syntax sugar for [`despawn_system()`].
+    fn despawn_system(&mut self, sys_id: impl Into<SysId>);
 }
 
 impl<'w, 's> SpawnedSyscallCommandsExt for Commands<'w, 's>
 {
-    fn spawn_system<I, O, S, Marker>(&mut self, system: S) -> SysId
+    fn spawn_system<I, O, S, Marker>(&mut self, system: S) -> SysIdTyped<I, O>
     where
         I: Send + Sync + SystemInput + 'static,
         O: Send + Sync + 'static,
@@ -222,12 +346,12 @@ impl<'w, 's> SpawnedSyscallCommandsExt for Commands<'w, 's>
         self.spawn_system_from(CallbackSystem::new(system))
     }
 
-    fn spawn_system_from<I, O>(&mut self, system: CallbackSystem<I, O>) -> SysId
+    fn spawn_system_from<I, O>(&mut self, system: CallbackSystem<I, O>) -> SysIdTyped<I, O>
     where
         I: Send + Sync + SystemInput + 'static,
         O: Send + Sync + 'static
     {
-        SysId::new(self.spawn(SpawnedSystem::new(system)).id())
+        SysIdTyped::new(self.spawn(SpawnedSystem::new(system)).id())
     }
 
     fn insert_system<I, O, S, Marker>(&mut self, entity: Entity, system: S) -> Result<(), ()>
@@ -242,10 +366,11 @@ impl<'w, 's> SpawnedSyscallCommandsExt for Commands<'w, 's>
         Ok(())
     }
 
-    fn spawned_syscall<I>(&mut self, sys_id: SysId, input: <I as SystemInput>::Inner<'static>)
+    fn spawned_syscall<I>(&mut self, sys_id: impl IntoSysId<I, ()>, input: <I as SystemInput>::Inner<'static>)
     where
         I: Send + Sync + SystemInput + 'static, <I as SystemInput>::Inner<'static>: Send
     {
+        let sys_id = sys_id.into_sys_id();
         self.queue(
                 move |world: &mut World|
                 {
@@ -257,6 +382,12 @@ impl<'w, 's> SpawnedSyscallCommandsExt for Commands<'w, 's>
 
             );
     }
+
+    fn despawn_system(&mut self, sys_id: impl Into<SysId>)
+    {
+        let sys_id = sys_id.into();
+        self.queue(move |world: &mut World| { despawn_system(world, sys_id); });
+    }
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -1,12 +1,13 @@
 //local shortcuts
-use crate::prelude::CobwebResult;
+use crate::prelude::{despawn_system, CobwebResult, SysId};
 
 //third-party shortcuts
-use bevy::ecs::system::{BoxedSystem, EntityCommands};
+use bevy::ecs::system::{BoxedSystem, EntityCommands, SystemParam, SystemParamItem, SystemState};
 use bevy::prelude::*;
 
 //standard shortcuts
 use std::marker::PhantomData;
+use std::time::{Duration, Instant};
 
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
@@ -116,6 +117,181 @@ where
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Same as [`syscall`] except the wall-clock time spent running the system is returned alongside its output.
+///
+/// This is a thin wrapper: the cached initialized system is reused exactly like in [`syscall`], and the timer only
+/// wraps the call to `system.sys.run` (which includes applying the system's deferred commands). Initializing the
+/// system on first use is not included in the measurement.
+pub fn syscall_timed<I, O, S, Marker>(
+    world: &mut World,
+    input: <I as SystemInput>::Inner<'_>,
+    system: S
+) -> (O, Duration)
+where
+    I: Send + Sync + SystemInput + 'static,
+    O: Send + Sync + 'static,
+    S: IntoSystem<I, O, Marker> + Send + Sync + 'static,
+{
+    // get the initialized system
+    let mut system =
+        match world.remove_resource::<InitializedSystem<I, O, S>>()
+        {
+            Some(system) => system,
+            None =>
+            {
+                let mut sys = IntoSystem::into_system(system);
+                sys.initialize(world);
+                InitializedSystem::<I, O, S>{ sys: Box::new(sys), _phantom: PhantomData::<S>{} }
+            }
+        };
+
+    // run the system
+    // - This automatically calls `apply_deferred`.
+    let start = Instant::now();
+    let result = system.sys.run(input, world);
+    let elapsed = start.elapsed();
+
+    // put the system back
+    world.insert_resource(system);
+
+    (result, elapsed)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Same as [`syscall`] except the resource `Rsc` is temporarily replaced with `temp` while the system runs.
+///
+/// The previous value of `Rsc` is removed before the system runs and reinserted afterward. If `Rsc` did not exist
+/// before, then it is removed afterward. The final value of `temp` is dropped.
+///
+/// This is useful for testing systems (or reactors they trigger) under a specific resource value.
+pub fn syscall_with_resource<Rsc, I, O, S, Marker>(
+    world: &mut World,
+    temp: Rsc,
+    input: <I as SystemInput>::Inner<'_>,
+    system: S
+) -> O
+where
+    Rsc: Resource,
+    I: Send + Sync + SystemInput + 'static,
+    O: Send + Sync + 'static,
+    S: IntoSystem<I, O, Marker> + Send + Sync + 'static,
+{
+    // swap in the temporary resource
+    let prev = world.remove_resource::<Rsc>();
+    world.insert_resource(temp);
+
+    // run the system
+    let result = syscall(world, input, system);
+
+    // restore the previous state
+    match prev
+    {
+        Some(prev) => world.insert_resource(prev),
+        None => { world.remove_resource::<Rsc>(); }
+    }
+
+    result
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Resource)]
+struct InitializedSystemState<P: SystemParam + 'static>
+{
+    state: SystemState<P>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Borrows the system parameters `P` from the world for the duration of `f`, then applies their deferred commands.
+///
+/// This is for reading data out of the world without cloning it. A system run by [`syscall`] can't return a borrow
+/// into the world, because a system's output type is fixed independent of the lifetimes of its parameters (and the
+/// world is mutably borrowed again to apply deferred commands after the system runs). Instead, `f` receives the
+/// parameters themselves, and anything borrowed from them must be consumed before `f` returns. Only the owned
+/// result `R` escapes.
+///
+/// The parameter state is cached in the world, keyed by `P`. All calls with the same `P` share the same `Local`s and
+/// change ticks.
+///
+/*
+```rust
+// sum the lengths of all names without cloning them
+// - `P` can't be inferred from the callback, so it must be specified.
+let total = world.syscall_scoped::<Query<&Name>, _, _>((), |(), names| names.iter().map(|n| n.len()).sum::<usize>());
+```
+*/
+///
+/// ## WARNING
+/// If a call is made recursively with the same `P`, the state of all but the outer-most invocation will not persist.
+pub fn syscall_scoped<P, I, R>(world: &mut World, input: I, f: impl FnOnce(I, SystemParamItem<P>) -> R) -> R
+where
+    P: SystemParam + 'static,
+{
+    // get the initialized state
+    let mut state = world
+        .remove_resource::<InitializedSystemState<P>>()
+        .unwrap_or_else(|| InitializedSystemState{ state: SystemState::new(world) });
+
+    // run the callback
+    // - The borrowed params are dropped before deferred commands are applied.
+    let result = (f)(input, state.state.get_mut(world));
+    state.state.apply(world);
+
+    // put the state back
+    world.insert_resource(state);
+
+    result
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A system that is initialized once and stored by the caller for repeated calls.
+///
+/// Unlike [`syscall`], which caches systems in a world resource keyed by the system type and looks the system up on
+/// every call, a `CachedSystem` owns its initialized system directly. Calls have no lookup overhead, captured data is
+/// fine, and each `CachedSystem` has its own `Local`/state even if made from the same system as another one.
+///
+/// The system must only be run in the world it was made with.
+///
+/*
+```rust
+let mut counter = CachedSystem::new(&mut world, |In(n): In<u16>, mut local: Local<u16>| { *local += n; *local });
+assert_eq!(counter.run(&mut world, 1), 1);
+assert_eq!(counter.run(&mut world, 1), 2);  //Local is preserved
+```
+*/
+pub struct CachedSystem<I: SystemInput = (), O = ()>
+{
+    sys: BoxedSystem<I, O>,
+}
+
+impl<I, O> CachedSystem<I, O>
+where
+    I: Send + Sync + SystemInput + 'static,
+    O: Send + Sync + 'static,
+{
+    /// Makes a new cached system, initializing it in `world`.
+    pub fn new<S, Marker>(world: &mut World, system: S) -> Self
+    where
+        S: IntoSystem<I, O, Marker> + Send + Sync + 'static,
+    {
+        let mut sys = IntoSystem::into_system(system);
+        sys.initialize(world);
+        Self{ sys: Box::new(sys) }
+    }
+
+    /// Executes the system on some data then applies the system's deferred commands.
+    pub fn run(&mut self, world: &mut World, input: <I as SystemInput>::Inner<'_>) -> O
+    {
+        // - This automatically calls `apply_deferred`.
+        self.sys.run(input, world)
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// Wraps a `Fn` system in a system that consumes the system input.
 ///
 /// This is intended to wrap `Fn` systems. Do not use it if you have a `FnOnce` callback, for example when
@@ -156,6 +332,31 @@ pub trait WorldSyscallExt
         O: Send + Sync + 'static,
         S: IntoSystem<I, O, Marker> + Send + Sync + 'static;
 
+    /// See [`syscall_timed`].
+    fn syscall_timed<I, O, S, Marker>(&mut self, input: <I as SystemInput>::Inner<'static>, system: S) -> (O, Duration)
+    where
+        I: Send + Sync + SystemInput + 'static,
+        O: Send + Sync + 'static,
+        S: IntoSystem<I, O, Marker> + Send + Sync + 'static;
+
+    /// See [`syscall_with_resource`].
+    fn syscall_with_resource<Rsc, I, O, S, Marker>(
+        &mut self,
+        temp: Rsc,
+        input: <I as SystemInput>::Inner<'static>,
+        system: S
+    ) -> O
+    where
+        Rsc: Resource,
+        I: Send + Sync + SystemInput + 'static,
+        O: Send + Sync + 'static,
+        S: IntoSystem<I, O, Marker> + Send + Sync + 'static;
+
+    /// See [`syscall_scoped`].
+    fn syscall_scoped<P, I, R>(&mut self, input: I, f: impl FnOnce(I, SystemParamItem<P>) -> R) -> R
+    where
+        P: SystemParam + 'static;
+
     /// Similar to [`syscall`] except the system is not cached for reuse.
     fn syscall_once<I, O, S, Marker>(&mut self, input: <I as SystemInput>::Inner<'static>, system: S) -> O
     where
@@ -174,6 +375,9 @@ pub trait WorldSyscallExt
         I: Send + Sync + SystemInput + 'static,
         O: Send + Sync + 'static,
         S: IntoSystem<I, O, Marker> + Send + Sync + 'static;
+
+    /// See [`despawn_system`].
+    fn despawn_system(&mut self, sys_id: impl Into<SysId>) -> bool;
 }
 
 impl WorldSyscallExt for World
@@ -201,6 +405,37 @@ impl WorldSyscallExt for World
         syscall_with_validation(self, input, system, validation)
     }
 
+    fn syscall_timed<I, O, S, Marker>(&mut self, input: <I as SystemInput>::Inner<'static>, system: S) -> (O, Duration)
+    where
+        I: Send + Sync + SystemInput + 'static,
+        O: Send + Sync + 'static,
+        S: IntoSystem<I, O, Marker> + Send + Sync + 'static
+    {
+        syscall_timed(self, input, system)
+    }
+
+    fn syscall_with_resource<Rsc, I, O, S, Marker>(
+        &mut self,
+        temp: Rsc,
+        input: <I as SystemInput>::Inner<'static>,
+        system: S
+    ) -> O
+    where
+        Rsc: Resource,
+        I: Send + Sync + SystemInput + 'static,
+        O: Send + Sync + 'static,
+        S: IntoSystem<I, O, Marker> + Send + Sync + 'static
+    {
+        syscall_with_resource(self, temp, input, system)
+    }
+
+    fn syscall_scoped<P, I, R>(&mut self, input: I, f: impl FnOnce(I, SystemParamItem<P>) -> R) -> R
+    where
+        P: SystemParam + 'static
+    {
+        syscall_scoped(self, input, f)
+    }
+
     fn syscall_once<I, O, S, Marker>(&mut self, input: <I as SystemInput>::Inner<'static>, system: S) -> O
     where
         I: Send + Sync + SystemInput + 'static,
@@ -228,6 +463,11 @@ impl WorldSyscallExt for World
         sys.initialize(self);
         sys.run(input, self)
     }
+
+    fn despawn_system(&mut self, sys_id: impl Into<SysId>) -> bool
+    {
+        despawn_system(self, sys_id)
+    }
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,78 @@
+//local shortcuts
+use crate::prelude::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+use bevy::tasks::{block_on, Task};
+
+//standard shortcuts
+
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Sends the output of a finished async reaction to its apply system command.
+pub(crate) type AsyncReactionApply = Box<dyn FnOnce(&mut Commands) + Send + Sync + 'static>;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Tracks in-flight tasks of reactors registered with [`ReactCommands::on_async`].
+#[derive(Resource, Default)]
+pub(crate) struct AsyncReactionTasks
+{
+    /// Tasks and the system commands that apply their outputs, in spawn order.
+    tasks: Vec<(SystemCommand, Task<AsyncReactionApply>)>,
+}
+
+impl AsyncReactionTasks
+{
+    /// Adds a task whose output will be applied by `apply`.
+    pub(crate) fn push(&mut self, apply: SystemCommand, task: Task<AsyncReactionApply>)
+    {
+        self.tasks.push((apply, task));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Sends the outputs of finished async reaction tasks to their apply system commands.
+///
+/// Tasks whose apply system command was despawned (e.g. because the reactor was revoked) are dropped, which cancels
+/// them. Entities are garbage collected first so revoked reactors are not missed.
+pub(crate) fn poll_async_reactions(world: &mut World)
+{
+    garbage_collect_entities(world);
+
+    let mut finished = Vec::default();
+    world.resource_scope(|world: &mut World, mut tasks: Mut<AsyncReactionTasks>|
+    {
+        let entities = world.entities();
+        let mut idx = 0;
+        while idx < tasks.tasks.len()
+        {
+            let (apply, task) = &tasks.tasks[idx];
+            if !entities.contains(**apply)
+            {
+                // Dropping the task cancels it.
+                let _ = tasks.tasks.remove(idx);
+                continue;
+            }
+            if !task.is_finished()
+            {
+                idx += 1;
+                continue;
+            }
+
+            let (_, task) = tasks.tasks.remove(idx);
+            finished.push(block_on(task));
+        }
+    });
+
+    let mut commands = world.commands();
+    for apply in finished
+    {
+        (apply)(&mut commands);
+    }
+    world.flush();
+}
+
+//-------------------------------------------------------------------------------------------------------------------
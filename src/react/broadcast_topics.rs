@@ -0,0 +1,155 @@
+//local shortcuts
+use crate::prelude::*;
+
+//third-party shortcuts
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+//standard shortcuts
+use core::any::{type_name, Any, TypeId};
+use std::marker::PhantomData;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Marker trait for broadcast topics.
+///
+/// A topic groups broadcast event types so one reactor can respond to all of them. Reactors subscribe to a topic with
+/// [`broadcast_topic`], and read events with [`TopicEvent`].
+///
+/// Event types join a topic by implementing [`TopicMember`] and being registered with
+/// [`ReactCommands::add_topic_member`].
+pub trait BroadcastTopic: Send + Sync + 'static {}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Declares that broadcast event type `Self` is a member of topic `T`.
+///
+/// Membership must also be registered at runtime with [`ReactCommands::add_topic_member`], because
+/// [`ReactCommands::broadcast`] can't see this trait for arbitrary event types. After registration, every broadcast of
+/// `Self` on the default channel also triggers reactors registered with [`broadcast_topic::<T>()`](broadcast_topic).
+///
+/*
+```rust
+struct Input;
+impl BroadcastTopic for Input {}
+
+struct Click(Vec2);
+impl TopicMember<Input> for Click {}
+
+struct KeyPress(KeyCode);
+impl TopicMember<Input> for KeyPress {}
+
+app.react(|rc| {
+    rc.add_topic_member::<Input, Click>();
+    rc.add_topic_member::<Input, KeyPress>();
+});
+```
+*/
+pub trait TopicMember<T: BroadcastTopic>: Send + Sync + 'static {}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Stores the data of a broadcast event that has topic reactors.
+///
+/// The event is type-erased so [`TopicEvent`] can read it without knowing its type. [`BroadcastEvent`] falls back to
+/// this component when the event's data entity has no typed data.
+#[derive(Component)]
+pub(crate) struct TopicEventData
+{
+    event: Box<dyn Any + Send + Sync>,
+    type_name: &'static str,
+}
+
+impl TopicEventData
+{
+    /// Makes new topic event data.
+    pub(crate) fn new<E: Send + Sync + 'static>(event: E) -> Self
+    {
+        Self{ event: Box::new(event), type_name: type_name::<E>() }
+    }
+
+    /// Reads the event if it has type `E`.
+    pub(crate) fn downcast<E: Send + Sync + 'static>(&self) -> Option<&E>
+    {
+        self.event.downcast_ref::<E>()
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Registers a topic membership.
+pub(crate) fn add_topic_member<T: BroadcastTopic, E: TopicMember<T>>(mut cache: ResMut<ReactCache>)
+{
+    cache.add_topic_member(TypeId::of::<T>(), TypeId::of::<E>());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// System parameter for reading broadcast events of topic `T`.
+///
+/// Can only be used within [`SystemCommands`](super::SystemCommand).
+///
+/// Use [`broadcast_topic`] to make a trigger that will read these events. The event can be any member of `T`, so it
+/// must be read with [`Self::downcast`].
+///
+/*
+```rust
+fn example(mut c: Commands)
+{
+    c.react().on(
+        broadcast_topic::<Input>(),
+        |event: TopicEvent<Input>|
+        {
+            if let Some(click) = event.downcast::<Click>() { println!("click at {:?}", click.0); }
+            if let Some(key) = event.downcast::<KeyPress>() { println!("key {:?}", key.0); }
+        }
+    );
+}
+```
+*/
+#[derive(SystemParam)]
+pub struct TopicEvent<'w, 's, T: BroadcastTopic>
+{
+    tracker: Res<'w, EventAccessTracker>,
+    data: Query<'w, 's, &'static TopicEventData>,
+    p: PhantomData<T>,
+}
+
+impl<'w, 's, T: BroadcastTopic> TopicEvent<'w, 's, T>
+{
+    fn data(&self) -> Option<&TopicEventData>
+    {
+        if !self.tracker.is_reacting() { return None; }
+        self.data.get(self.tracker.data_entity()).ok()
+    }
+
+    /// Reads the event if it has type `E`.
+    ///
+    /// Returns `None` if there is no event or the event has a different type.
+    pub fn downcast<E: TopicMember<T>>(&self) -> Option<&E>
+    {
+        self.data()?.downcast::<E>()
+    }
+
+    /// Returns `true` if the event has type `E`.
+    pub fn is<E: TopicMember<T>>(&self) -> bool
+    {
+        self.downcast::<E>().is_some()
+    }
+
+    /// Returns the type name of the event.
+    ///
+    /// Returns `None` if there is no event.
+    pub fn event_type_name(&self) -> Option<&'static str>
+    {
+        self.data().map(|data| data.type_name)
+    }
+
+    /// Returns `true` if there is nothing to read.
+    pub fn is_empty(&self) -> bool
+    {
+        self.data().is_none()
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
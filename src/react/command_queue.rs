@@ -34,6 +34,12 @@ impl<T: Send + Sync + 'static> CobwebCommandQueue<T>
         self.commands.push_back(command);
     }
 
+    /// Returns `true` if there are no queued commands.
+    pub(crate) fn is_empty(&self) -> bool
+    {
+        self.commands.is_empty()
+    }
+
     /// Removes a command from the front of the queue.
     pub(crate) fn pop_front(&mut self) -> Option<T>
     {
@@ -11,7 +11,7 @@ use std::any::TypeId;
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
 
-fn try_cleanup_data_entity(world: &mut World, entity: Entity)
+pub(crate) fn try_cleanup_data_entity(world: &mut World, entity: Entity)
 {
     let Some(mut counter) = world.get_mut::<DataEntityCounter>(entity) else { return };
     counter.decrement();
@@ -23,9 +23,9 @@ fn try_cleanup_data_entity(world: &mut World, entity: Entity)
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
 
-fn start_system_event(world: &mut World, system: SystemCommand)
+fn start_system_event(world: &mut World, system: SystemCommand, version: ReactionVersion)
 {
-    world.resource_mut::<SystemEventAccessTracker>().start(system);
+    world.resource_mut::<SystemEventAccessTracker>().start(system, version);
 }
 
 fn end_system_event(world: &mut World)
@@ -37,9 +37,9 @@ fn end_system_event(world: &mut World)
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
 
-fn start_entity_reaction(world: &mut World, reactor: SystemCommand)
+fn start_entity_reaction(world: &mut World, reactor: SystemCommand, version: ReactionVersion)
 {
-    world.resource_mut::<EntityReactionAccessTracker>().start(reactor);
+    world.resource_mut::<EntityReactionAccessTracker>().start(reactor, version);
 }
 
 fn end_entity_reaction(world: &mut World)
@@ -50,9 +50,9 @@ fn end_entity_reaction(world: &mut World)
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
 
-fn start_despawn_reaction(world: &mut World, reactor: SystemCommand)
+fn start_despawn_reaction(world: &mut World, reactor: SystemCommand, version: ReactionVersion)
 {
-    world.resource_mut::<DespawnAccessTracker>().start(reactor);
+    world.resource_mut::<DespawnAccessTracker>().start(reactor, version);
 }
 
 fn end_despawn_reaction(world: &mut World)
@@ -63,10 +63,10 @@ fn end_despawn_reaction(world: &mut World)
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
 
-fn start_entity_event(world: &mut World, reactor: SystemCommand)
+fn start_entity_event(world: &mut World, reactor: SystemCommand, version: ReactionVersion)
 {
-    start_entity_reaction(world, reactor);
-    world.resource_mut::<EventAccessTracker>().start(reactor);
+    start_entity_reaction(world, reactor, version);
+    world.resource_mut::<EventAccessTracker>().start(reactor, version);
 }
 
 fn end_entity_event(world: &mut World)
@@ -79,9 +79,9 @@ fn end_entity_event(world: &mut World)
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
 
-fn start_broadcast_event(world: &mut World, reactor: SystemCommand)
+fn start_broadcast_event(world: &mut World, reactor: SystemCommand, version: ReactionVersion)
 {
-    world.resource_mut::<EventAccessTracker>().start(reactor);
+    world.resource_mut::<EventAccessTracker>().start(reactor, version);
 }
 
 fn end_broadcast_event(world: &mut World)
@@ -107,6 +107,11 @@ impl DataEntityCounter
         Self{ count }
     }
 
+    pub(crate) fn increment(&mut self)
+    {
+        self.count += 1;
+    }
+
     fn decrement(&mut self)
     {
         self.count = self.count.saturating_sub(1);
@@ -130,7 +135,7 @@ impl DataEntityCounter
 /// [`commands.spawn_system_command()`](super::ReactCommandsExt::spawn_system_command).
 ///
 /// All reactors are stored as system commands (i.e. systems registered with [`ReactCommands::on`]).
-#[derive(Debug, Copy, Clone, Deref, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Deref, Eq, PartialEq, Hash)]
 pub struct SystemCommand(pub Entity);
 
 impl Command for SystemCommand
@@ -169,11 +174,12 @@ impl Command for EventCommand
 {
     fn apply(self, world: &mut World)
     {
-        world.resource_mut::<SystemEventAccessTracker>().prepare(self.system, self.data_entity);
+        let version = world.resource_mut::<ReactCache>().next_reaction_version();
+        world.resource_mut::<SystemEventAccessTracker>().prepare(self.system, version, self.data_entity);
         syscommand_runner(
             world,
             self.system,
-            SystemCommandSetup::new(self.system, start_system_event),
+            SystemCommandSetup::new(self.system, version, start_system_event),
             SystemCommandCleanup::new(end_system_event)
         );
     }
@@ -184,12 +190,25 @@ impl Command for EventCommand
 /// A reaction command.
 ///
 /// Reaction commands are sent by the internals of [`ReactCommands`].
-#[derive(Clone)]
 pub(crate) enum ReactionCommand
 {
     /// A reaction to a resource mutation.
     Resource
     {
+        /// The type of the mutated resource.
+        resource: TypeId,
+        /// The name of the mutated resource type (for diagnostics).
+        type_name: &'static str,
+        /// The system command triggered by this event.
+        reactor: SystemCommand,
+    },
+    /// A reaction to a change in a plain Bevy resource.
+    BevyResource
+    {
+        /// The type of the changed resource.
+        resource: TypeId,
+        /// The name of the changed resource type (for diagnostics).
+        type_name: &'static str,
         /// The system command triggered by this event.
         reactor: SystemCommand,
     },
@@ -200,6 +219,22 @@ pub(crate) enum ReactionCommand
         reaction_source: Entity,
         /// The type of the entity reaction trigger.
         reaction_type: EntityReactionType,
+        /// The name of the component type that triggered this reaction (for diagnostics).
+        type_name: &'static str,
+        /// The system command triggered by this event.
+        reactor: SystemCommand,
+    },
+    /// A reaction to an entity mutation that stashed the component's previous value.
+    TrackedMutation
+    {
+        /// The entity that triggered this reaction.
+        reaction_source: Entity,
+        /// Entity where the previous value is stored.
+        data_entity: Entity,
+        /// The type of the mutated component.
+        component: TypeId,
+        /// The name of the component type that triggered this reaction (for diagnostics).
+        type_name: &'static str,
         /// The system command triggered by this event.
         reactor: SystemCommand,
     },
@@ -223,6 +258,10 @@ pub(crate) enum ReactionCommand
         target: Entity,
         /// Entity where the event data is stored.
         data_entity: Entity,
+        /// The type of the event.
+        event: TypeId,
+        /// The name of the event type (for diagnostics).
+        type_name: &'static str,
         /// The system command triggered by this event.
         reactor: SystemCommand,
     },
@@ -231,66 +270,240 @@ pub(crate) enum ReactionCommand
     {
         /// Entity where the event data is stored.
         data_entity: Entity,
+        /// The type of the event.
+        event: TypeId,
+        /// The name of the event type (for diagnostics).
+        type_name: &'static str,
+        /// The system command triggered by this event.
+        reactor: SystemCommand,
+    },
+    /// A reaction to one event in a batch of broadcast events.
+    BroadcastBatchEvent
+    {
+        /// Entity where the event batch is stored.
+        data_entity: Entity,
+        /// Index of the event in the batch.
+        index: usize,
+        /// The type of the event.
+        event: TypeId,
+        /// The name of the event type (for diagnostics).
+        type_name: &'static str,
         /// The system command triggered by this event.
         reactor: SystemCommand,
     },
+    /// A plain world mutation that runs in order with reactions.
+    ///
+    /// See [`ReactCommands::queue_in_tree`].
+    Custom(Box<dyn FnOnce(&mut World) + Send + Sync + 'static>),
 }
 
-impl Command for ReactionCommand
+/// A [`ReactionCommand`] with its reaction metadata prepared.
+pub(crate) enum PreparedReaction
 {
-    fn apply(self, world: &mut World)
+    /// A reactor to run with [`syscommand_runner`].
+    Reactor
+    {
+        reactor: SystemCommand,
+        setup: SystemCommandSetup,
+        cleanup: SystemCommandCleanup,
+    },
+    /// A plain world mutation.
+    Custom(Box<dyn FnOnce(&mut World) + Send + Sync + 'static>),
+}
+
+impl PreparedReaction
+{
+    /// Runs the reaction.
+    pub(crate) fn run(self, world: &mut World)
     {
         match self
         {
-            Self::Resource{ reactor } =>
+            Self::Reactor{ reactor, setup, cleanup } =>
             {
-                syscommand_runner(world, reactor, SystemCommandSetup::default(), SystemCommandCleanup::default());
+                if let Some(mut counter) = world.get_resource_mut::<ReactionCounter>() { counter.increment(); }
+                syscommand_runner(world, reactor, setup, cleanup);
             }
-            Self::EntityReaction{ reaction_source, reaction_type, reactor } =>
+            Self::Custom(callback) => (callback)(world),
+        }
+    }
+
+    /// Discards the reaction without running it, cleaning up its reaction metadata.
+    pub(crate) fn abort(self, world: &mut World)
+    {
+        match self
+        {
+            Self::Reactor{ setup, cleanup, .. } => cleanup_on_abort(world, setup, cleanup),
+            Self::Custom(_) => (),
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Counts reactions that ran since the counter was last taken.
+#[derive(Resource, Default, Debug)]
+pub(crate) struct ReactionCounter(u64);
+
+impl ReactionCounter
+{
+    fn increment(&mut self)
+    {
+        self.0 = self.0.saturating_add(1);
+    }
+
+    /// Gets the count without resetting it.
+    pub(crate) fn get(&self) -> u64
+    {
+        self.0
+    }
+
+    /// Takes the count, resetting it to zero.
+    pub(crate) fn take(&mut self) -> u64
+    {
+        std::mem::take(&mut self.0)
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+impl ReactionCommand
+{
+    /// Gets the reactor and trigger of this reaction.
+    pub(crate) fn info(&self) -> (Option<SystemCommand>, ReactionTriggerInfo)
+    {
+        match self
+        {
+            Self::Resource{ resource, type_name, reactor } =>
+            {
+                (Some(*reactor), ReactionTriggerInfo::new(ReactorType::ResourceMutation(*resource), type_name))
+            }
+            Self::BevyResource{ resource, type_name, reactor } =>
+            {
+                (Some(*reactor), ReactionTriggerInfo::new(ReactorType::BevyResourceChanged(*resource), type_name))
+            }
+            Self::EntityReaction{ reaction_source, reaction_type, type_name, reactor } =>
+            {
+                (Some(*reactor), ReactionTriggerInfo::new(reaction_type.reactor_type(*reaction_source), type_name))
+            }
+            Self::TrackedMutation{ reaction_source, component, type_name, reactor, .. } =>
+            {
+                let reaction_type = EntityReactionType::Mutation(*component);
+                (Some(*reactor), ReactionTriggerInfo::new(reaction_type.reactor_type(*reaction_source), type_name))
+            }
+            Self::Despawn{ reaction_source, reactor, .. } =>
+            {
+                (Some(*reactor), ReactionTriggerInfo::despawn(*reaction_source))
+            }
+            Self::EntityEvent{ target, event, type_name, reactor, .. } =>
+            {
+                let reaction_type = EntityReactionType::Event(*event);
+                (Some(*reactor), ReactionTriggerInfo::new(reaction_type.reactor_type(*target), type_name))
+            }
+            Self::BroadcastEvent{ event, type_name, reactor, .. } |
+            Self::BroadcastBatchEvent{ event, type_name, reactor, .. } =>
             {
-                world.resource_mut::<EntityReactionAccessTracker>().prepare(reactor, reaction_source, reaction_type);
-                syscommand_runner(
-                    world,
+                (Some(*reactor), ReactionTriggerInfo::new(ReactorType::Broadcast(*event), type_name))
+            }
+            Self::Custom(_) => (None, ReactionTriggerInfo::default()),
+        }
+    }
+
+    /// Prepares the reaction metadata so the reaction can be run.
+    pub(crate) fn prepare(self, world: &mut World) -> PreparedReaction
+    {
+        let (_, trigger) = self.info();
+        match self
+        {
+            Self::Resource{ reactor, .. } | Self::BevyResource{ reactor, .. } =>
+            {
+                PreparedReaction::Reactor{
+                    reactor,
+                    setup: SystemCommandSetup::default().with_trigger(trigger),
+                    cleanup: SystemCommandCleanup::default(),
+                }
+            }
+            Self::EntityReaction{ reaction_source, reaction_type, reactor, .. } =>
+            {
+                let version = world.resource_mut::<ReactCache>().next_reaction_version();
+                world.resource_mut::<EntityReactionAccessTracker>()
+                    .prepare(reactor, version, reaction_source, reaction_type, trigger);
+                PreparedReaction::Reactor{
                     reactor,
-                    SystemCommandSetup::new(reactor, start_entity_reaction),
-                    SystemCommandCleanup::new(end_entity_reaction)
-                );
+                    setup: SystemCommandSetup::new(reactor, version, start_entity_reaction).with_trigger(trigger),
+                    cleanup: SystemCommandCleanup::new(end_entity_reaction),
+                }
+            }
+            Self::TrackedMutation{ reaction_source, data_entity, component, reactor, .. } =>
+            {
+                // The previous value is read like entity event data.
+                let reaction_type = EntityReactionType::Mutation(component);
+                let version = world.resource_mut::<ReactCache>().next_reaction_version();
+                world.resource_mut::<EntityReactionAccessTracker>()
+                    .prepare(reactor, version, reaction_source, reaction_type, trigger);
+                world.resource_mut::<EventAccessTracker>().prepare(reactor, version, data_entity);
+                PreparedReaction::Reactor{
+                    reactor,
+                    setup: SystemCommandSetup::new(reactor, version, start_entity_event).with_trigger(trigger),
+                    cleanup: SystemCommandCleanup::new(end_entity_event),
+                }
             }
             Self::Despawn{ reaction_source, reactor, handle } =>
             {
-                world.resource_mut::<DespawnAccessTracker>().prepare(reactor, reaction_source, handle);
-                syscommand_runner(
-                    world,
+                let version = world.resource_mut::<ReactCache>().next_reaction_version();
+                world.resource_mut::<DespawnAccessTracker>().prepare(reactor, version, reaction_source, handle);
+                PreparedReaction::Reactor{
                     reactor,
-                    SystemCommandSetup::new(reactor, start_despawn_reaction),
-                    SystemCommandCleanup::new(end_despawn_reaction));
+                    setup: SystemCommandSetup::new(reactor, version, start_despawn_reaction).with_trigger(trigger),
+                    cleanup: SystemCommandCleanup::new(end_despawn_reaction),
+                }
             }
-            Self::EntityEvent{ target, data_entity, reactor } =>
+            Self::EntityEvent{ target, data_entity, event, reactor, .. } =>
             {
                 // Include entity reaction tracker for EntityWorldReactor.
-                world.resource_mut::<EntityReactionAccessTracker>().prepare(
+                let reaction_type = EntityReactionType::Event(event);
+                let version = world.resource_mut::<ReactCache>().next_reaction_version();
+                world.resource_mut::<EntityReactionAccessTracker>()
+                    .prepare(reactor, version, target, reaction_type, trigger);
+                world.resource_mut::<EventAccessTracker>().prepare(reactor, version, data_entity);
+                PreparedReaction::Reactor{
                     reactor,
-                    target,
-                    EntityReactionType::Event(TypeId::of::<()>()),
-                );
-                world.resource_mut::<EventAccessTracker>().prepare(reactor, data_entity);
-                syscommand_runner(world,
+                    setup: SystemCommandSetup::new(reactor, version, start_entity_event).with_trigger(trigger),
+                    cleanup: SystemCommandCleanup::new(end_entity_event),
+                }
+            }
+            Self::BroadcastEvent{ data_entity, reactor, .. } =>
+            {
+                let version = world.resource_mut::<ReactCache>().next_reaction_version();
+                world.resource_mut::<EventAccessTracker>().prepare(reactor, version, data_entity);
+                PreparedReaction::Reactor{
                     reactor,
-                    SystemCommandSetup::new(reactor, start_entity_event),
-                    SystemCommandCleanup::new(end_entity_event)
-                );
+                    setup: SystemCommandSetup::new(reactor, version, start_broadcast_event).with_trigger(trigger),
+                    cleanup: SystemCommandCleanup::new(end_broadcast_event),
+                }
             }
-            Self::BroadcastEvent{ data_entity, reactor } =>
+            Self::BroadcastBatchEvent{ data_entity, index, reactor, .. } =>
             {
-                world.resource_mut::<EventAccessTracker>().prepare(reactor, data_entity);
-                syscommand_runner(world,
+                let version = world.resource_mut::<ReactCache>().next_reaction_version();
+                world.resource_mut::<EventAccessTracker>().prepare_batch_item(reactor, version, data_entity, index);
+                PreparedReaction::Reactor{
                     reactor,
-                    SystemCommandSetup::new(reactor, start_broadcast_event),
-                    SystemCommandCleanup::new(end_broadcast_event)
-                );
+                    setup: SystemCommandSetup::new(reactor, version, start_broadcast_event).with_trigger(trigger),
+                    cleanup: SystemCommandCleanup::new(end_broadcast_event),
+                }
             }
+            Self::Custom(callback) => PreparedReaction::Custom(callback),
         }
     }
 }
 
+impl Command for ReactionCommand
+{
+    fn apply(self, world: &mut World)
+    {
+        // Reactions are captured while a stepped tree is active.
+        let Some(reaction) = capture_stepped_reaction(world, self) else { return; };
+        reaction.prepare(world).run(world);
+    }
+}
+
 //-------------------------------------------------------------------------------------------------------------------
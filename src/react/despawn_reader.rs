@@ -24,26 +24,31 @@ pub(crate) struct DespawnAccessTracker
     reactor_handle: Option<ReactorHandle>,
 
     /// Reaction information cached for when the reaction system actually runs.
-    prepared: Vec<(SystemCommand, Entity, ReactorHandle)>,
+    prepared: Vec<(SystemCommand, ReactionVersion, Entity, ReactorHandle)>,
 }
 
 impl DespawnAccessTracker
 {
     /// Caches metadata for an entity reaction.
-    pub(crate) fn prepare(&mut self, reactor: SystemCommand, source: Entity, handle: ReactorHandle)
-    {
-        self.prepared.push((reactor, source, handle));
+    pub(crate) fn prepare(
+        &mut self,
+        reactor : SystemCommand,
+        version : ReactionVersion,
+        source  : Entity,
+        handle  : ReactorHandle
+    ){
+        self.prepared.push((reactor, version, source, handle));
     }
 
     /// Sets metadata for the current entity reaction.
-    pub(crate) fn start(&mut self, reactor: SystemCommand)
+    pub(crate) fn start(&mut self, reactor: SystemCommand, version: ReactionVersion)
     {
-        let Some(pos) = self.prepared.iter().position(|(s, _, _)| *s == reactor) else {
+        let Some(pos) = self.prepared.iter().position(|(s, v, _, _)| *s == reactor && *v == version) else {
             tracing::error!("prepared despawn entity reaction is missing {:?}", reactor);
             debug_assert!(false);
             return;
         };
-        let (_, source, handle) = self.prepared.swap_remove(pos);
+        let (_, _, source, handle) = self.prepared.swap_remove(pos);
 
         self.currently_reacting = true;
         self.reaction_source = source;
@@ -57,6 +62,12 @@ impl DespawnAccessTracker
         self.reactor_handle = None;
     }
 
+    /// Returns `true` if no reactions are running or prepared.
+    pub(crate) fn is_idle(&self) -> bool
+    {
+        !self.currently_reacting && self.prepared.is_empty()
+    }
+
     /// Returns `true` if an entity reaction is currently being processed.
     fn is_reacting(&self) -> bool
     {
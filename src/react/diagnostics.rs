@@ -0,0 +1,175 @@
+//local shortcuts
+use crate::prelude::*;
+
+//third-party shortcuts
+use bevy::diagnostic::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic};
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+//standard shortcuts
+
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Records react framework diagnostics.
+fn update_react_diagnostics(
+    mut diagnostics : Diagnostics,
+    mut counter     : ResMut<ReactionCounter>,
+    cache           : Res<ReactCache>,
+){
+    let reactions = counter.take();
+    diagnostics.add_measurement(&ReactDiagnosticsPlugin::REACTIONS_PER_FRAME, || reactions as f64);
+    diagnostics.add_measurement(&ReactDiagnosticsPlugin::LIVE_REACTORS, || cache.count_live_reactors() as f64);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Adds react framework diagnostics to Bevy's [`DiagnosticsStore`](bevy::diagnostic::DiagnosticsStore).
+///
+/// Diagnostics are measured once per frame in `Last`, after removals and despawns are processed:
+/// - [`Self::REACTIONS_PER_FRAME`]: Number of reactors that ran since the previous measurement. Reactions that run
+///   later in `Last` are counted in the next frame.
+/// - [`Self::LIVE_REACTORS`]: Number of distinct reactors with at least one registered trigger.
+///
+/// Requires [`ReactPlugin`].
+pub struct ReactDiagnosticsPlugin;
+
+impl ReactDiagnosticsPlugin
+{
+    /// Number of reactors that ran in the last frame.
+    pub const REACTIONS_PER_FRAME: DiagnosticPath = DiagnosticPath::const_new("cobweb/reactions_per_frame");
+    /// Number of registered reactors.
+    pub const LIVE_REACTORS: DiagnosticPath = DiagnosticPath::const_new("cobweb/live_reactors");
+}
+
+impl Plugin for ReactDiagnosticsPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.register_diagnostic(Diagnostic::new(Self::REACTIONS_PER_FRAME))
+            .register_diagnostic(Diagnostic::new(Self::LIVE_REACTORS))
+            .add_systems(Last, update_react_diagnostics.after(schedule_removal_and_despawn_reactors));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// When trigger fire counts collected with [`ReactPlugin::with_metrics`] are reset.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub enum TriggerMetricsReset
+{
+    /// Counts are never reset, so they include every fire since metrics were enabled.
+    #[default]
+    Cumulative,
+    /// Counts are reset at the start of each frame (in `First`), so they only include fires in the current frame.
+    PerFrame,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Clears trigger fire counts.
+pub(crate) fn reset_trigger_metrics(mut cache: ResMut<ReactCache>)
+{
+    cache.reset_trigger_metrics();
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Counts of registered reactors by trigger category.
+///
+/// See [`ReactDiagnostics::reactor_counts`].
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub struct ReactorCounts
+{
+    /// Entity-agnostic component reactors ([`insertion`], [`mutation`], [`removal`]).
+    pub component: usize,
+    /// Resource reactors ([`resource_mutation`], [`bevy_resource_changed`]).
+    pub resource: usize,
+    /// Broadcast reactors ([`broadcast`], [`broadcast_channel`], [`broadcast_topic`]).
+    pub broadcast: usize,
+    /// [`despawn`] reactors.
+    pub despawn: usize,
+    /// [`any_entity_event`] reactors.
+    pub any_entity_event: usize,
+}
+
+impl ReactorCounts
+{
+    /// Returns the sum of all counts.
+    pub fn total(&self) -> usize
+    {
+        self.component + self.resource + self.broadcast + self.despawn + self.any_entity_event
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// System parameter for reading react framework metrics.
+///
+/// Trigger fire counts are only collected if metrics are enabled with [`ReactPlugin::with_metrics`].
+#[derive(SystemParam)]
+pub struct ReactDiagnostics<'w, 's>
+{
+    cache: Res<'w, ReactCache>,
+    entity_reactors: Query<'w, 's, &'static EntityReactors>,
+}
+
+impl ReactDiagnostics<'_, '_>
+{
+    /// Returns how many times each trigger type fired, in order of the first fire of each trigger type.
+    ///
+    /// A trigger fires when its reactions are scheduled, even if no reactors are registered for it. Entity-specific
+    /// triggers are counted under their entity-agnostic types (e.g. [`entity_mutation`] under
+    /// [`ReactorType::ComponentMutation`] and [`entity_event`] under [`ReactorType::AnyEntityEvent`]), and despawns of
+    /// entities with [`despawn`] reactors are counted under `ReactorType::Despawn(Entity::PLACEHOLDER)`.
+    ///
+    /// Returns an empty list if metrics are disabled.
+    pub fn trigger_fire_counts(&self) -> Vec<(ReactorType, u64)>
+    {
+        self.cache.trigger_fire_counts()
+    }
+
+    /// Returns the number of registered reactors in each trigger category.
+    ///
+    /// A reactor is counted once for each trigger it is registered with, so counts that grow without bound usually
+    /// mean reactors are not being revoked. Entity-specific triggers (e.g. [`entity_mutation`]) are not counted,
+    /// because they are cleaned up when their entities despawn.
+    ///
+    /// This only sums the lengths of the reactor lists, so it is cheap enough to call every frame.
+    pub fn reactor_counts(&self) -> ReactorCounts
+    {
+        self.cache.reactor_counts()
+    }
+
+    /// Returns the number of reactors registered for a trigger type.
+    ///
+    /// A reactor registered multiple times with the same trigger is counted once for each registration, so this can
+    /// be used to assert that there are no duplicate reactors, e.g.
+    /// `reactor_count_for(broadcast::<E>().reactor_type())`.
+    ///
+    /// Entity-specific trigger types ([`ReactorType::EntityInsertion`], [`ReactorType::EntityMutation`],
+    /// [`ReactorType::EntityRemoval`], [`ReactorType::EntityEvent`]) are counted from the target entity's reactors,
+    /// and return zero if the entity doesn't exist. All other types are counted from the react cache.
+    pub fn reactor_count_for(&self, rtype: ReactorType) -> usize
+    {
+        let entity_rtype = match rtype
+        {
+            ReactorType::EntityInsertion(entity, id) => Some((entity, EntityReactionType::Insertion(id))),
+            ReactorType::EntityMutation(entity, id) => Some((entity, EntityReactionType::Mutation(id))),
+            ReactorType::EntityRemoval(entity, id) => Some((entity, EntityReactionType::Removal(id))),
+            ReactorType::EntityEvent(entity, id) => Some((entity, EntityReactionType::Event(id))),
+            _ => None,
+        };
+
+        match entity_rtype
+        {
+            Some((entity, entity_rtype)) =>
+            {
+                self.entity_reactors.get(entity).map(|reactors| reactors.count(entity_rtype)).unwrap_or_default()
+            }
+            None => self.cache.reactor_count_for(rtype),
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
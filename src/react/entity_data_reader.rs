@@ -0,0 +1,131 @@
+//local shortcuts
+use crate::prelude::*;
+
+//third-party shortcuts
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+//standard shortcuts
+use std::any::type_name;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Adds `data` for `reactor` to the [`EntityReactorData<D>`] on `entity`.
+///
+/// Does nothing if the entity doesn't exist.
+pub(crate) fn insert_entity_reactor_data<D: Send + Sync + 'static>(
+    In((entity, reactor, data)) : In<(Entity, SystemCommand, D)>,
+    world                       : &mut World,
+){
+    let Ok(mut entity_mut) = world.get_entity_mut(entity) else { return; };
+    match entity_mut.get_mut::<EntityReactorData<D>>()
+    {
+        Some(mut entity_data) => { entity_data.data.insert(reactor, data); }
+        None => { entity_mut.insert(EntityReactorData{ data: HashMap::from([(reactor, data)]) }); }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Component on the target entity of reactors registered with [`ReactCommands::on_entity_with_data`].
+///
+/// Stores the data of each reactor (keyed by the reactor's [`SystemCommand`]), so reactors with the same data type on
+/// the same entity don't share data. The data is dropped when the entity is despawned, which is also when the
+/// reactors are cleaned up.
+#[derive(Component)]
+pub(crate) struct EntityReactorData<D: Send + Sync + 'static>
+{
+    data: HashMap<SystemCommand, D>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// System parameter for accessing the per-entity data of reactors registered with
+/// [`ReactCommands::on_entity_with_data`].
+///
+/*
+```rust
+#[derive(ReactComponent)]
+struct Health(usize);
+
+fn setup(mut c: Commands)
+{
+    let entity = c.spawn_empty().id();
+    c.react().insert(entity, Health(100));
+    c.react().on_entity_with_data::<Health, usize, _, _>(entity, 0usize,
+        |mut hits: EntityData<usize>|
+        {
+            let (entity, hits) = hits.get_mut();
+            *hits += 1;
+            println!("{entity:?} health changed {hits} times");
+        }
+    );
+}
+```
+*/
+#[derive(SystemParam)]
+pub struct EntityData<'w, 's, D: Send + Sync + 'static>
+{
+    tracker: Res<'w, EntityReactionAccessTracker>,
+    data: Query<'w, 's, &'static mut EntityReactorData<D>>,
+}
+
+impl<'w, 's, D: Send + Sync + 'static> EntityData<'w, 's, D>
+{
+    /// Gets the current entity.
+    ///
+    /// Panics if not called from within an entity reaction.
+    pub fn entity(&self) -> Entity
+    {
+        self.check();
+        self.tracker.source()
+    }
+
+    /// Gets the current entity's data for this reactor.
+    ///
+    /// Panics if not called from within a reactor registered with [`ReactCommands::on_entity_with_data`].
+    pub fn get(&self) -> (Entity, &D)
+    {
+        self.try_get()
+            .unwrap_or_else(|| panic!("entity missing reactor data {} in EntityData", type_name::<D>()))
+    }
+
+    /// See [`Self::get`].
+    pub fn try_get(&self) -> Option<(Entity, &D)>
+    {
+        if !self.tracker.is_reacting() { return None; }
+        let entity = self.tracker.source();
+        let data = self.data.get(entity).ok()?.data.get(&self.tracker.system())?;
+        Some((entity, data))
+    }
+
+    /// Gets the current entity's data for this reactor.
+    ///
+    /// Panics if not called from within a reactor registered with [`ReactCommands::on_entity_with_data`].
+    pub fn get_mut(&mut self) -> (Entity, &mut D)
+    {
+        self.try_get_mut()
+            .unwrap_or_else(|| panic!("entity missing reactor data {} in EntityData", type_name::<D>()))
+    }
+
+    /// See [`Self::get_mut`].
+    pub fn try_get_mut(&mut self) -> Option<(Entity, &mut D)>
+    {
+        if !self.tracker.is_reacting() { return None; }
+        let entity = self.tracker.source();
+        let system = self.tracker.system();
+        let data = self.data.get_mut(entity).ok()?.into_inner().data.get_mut(&system)?;
+        Some((entity, data))
+    }
+
+    fn check(&self)
+    {
+        if !self.tracker.is_reacting()
+        {
+            panic!("EntityData should only be used in an entity reaction");
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
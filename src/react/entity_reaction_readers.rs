@@ -57,34 +57,43 @@ pub(crate) struct EntityReactionAccessTracker
     reaction_source: Entity,
     /// The type of the most recent entity reaction trigger.
     reaction_type: EntityReactionType,
+    /// Description of the most recent entity reaction trigger, for diagnostics.
+    trigger: ReactionTriggerInfo,
 
     /// Reaction information cached for when the reaction system actually runs.
-    prepared: Vec<(SystemCommand, Entity, EntityReactionType)>,
+    prepared: Vec<(SystemCommand, ReactionVersion, Entity, EntityReactionType, ReactionTriggerInfo)>,
 }
 
 impl EntityReactionAccessTracker
 {
     /// Caches metadata for an entity reaction.
-    pub(crate) fn prepare(&mut self, system: SystemCommand, source: Entity, reaction: EntityReactionType)
-    {
-        self.prepared.push((system, source, reaction));
+    pub(crate) fn prepare(
+        &mut self,
+        system   : SystemCommand,
+        version  : ReactionVersion,
+        source   : Entity,
+        reaction : EntityReactionType,
+        trigger  : ReactionTriggerInfo,
+    ){
+        self.prepared.push((system, version, source, reaction, trigger));
     }
 
     /// Sets metadata for the current entity reaction.
-    pub(crate) fn start(&mut self, reactor: SystemCommand)
+    pub(crate) fn start(&mut self, reactor: SystemCommand, version: ReactionVersion)
     {
-        let Some(pos) = self.prepared.iter().position(|(s, _, _)| *s == reactor) else {
+        let Some(pos) = self.prepared.iter().position(|(s, v, _, _, _)| *s == reactor && *v == version) else {
             tracing::error!("prepared entity reaction is missing {:?}", reactor);
             debug_assert!(false);
             return;
         };
-        let (system, source, reaction) = self.prepared.swap_remove(pos);
+        let (system, _, source, reaction, trigger) = self.prepared.swap_remove(pos);
 
         debug_assert!(!self.currently_reacting);
         self.currently_reacting = true;
         self.system = system;
         self.reaction_source = source;
         self.reaction_type = reaction;
+        self.trigger = trigger;
     }
 
     /// Unsets the 'is reacting' flag.
@@ -93,20 +102,26 @@ impl EntityReactionAccessTracker
         self.currently_reacting = false;
     }
 
+    /// Returns `true` if no reactions are running or prepared.
+    pub(crate) fn is_idle(&self) -> bool
+    {
+        !self.currently_reacting && self.prepared.is_empty()
+    }
+
     /// Returns `true` if an entity reaction is currently being processed.
-    fn is_reacting(&self) -> bool
+    pub(crate) fn is_reacting(&self) -> bool
     {
         self.currently_reacting
     }
 
     /// Returns the system running the entity reaction.
-    fn system(&self) -> SystemCommand
+    pub(crate) fn system(&self) -> SystemCommand
     {
         self.system
     }
 
     /// Returns the source of the most recent entity reaction.
-    fn source(&self) -> Entity
+    pub(crate) fn source(&self) -> Entity
     {
         self.reaction_source
     }
@@ -116,6 +131,13 @@ impl EntityReactionAccessTracker
     {
         self.reaction_type
     }
+
+    /// Returns a description of the current entity reaction trigger, for diagnostics.
+    fn trigger(&self) -> ReactionTriggerInfo
+    {
+        if !self.currently_reacting { return ReactionTriggerInfo::default(); }
+        self.trigger
+    }
 }
 
 impl Default for EntityReactionAccessTracker
@@ -127,6 +149,7 @@ impl Default for EntityReactionAccessTracker
             system: SystemCommand(Entity::PLACEHOLDER),
             reaction_source: Entity::PLACEHOLDER,
             reaction_type: EntityReactionType::Insertion(TypeId::of::<()>()),
+            trigger: ReactionTriggerInfo::default(),
             prepared: Vec::default(),
         }
     }
@@ -134,6 +157,55 @@ impl Default for EntityReactionAccessTracker
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// System parameter for reading the source entity and type of the entity reaction a system is reacting to.
+///
+/// This reads reactions to entity-specific and entity-agnostic component triggers (e.g. [`insertion`],
+/// [`entity_mutation`], [`removal`]) and entity events (e.g. [`entity_event`]), without needing to know the component
+/// or event type.
+///
+/*
+```rust
+fn example(mut c: Commands)
+{
+    c.react().on(
+        (insertion::<A>(), mutation::<B>(), removal::<C>()),
+        |source: EntityReactionSource|
+        {
+            let Some((entity, reaction)) = source.get() else { return; };
+            println!("{:?} triggered {:?}", entity, reaction);
+        }
+    );
+}
+```
+*/
+#[derive(SystemParam)]
+pub struct EntityReactionSource<'w>
+{
+    tracker: Res<'w, EntityReactionAccessTracker>,
+}
+
+impl<'w> EntityReactionSource<'w>
+{
+    /// Returns the source entity and type of the current entity reaction.
+    ///
+    /// Returns `None` if the system is not reacting to an entity reaction.
+    pub fn get(&self) -> Option<(Entity, EntityReactionType)>
+    {
+        if !self.tracker.is_reacting() { return None; }
+        Some((self.tracker.source(), self.tracker.reaction_type()))
+    }
+
+    /// Returns `true` if there is nothing to read.
+    ///
+    /// Equivalent to `source.get().is_none()`.
+    pub fn is_empty(&self) -> bool
+    {
+        self.get().is_none()
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// System parameter for reading entity component insertion events in systems that react to those events.
 ///
 /// Can only be used within [`SystemCommands`](super::SystemCommand).
@@ -176,7 +248,8 @@ impl<'w, 's, T: ReactComponent> InsertionEvent<'w, 's, T>
     pub fn entity(&self) -> Entity
     {
         self.get()
-            .unwrap_or_else(|_| panic!("failed reading insertion event for {}, there is no event", type_name::<T>()))
+            .unwrap_or_else(|_| panic!("failed reading insertion event for {}, there is no event (current trigger: {})",
+                type_name::<T>(), self.tracker.trigger()))
     }
 
     /// See [`Self::entity`].
@@ -203,12 +276,33 @@ impl<'w, 's, T: ReactComponent> InsertionEvent<'w, 's, T>
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Stores the pre-mutation value of a [`React`] component for [`MutationEvent::previous`].
+#[derive(Component)]
+pub(crate) struct MutationPrevious<T: ReactComponent>
+{
+    entity : Entity,
+    value  : T,
+}
+
+impl<T: ReactComponent> MutationPrevious<T>
+{
+    pub(crate) fn new(entity: Entity, value: T) -> Self
+    {
+        Self{ entity, value }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// System parameter for reading entity component mutation events in systems that react to those events.
 ///
 /// Can only be used within [`SystemCommands`](super::SystemCommand).
 ///
 /// Use [`entity_mutation`] or [`mutation`] to make a trigger that will read these events.
 ///
+/// If the component was mutated with [`React::get_mut_tracked`], the value before the mutation can be read with
+/// [`Self::previous`].
+///
 /*
 ```rust
 fn example(mut c: Commands, query: Query<&mut React<A>>)
@@ -232,6 +326,8 @@ pub struct MutationEvent<'w, 's, T: ReactComponent>
 {
     component_id: Local<'s, ReactComponentId<T>>,
     tracker: Res<'w, EntityReactionAccessTracker>,
+    event_tracker: Res<'w, EventAccessTracker>,
+    previous: Query<'w, 's, &'static MutationPrevious<T>>,
 }
 
 impl<'w, 's, T: ReactComponent> MutationEvent<'w, 's, T>
@@ -244,7 +340,8 @@ impl<'w, 's, T: ReactComponent> MutationEvent<'w, 's, T>
     pub fn entity(&self) -> Entity
     {
         self.get()
-            .unwrap_or_else(|_| panic!("failed reading mutation event for {}, there is no event", type_name::<T>()))
+            .unwrap_or_else(|_| panic!("failed reading mutation event for {}, there is no event (current trigger: {})",
+                type_name::<T>(), self.tracker.trigger()))
     }
 
     /// See [`Self::entity`].
@@ -260,6 +357,20 @@ impl<'w, 's, T: ReactComponent> MutationEvent<'w, 's, T>
         Ok(self.tracker.source())
     }
 
+    /// Returns the value of the `React<T>` component before the mutation the system is reacting to.
+    ///
+    /// Returns `None` if the system is not reacting to a mutation event for `T`, or if the component was not mutated
+    /// with [`React::get_mut_tracked`].
+    pub fn previous(&self) -> Option<&T>
+    {
+        let entity = self.get().ok()?;
+        if !self.event_tracker.is_reacting() { return None; }
+        let previous = self.previous.get(self.event_tracker.data_entity()).ok()?;
+        if previous.entity != entity { return None; }
+
+        Some(&previous.value)
+    }
+
     /// Returns `true` if there is nothing to read.
     ///
     /// Equivalent to `event.get().is_ok()`.
@@ -271,6 +382,79 @@ impl<'w, 's, T: ReactComponent> MutationEvent<'w, 's, T>
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// System parameter for reading entity component insertion or mutation events in systems that react to those events.
+///
+/// Use [`entity_change`] or [`change`] to make a trigger that will read these events. It also reads reactions to
+/// [`insertion`], [`mutation`], and their entity-specific versions.
+///
+/*
+```rust
+fn example(mut c: Commands)
+{
+    c.react().on(
+        change::<A>(),  // entity-specific: entity_change::<A>(target_entity)
+        |event: ChangeEvent<A>, query: Reactive<A>|
+        {
+            let entity = event.get()?;
+            println!("'A' is now {:?} on {:?}", query.get(entity)?, entity);
+            DONE
+        }
+    );
+}
+```
+*/
+#[derive(SystemParam)]
+pub struct ChangeEvent<'w, 's, T: ReactComponent>
+{
+    component_id: Local<'s, ReactComponentId<T>>,
+    tracker: Res<'w, EntityReactionAccessTracker>,
+}
+
+impl<'w, 's, T: ReactComponent> ChangeEvent<'w, 's, T>
+{
+    /// Returns the entity on which a `React<T>` component was inserted or mutated that the system is reacting to.
+    ///
+    /// This will return at most one unique entity each time a reactor runs.
+    ///
+    /// Panics if the system is not reacting to an insertion or mutation event for `T`.
+    pub fn entity(&self) -> Entity
+    {
+        self.get()
+            .unwrap_or_else(|_| panic!("failed reading change event for {}, there is no event (current trigger: {})",
+                type_name::<T>(), self.tracker.trigger()))
+    }
+
+    /// See [`Self::entity`].
+    pub fn get(&self) -> Result<Entity, CobwebReactError>
+    {
+        let t = type_name::<T>();
+        if !self.tracker.is_reacting() { return Err(CobwebReactError::ChangeEvent(t)); }
+        let (EntityReactionType::Insertion(component_id) | EntityReactionType::Mutation(component_id)) =
+            self.tracker.reaction_type() else { return Err(CobwebReactError::ChangeEvent(t)); };
+        if component_id != self.component_id.id() { return Err(CobwebReactError::ChangeEvent(t)); }
+
+        Ok(self.tracker.source())
+    }
+
+    /// Returns `true` if the change is an insertion.
+    ///
+    /// Returns `false` if the change is a mutation or there is nothing to read.
+    pub fn is_insertion(&self) -> bool
+    {
+        self.get().is_ok() && matches!(self.tracker.reaction_type(), EntityReactionType::Insertion(_))
+    }
+
+    /// Returns `true` if there is nothing to read.
+    ///
+    /// Equivalent to `event.get().is_err()`.
+    pub fn is_empty(&self) -> bool
+    {
+        self.get().is_err()
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// System parameter for reading entity component removal events in systems that react to those events.
 ///
 /// Note that removals are detected for entity despawns, so if the entity returned from `RemovalEvent` does not
@@ -314,7 +498,8 @@ impl<'w, 's, T: ReactComponent> RemovalEvent<'w, 's, T>
     pub fn entity(&self) -> Entity
     {
         self.get()
-            .unwrap_or_else(|_| panic!("failed reading removal event for {}, there is no event", type_name::<T>()))
+            .unwrap_or_else(|_| panic!("failed reading removal event for {}, there is no event (current trigger: {})",
+                type_name::<T>(), self.tracker.trigger()))
     }
 
     /// See [`Self::entity`].
@@ -341,6 +526,112 @@ impl<'w, 's, T: ReactComponent> RemovalEvent<'w, 's, T>
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// System parameter for reading batched removals of [`React<C>`] components along with the removed values.
+///
+/// Use [`removal_batched_with_values`] to make a trigger that will read these events.
+///
+/*
+```rust
+c.react().on(
+    removal_batched_with_values::<A>(),
+    |event: BatchedRemovalEvent<A>|
+    {
+        for (entity, value) in event.iter()
+        {
+            println!("{:?} was removed from {:?}", value, entity);
+        }
+    }
+);
+```
+*/
+#[derive(SystemParam)]
+pub struct BatchedRemovalEvent<'w, 's, C: ReactComponent + Clone>
+{
+    event: BroadcastEvent<'w, 's, BatchedRemovals<C>>,
+}
+
+impl<'w, 's, C: ReactComponent + Clone> BatchedRemovalEvent<'w, 's, C>
+{
+    /// Iterates the entities that lost `React<C>` and the removed values, in removal order.
+    ///
+    /// The iterator is empty if the system is not reacting to batched removals of `C`.
+    pub fn iter(&self) -> impl Iterator<Item = (Entity, &C)> + '_
+    {
+        self.event
+            .try_read()
+            .into_iter()
+            .flat_map(|removals| removals.0.iter().map(|(entity, value)| (*entity, value)))
+    }
+
+    /// Returns the number of removals.
+    pub fn len(&self) -> usize
+    {
+        self.event.try_read().map(|removals| removals.0.len()).unwrap_or_default()
+    }
+
+    /// Returns `true` if there is nothing to read.
+    pub fn is_empty(&self) -> bool
+    {
+        self.len() == 0
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// System parameter for reading the entity of a [`bevy_component_added`] or [`bevy_component_changed`] reaction.
+///
+/*
+```rust
+c.react().on(
+    bevy_component_added::<Transform>(),
+    |event: BevyComponentEvent<Transform>|
+    {
+        println!("{:?} got a transform", event.entity());
+    }
+);
+```
+*/
+#[derive(SystemParam)]
+pub struct BevyComponentEvent<'w, 's, C: Component>
+{
+    added: BroadcastEvent<'w, 's, BevyComponentAdded<C>>,
+    changed: BroadcastEvent<'w, 's, BevyComponentChanged<C>>,
+}
+
+impl<'w, 's, C: Component> BevyComponentEvent<'w, 's, C>
+{
+    /// Returns the entity where `C` was added or changed.
+    ///
+    /// Panics if the system is not reacting to an addition or change of `C`.
+    pub fn entity(&self) -> Entity
+    {
+        self.get().unwrap_or_else(|_| panic!("failed reading bevy component event for {}, there is no event",
+            type_name::<C>()))
+    }
+
+    /// See [`Self::entity`].
+    pub fn get(&self) -> Result<Entity, CobwebReactError>
+    {
+        if let Ok(added) = self.added.try_read() { return Ok(added.0); }
+        if let Ok(changed) = self.changed.try_read() { return Ok(changed.0); }
+        Err(CobwebReactError::BroadcastEvent(type_name::<C>()))
+    }
+
+    /// Returns `true` if the system is reacting to an addition of `C`.
+    pub fn is_added(&self) -> bool
+    {
+        !self.added.is_empty()
+    }
+
+    /// Returns `true` if there is nothing to read.
+    pub fn is_empty(&self) -> bool
+    {
+        self.get().is_err()
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// System parameter for reading entity-specific data for [`EntityWorldReactor`] reactors.
 ///
 /*
@@ -4,6 +4,7 @@ use crate::prelude::*;
 //third-party shortcuts
 use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
+use bevy::utils::HashSet;
 
 //standard shortcuts
 use std::any::type_name;
@@ -16,10 +17,42 @@ fn cleanup_reactor_data<T: EntityWorldReactor>(
     In((id, entity)): In<(SystemCommand, Entity)>,
     mut commands: Commands,
     entities: Query<&EntityReactors>,
+    mut inner: ResMut<EntityWorldReactorRes<T>>,
 ){
     let Ok(reactor) = entities.get(entity) else { return };
     if reactor.iter_reactors().find(|reactor_id| *reactor_id == id).is_some() { return }
     commands.entity(entity).remove::<EntityWorldLocal<T>>();
+
+    // Stop tracking the entity.
+    if !inner.entities.remove(&entity) { return }
+    let cleanup = inner.cleanup;
+    commands.react().revoke(RevokeToken::new_from(cleanup, despawn(entity)));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn track_reactor_entity<T: EntityWorldReactor>(
+    In(entity): In<Entity>,
+    mut commands: Commands,
+    mut inner: ResMut<EntityWorldReactorRes<T>>,
+){
+    if !inner.entities.insert(entity) { return }
+    let cleanup = inner.cleanup;
+    commands.react().with(despawn(entity), cleanup, ReactorMode::Persistent);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Prunes despawned entities from an entity world reactor's tracking set.
+pub(crate) fn prune_despawned_reactor_entity<T: EntityWorldReactor>(
+    event: DespawnEvent,
+    mut inner: ResMut<EntityWorldReactorRes<T>>,
+) -> DropErr
+{
+    inner.entities.remove(&event.get()?);
+    DONE
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -29,14 +62,18 @@ fn cleanup_reactor_data<T: EntityWorldReactor>(
 pub(crate) struct EntityWorldReactorRes<T: EntityWorldReactor>
 {
     sys_command: SystemCommand,
+    /// Persistent despawn reactor that prunes `entities`.
+    cleanup: SystemCommand,
+    /// Entities currently registered with the reactor.
+    entities: HashSet<Entity>,
     p: PhantomData<T>,
 }
 
 impl<T: EntityWorldReactor> EntityWorldReactorRes<T>
 {
-    pub(crate) fn new(sys_command: SystemCommand) -> Self
+    pub(crate) fn new(sys_command: SystemCommand, cleanup: SystemCommand) -> Self
     {
-        Self{ sys_command, p: PhantomData::default() }
+        Self{ sys_command, cleanup, entities: HashSet::default(), p: PhantomData::default() }
     }
 }
 
@@ -141,6 +178,10 @@ impl<'w, T: EntityWorldReactor> EntityReactor<'w, T>
 {
     /// Adds a listener to the reactor.
     ///
+    /// The entity is recorded in the reactor's tracking set (see [`Self::num_tracked`]). The first time an entity is
+    /// added, a [`despawn`] trigger is registered for it on an internal persistent reactor that prunes the entity from
+    /// the tracking set, so despawned entities don't linger after their [`EntityLocal`] data is gone.
+    ///
     /// Returns `false` if:
     /// - The reactor doesn't exist.
     /// - The trigger entity doesn't exist.
@@ -159,6 +200,7 @@ impl<'w, T: EntityWorldReactor> EntityReactor<'w, T>
 
         let triggers = <T as EntityWorldReactor>::Triggers::new_bundle(trigger_entity);
         c.react().with(triggers, inner.sys_command, ReactorMode::Persistent);
+        c.syscall(trigger_entity, track_reactor_entity::<T>);
         true
     }
 
@@ -192,6 +234,25 @@ impl<'w, T: EntityWorldReactor> EntityReactor<'w, T>
         true
     }
 
+    /// Gets the number of entities currently registered with the reactor.
+    ///
+    /// Entities are tracked after the commands queued by [`Self::add`] are applied, and untracked when they are
+    /// despawned or all their triggers are removed with [`Self::remove`].
+    ///
+    /// Returns `0` if the reactor doesn't exist.
+    pub fn num_tracked(&self) -> usize
+    {
+        self.inner.as_ref().map(|inner| inner.entities.len()).unwrap_or_default()
+    }
+
+    /// Returns `true` if `entity` is currently registered with the reactor.
+    ///
+    /// See [`Self::num_tracked`].
+    pub fn is_tracking(&self, entity: Entity) -> bool
+    {
+        self.inner.as_ref().map(|inner| inner.entities.contains(&entity)).unwrap_or_default()
+    }
+
     /// Gets the reactor's system command.
     ///
     /// Returns `None` if the reactor doesn't exist.
@@ -10,12 +10,20 @@ pub enum CobwebReactError
     DespawnEvent,
     InsertionEvent(&'static str),
     MutationEvent(&'static str),
+    ChangeEvent(&'static str),
     RemovalEvent(&'static str),
     BroadcastEvent(&'static str),
+    BroadcastResponder(&'static str),
     EntityEvent(&'static str),
     Reactive(Entity, &'static str),
     ReactiveMut(Entity, &'static str),
     SystemEvent(&'static str),
+    #[cfg(feature = "serde")]
+    ReactRes(&'static str),
+    #[cfg(feature = "serde")]
+    Serialize(&'static str),
+    #[cfg(feature = "serde")]
+    Deserialize(&'static str),
 }
 
 impl std::error::Error for CobwebReactError
@@ -34,12 +42,20 @@ impl std::fmt::Display for CobwebReactError
             Self::DespawnEvent => f.write_fmt(format_args!("DespawnEvent")),
             Self::InsertionEvent(t) => f.write_fmt(format_args!("InsertionEvent<{t}>")),
             Self::MutationEvent(t) => f.write_fmt(format_args!("MutationEvent<{t}>")),
+            Self::ChangeEvent(t) => f.write_fmt(format_args!("ChangeEvent<{t}>")),
             Self::RemovalEvent(t) => f.write_fmt(format_args!("RemovalEvent<{t}>")),
             Self::BroadcastEvent(t) => f.write_fmt(format_args!("BroadcastEvent<{t}>")),
+            Self::BroadcastResponder(t) => f.write_fmt(format_args!("BroadcastResponder<{t}>")),
             Self::EntityEvent(t) => f.write_fmt(format_args!("EntityEvent<{t}>")),
             Self::Reactive(entity, t) => f.write_fmt(format_args!("Reactive<{t}>({entity:?})")),
             Self::ReactiveMut(entity, t) => f.write_fmt(format_args!("ReactiveMut<{t}>({entity:?})")),
             Self::SystemEvent(t) => f.write_fmt(format_args!("SystemEvent<{t}>")),
+            #[cfg(feature = "serde")]
+            Self::ReactRes(t) => f.write_fmt(format_args!("ReactRes<{t}>")),
+            #[cfg(feature = "serde")]
+            Self::Serialize(t) => f.write_fmt(format_args!("Serialize<{t}>")),
+            #[cfg(feature = "serde")]
+            Self::Deserialize(t) => f.write_fmt(format_args!("Deserialize<{t}>")),
         }
     }
 }
@@ -7,6 +7,7 @@ use bevy::prelude::*;
 
 //standard shortcuts
 use std::any::type_name;
+use std::sync::{Arc, Mutex};
 
 //-------------------------------------------------------------------------------------------------------------------
 
@@ -18,32 +19,46 @@ pub(crate) struct EventAccessTracker
     currently_reacting: bool,
     /// Entity where the event data is stored.
     data_entity: Entity,
+    /// Index of the event in a broadcast batch.
+    batch_index: usize,
 
     /// Reaction information cached for when the reaction system actually runs.
-    prepared: Vec<(SystemCommand, Entity)>,
+    prepared: Vec<(SystemCommand, ReactionVersion, Entity, usize)>,
 }
 
 impl EventAccessTracker
 {
     /// Caches metadata for an entity reaction.
-    pub(crate) fn prepare(&mut self, system: SystemCommand, data_entity: Entity)
+    pub(crate) fn prepare(&mut self, system: SystemCommand, version: ReactionVersion, data_entity: Entity)
     {
-        self.prepared.push((system, data_entity));
+        self.prepare_batch_item(system, version, data_entity, 0);
+    }
+
+    /// Caches metadata for a reaction to one event in a broadcast batch.
+    pub(crate) fn prepare_batch_item(
+        &mut self,
+        system      : SystemCommand,
+        version     : ReactionVersion,
+        data_entity : Entity,
+        batch_index : usize,
+    ){
+        self.prepared.push((system, version, data_entity, batch_index));
     }
 
     /// Sets metadata for the current entity reaction.
-    pub(crate) fn start(&mut self, reactor: SystemCommand)
+    pub(crate) fn start(&mut self, reactor: SystemCommand, version: ReactionVersion)
     {
-        let Some(pos) = self.prepared.iter().position(|(s, _)| *s == reactor) else {
+        let Some(pos) = self.prepared.iter().position(|(s, v, _, _)| *s == reactor && *v == version) else {
             tracing::error!("prepared event reaction is missing {:?}", reactor);
             debug_assert!(false);
             return;
         };
-        let (_, data_entity) = self.prepared.swap_remove(pos);
+        let (_, _, data_entity, batch_index) = self.prepared.swap_remove(pos);
 
         debug_assert!(!self.currently_reacting);
         self.currently_reacting = true;
         self.data_entity = data_entity;
+        self.batch_index = batch_index;
     }
 
     /// Unsets the 'is reacting' flag.
@@ -55,17 +70,31 @@ impl EventAccessTracker
         self.data_entity
     }
 
+    /// Returns `true` if no reactions are running or prepared.
+    pub(crate) fn is_idle(&self) -> bool
+    {
+        !self.currently_reacting && self.prepared.is_empty()
+    }
+
     /// Returns `true` if an reactive event is currently being processed.
-    fn is_reacting(&self) -> bool
+    pub(crate) fn is_reacting(&self) -> bool
     {
         self.currently_reacting
     }
 
     /// Returns the data entity of the most recent reactive event.
-    fn data_entity(&self) -> Entity
+    pub(crate) fn data_entity(&self) -> Entity
     {
         self.data_entity
     }
+
+    /// Returns the index of the most recent reactive event in its broadcast batch.
+    ///
+    /// This is zero for events that are not part of a batch.
+    pub(crate) fn batch_index(&self) -> usize
+    {
+        self.batch_index
+    }
 }
 
 impl Default for EventAccessTracker
@@ -75,6 +104,7 @@ impl Default for EventAccessTracker
         Self{
             currently_reacting: false,
             data_entity: Entity::from_raw(0u32),
+            batch_index: 0,
             prepared: Vec::default(),
         }
     }
@@ -106,6 +136,30 @@ impl<T: Send + Sync + 'static> BroadcastEventData<T>
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Stores data for a batch of broadcast events sent with [`ReactCommands::broadcast_batch`].
+#[derive(Component)]
+pub(crate) struct BroadcastBatchData<T: Send + Sync + 'static>
+{
+    data: Vec<T>,
+}
+
+impl<T: Send + Sync + 'static> BroadcastBatchData<T>
+{
+    /// Makes a new broadcast batch data.
+    pub(crate) fn new(data: Vec<T>) -> Self
+    {
+        Self{ data }
+    }
+
+    /// Reads the event at `index` in the batch.
+    fn read(&self, index: usize) -> Option<&T>
+    {
+        self.data.get(index)
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// Stores data for a reactive event.
 #[derive(Component)]
 pub(crate) struct EntityEventData<T: Send + Sync + 'static>
@@ -131,12 +185,85 @@ impl<T: Send + Sync + 'static> EntityEventData<T>
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Stores the shared response buffer of a broadcast sent with [`ReactCommands::broadcast_collect`].
+#[derive(Component)]
+pub(crate) struct BroadcastResponseBuffer<R: Send + 'static>
+{
+    responses: Arc<Mutex<Vec<R>>>,
+}
+
+impl<R: Send + 'static> BroadcastResponseBuffer<R>
+{
+    /// Makes a new response buffer that shares responses with `handle`.
+    pub(crate) fn new(handle: &CollectHandle<R>) -> Self
+    {
+        Self{ responses: handle.responses.clone() }
+    }
+
+    /// Adds a response.
+    fn push(&self, response: R)
+    {
+        self.responses.lock().unwrap_or_else(|err| err.into_inner()).push(response);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Handle for reading the responses to a broadcast sent with [`ReactCommands::broadcast_collect`].
+///
+/// Responses are stored in reactor run order. The handle shares the response buffer with the broadcast's event data,
+/// which is dropped after the last reactor reading the event has run, so once the tree of system commands that sent
+/// the broadcast ends the handle is the only owner of the buffer.
+pub struct CollectHandle<R: Send + 'static>
+{
+    responses: Arc<Mutex<Vec<R>>>,
+}
+
+impl<R: Send + 'static> CollectHandle<R>
+{
+    pub(crate) fn new() -> Self
+    {
+        Self{ responses: Arc::new(Mutex::new(Vec::new())) }
+    }
+
+    /// Takes all responses collected so far.
+    pub fn take(&self) -> Vec<R>
+    {
+        std::mem::take(&mut *self.responses.lock().unwrap_or_else(|err| err.into_inner()))
+    }
+
+    /// Returns the number of responses collected so far.
+    pub fn len(&self) -> usize
+    {
+        self.responses.lock().unwrap_or_else(|err| err.into_inner()).len()
+    }
+
+    /// Returns `true` if no responses have been collected.
+    pub fn is_empty(&self) -> bool
+    {
+        self.len() == 0
+    }
+}
+
+impl<R: Send + 'static> Clone for CollectHandle<R>
+{
+    fn clone(&self) -> Self
+    {
+        Self{ responses: self.responses.clone() }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// System parameter for reading broadcast event data.
 ///
 /// Can only be used within [`SystemCommands`](super::SystemCommand).
 ///
 /// Use [`broadcast`](crate::prelude::broadcast) to make a trigger that will read these events.
 ///
+/// When events are sent with [`ReactCommands::broadcast_batch`], the reader reads the event in the batch that the
+/// current reaction is for.
+///
 /*
 ```rust
 fn example(mut c: Commands)
@@ -160,6 +287,9 @@ pub struct BroadcastEvent<'w, 's, T: Send + Sync + 'static>
 {
     tracker: Res<'w, EventAccessTracker>,
     data: Query<'w, 's, &'static BroadcastEventData<T>>,
+    batch_data: Query<'w, 's, &'static BroadcastBatchData<T>>,
+    /// Events with [`broadcast_topic`](crate::prelude::broadcast_topic) reactors are stored type-erased.
+    topic_data: Query<'w, 's, &'static TopicEventData>,
 }
 
 impl<'w, 's, T: Send + Sync + 'static> BroadcastEvent<'w, 's, T>
@@ -180,11 +310,32 @@ impl<'w, 's, T: Send + Sync + 'static> BroadcastEvent<'w, 's, T>
     {
         let t = type_name::<T>();
         if !self.tracker.is_reacting() { return Err(CobwebReactError::BroadcastEvent(t)); }
-        let Ok(data) = self.data.get(self.tracker.data_entity()) else {
-            return Err(CobwebReactError::BroadcastEvent(t));
-        };
+        let data_entity = self.tracker.data_entity();
+        if let Ok(data) = self.data.get(data_entity) { return Ok(data.read()); }
+        if let Ok(batch) = self.batch_data.get(data_entity)
+        {
+            return batch.read(self.tracker.batch_index()).ok_or(CobwebReactError::BroadcastEvent(t));
+        }
+        self.topic_data.get(data_entity)
+            .ok()
+            .and_then(|data| data.downcast::<T>())
+            .ok_or(CobwebReactError::BroadcastEvent(t))
+    }
 
-        Ok(data.read())
+    /// Returns the entity where the event data is stored.
+    ///
+    /// This is intended for extensions that need to attach extra components to or inspect the event data. The entity
+    /// is despawned after the last reactor reading the event has run, so it should not be used outside the reactor.
+    ///
+    /// Returns `None` if there is no event.
+    pub fn data_entity(&self) -> Option<Entity>
+    {
+        if !self.tracker.is_reacting() { return None; }
+        let data_entity = self.tracker.data_entity();
+        let has_data = self.data.contains(data_entity) ||
+            self.batch_data.contains(data_entity) ||
+            self.topic_data.get(data_entity).is_ok_and(|data| data.downcast::<T>().is_some());
+        has_data.then_some(data_entity)
     }
 
     /// Returns `true` if there is nothing to read.
@@ -198,6 +349,128 @@ impl<'w, 's, T: Send + Sync + 'static> BroadcastEvent<'w, 's, T>
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// System parameter for reading broadcast events sent with [`ReactCommands::broadcast_arc`].
+///
+/// Can only be used within [`SystemCommands`](super::SystemCommand).
+///
+/// Use [`broadcast::<Arc<T>>`](crate::prelude::broadcast) to make a trigger that will read these events.
+///
+/// Reading returns a clone of the shared [`Arc`], which reactors can keep after the tree ends. The data entity holding
+/// the event is still despawned after the last reactor reading the event has run, but that only drops its own `Arc`,
+/// so the payload lives until every retained `Arc` is dropped.
+///
+/*
+```rust
+fn example(mut c: Commands)
+{
+    c.react().on(
+        broadcast::<Arc<Vec<u8>>>(),
+        |event: ArcBroadcastEvent<Vec<u8>>, mut saved: ResMut<SavedPayload>|
+        {
+            saved.0 = Some(event.read());
+        }
+    );
+
+    c.react().broadcast_arc(Arc::new(vec![0u8; 1 << 20]));
+}
+```
+*/
+#[derive(SystemParam)]
+pub struct ArcBroadcastEvent<'w, 's, T: Send + Sync + 'static>
+{
+    event: BroadcastEvent<'w, 's, Arc<T>>,
+}
+
+impl<'w, 's, T: Send + Sync + 'static> ArcBroadcastEvent<'w, 's, T>
+{
+    /// Reads broadcast event data.
+    ///
+    /// Panics if there is no data to read.
+    pub fn read(&self) -> Arc<T>
+    {
+        self.event.read().clone()
+    }
+
+    /// See [`Self::read`].
+    pub fn try_read(&self) -> Result<Arc<T>, CobwebReactError>
+    {
+        self.event.try_read().cloned()
+    }
+
+    /// Returns `true` if there is nothing to read.
+    pub fn is_empty(&self) -> bool
+    {
+        self.event.is_empty()
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// System parameter for responding to broadcasts sent with [`ReactCommands::broadcast_collect`].
+///
+/// Can only be used within [`SystemCommands`](super::SystemCommand).
+///
+/// Responses are collected in reactor run order and can be read from the [`CollectHandle`] returned when the
+/// broadcast was sent.
+///
+/*
+```rust
+fn example(mut c: Commands)
+{
+    c.react().on(
+        broadcast::<()>(),
+        |responder: BroadcastResponder<usize>|
+        {
+            responder.respond(1);
+        }
+    );
+
+    let handle = c.react().broadcast_collect::<(), usize>(());
+}
+```
+*/
+#[derive(SystemParam)]
+pub struct BroadcastResponder<'w, 's, R: Send + 'static>
+{
+    tracker: Res<'w, EventAccessTracker>,
+    buffer: Query<'w, 's, &'static BroadcastResponseBuffer<R>>,
+}
+
+impl<'w, 's, R: Send + 'static> BroadcastResponder<'w, 's, R>
+{
+    /// Adds a response to the current broadcast.
+    ///
+    /// Panics if the current reaction is not for a broadcast that collects `R`.
+    pub fn respond(&self, response: R)
+    {
+        self.try_respond(response).unwrap_or_else(|_| panic!("failed responding with {}, there is no broadcast \
+            collecting responses", type_name::<R>()))
+    }
+
+    /// See [`Self::respond`].
+    ///
+    /// The response is dropped if there is no broadcast to respond to.
+    pub fn try_respond(&self, response: R) -> Result<(), CobwebReactError>
+    {
+        let t = type_name::<R>();
+        if !self.tracker.is_reacting() { return Err(CobwebReactError::BroadcastResponder(t)); }
+        let Ok(buffer) = self.buffer.get(self.tracker.data_entity()) else {
+            return Err(CobwebReactError::BroadcastResponder(t));
+        };
+
+        buffer.push(response);
+        Ok(())
+    }
+
+    /// Returns `true` if the current reaction is for a broadcast that collects `R`.
+    pub fn can_respond(&self) -> bool
+    {
+        self.tracker.is_reacting() && self.buffer.contains(self.tracker.data_entity())
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// System parameter for reading entity event data.
 ///
 /// Can only be used within [`SystemCommands`](super::SystemCommand).
@@ -270,6 +543,19 @@ impl<'w, 's, T: Send + Sync + 'static> EntityEvent<'w, 's, T>
         self.try_read().map(|(e, _)| e)
     }
 
+    /// Returns the entity where the event data is stored.
+    ///
+    /// This is intended for extensions that need to attach extra components to or inspect the event data. The entity
+    /// is despawned after the last reactor reading the event has run, so it should not be used outside the reactor.
+    ///
+    /// Returns `None` if there is no event.
+    pub fn data_entity(&self) -> Option<Entity>
+    {
+        if !self.tracker.is_reacting() { return None; }
+        let data_entity = self.tracker.data_entity();
+        self.data.contains(data_entity).then_some(data_entity)
+    }
+
     /// Returns `true` if there is nothing to read.
     ///
     /// Equivalent to `event.try_read().is_ok()`.
@@ -43,6 +43,17 @@ pub trait ReactAppExt
     fn add_entity_reactor<R: EntityWorldReactor>(&mut self, reactor: R) -> &mut Self;
     /// Provides access to [`ReactCommands`].
     fn react<T>(&mut self, callback: impl FnOnce(&mut ReactCommands) -> T) -> &mut Self;
+    /// Forwards Bevy input events as [`broadcast`] reactions.
+    ///
+    /// Each [`KeyboardInput`](bevy::input::keyboard::KeyboardInput),
+    /// [`MouseButtonInput`](bevy::input::mouse::MouseButtonInput), [`MouseWheel`](bevy::input::mouse::MouseWheel),
+    /// and [`TouchInput`](bevy::input::touch::TouchInput) event is cloned and sent with [`ReactCommands::broadcast`]
+    /// in `PreUpdate` after [`InputSystem`](bevy::input::InputSystem), so reactors also see the updated input
+    /// resources (e.g. `ButtonInput<KeyCode>`). Events are forwarded in that order by type, and in send order within
+    /// each type.
+    ///
+    /// Calling this more than once has no additional effect.
+    fn add_input_broadcasts(&mut self) -> &mut Self;
 }
 
 impl ReactAppExt for App
@@ -110,7 +121,8 @@ impl ReactAppExt for App
             panic!("duplicate entity world reactors of type {:?} are not allowed", std::any::type_name::<R>());
         }
         let sys_command = self.world_mut().spawn_system_command_from(reactor.reactor());
-        self.world_mut().insert_resource(EntityWorldReactorRes::<R>::new(sys_command));
+        let cleanup = self.world_mut().spawn_system_command(prune_despawned_reactor_entity::<R>);
+        self.world_mut().insert_resource(EntityWorldReactorRes::<R>::new(sys_command, cleanup));
         self
     }
 
@@ -120,6 +132,12 @@ impl ReactAppExt for App
         let _ = self.world_mut().react(callback);
         self
     }
+
+    fn add_input_broadcasts(&mut self) -> &mut Self
+    {
+        setup_input_broadcasts(self);
+        self
+    }
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -143,6 +161,14 @@ pub trait ReactWorldExt
     /// To run the system, schedule it with `commands.queue(system_command)`.
     fn spawn_system_command_from(&mut self, callback: SystemCommandCallback) -> SystemCommand;
 
+    /// Spawns a [`SystemCommand`] from a callback that receives the command's own id.
+    ///
+    /// See [`spawn_system_command_self`](crate::prelude::spawn_system_command_self).
+    fn spawn_system_command_self(
+        &mut self,
+        callback: impl FnMut(SystemCommand, &mut World, SystemCommandCleanup) + Send + Sync + 'static
+    ) -> SystemCommand;
+
     /// Provides access to [`ReactCommands`].
     fn react<T>(&mut self, callback: impl FnOnce(&mut ReactCommands) -> T) -> T;
 
@@ -150,19 +176,69 @@ pub trait ReactWorldExt
     ///
     /// The target system can consume the event with the [`SystemEvent`] system parameter.
     ///
-    /// If scheduled from user-land, this will cause a [`reaction_tree()`] to execute, otherwise it will be
-    /// processed within the already-running reaction tree.
+    /// If scheduled from user-land, this will start a new tree of system commands, otherwise it will be processed
+    /// within the already-running tree.
     fn send_system_event<T: Send + Sync + 'static>(&mut self, command: SystemCommand, event: T);
 
     /// Sends a broadcasted event.
     /// - Reactors can listen for the event with the [`broadcast()`] trigger.
     /// - Reactors can read the event with the [`BroadcastEvent`] system parameter.
+    ///
+    /// Unlike [`ReactCommands::broadcast`], this is synchronous: all reactions have run when it returns.
     fn broadcast<E: Send + Sync + 'static>(&mut self, event: E);
 
+    /// Sends a broadcasted event on a specific channel.
+    /// - Reactors can listen for the event with the [`broadcast_channel()`] trigger.
+    /// - Reactors can read the event with the [`BroadcastEvent`] system parameter.
+    ///
+    /// Unlike [`ReactCommands::broadcast_on_channel`], this is synchronous: all reactions have run when it returns.
+    fn broadcast_on_channel<E: Send + Sync + 'static>(&mut self, channel: ChannelId, event: E);
+
     /// Sends an entity-targeted event.
     /// - Reactors can listen for the event with the [`entity_event()`] trigger.
     /// - Reactors can read the event with the [`EntityEvent`] system parameter.
+    ///
+    /// Unlike [`ReactCommands::entity_event`], this is synchronous: all reactions have run when it returns.
     fn entity_event<E: Send + Sync + 'static>(&mut self, entity: Entity, event: E);
+
+    /// Inserts a [`React<C>`] built with [`FromWorld`] on `entity` if the entity doesn't have one.
+    ///
+    /// Returns `true` if the component was inserted. See [`ReactCommands::ensure_component`].
+    fn ensure_component<C: ReactComponent + FromWorld>(&mut self, entity: Entity) -> bool;
+
+    /// Begins a tree of system commands that is driven manually one reaction at a time.
+    ///
+    /// See [`SteppedTree`].
+    ///
+    /// Panics if a tree of system commands is running or a stepped tree is already active.
+    fn begin_stepped_tree(&mut self) -> SteppedTree;
+
+    /// Repeatedly collects detection-based reactions until no reactions run, for at most `max_iters` passes.
+    ///
+    /// Each pass applies pending commands, garbage collects auto-despawned entities, then runs the collection steps
+    /// that the [`ReactPlugin`] normally runs in `Last` (Bevy resource and component changes, asset loads, removals,
+    /// and despawns). Reactions run by a pass can cause changes that are only detected in the next pass (e.g. a
+    /// [`bevy_component_changed`] reactor changing another component). The world is stable when a pass runs no
+    /// reactions, which means all pending commands, removals, despawns, and detected changes were handled.
+    ///
+    /// Returns the number of passes that ran reactions. If every pass ran reactions then a warning is logged, since
+    /// there may be an infinite cascade, and `max_iters` is returned.
+    ///
+    /// Hierarchy changes and reactors rescheduled with [`ReactCommands::reschedule_current_next_tree`] are not
+    /// collected.
+    ///
+    /// Panics if a tree of system commands is running.
+    fn settle_reactions(&mut self, max_iters: usize) -> usize;
+
+    /// Mutates several [`ReactResources`](ReactResource) atomically.
+    ///
+    /// `f` mutates snapshots of the resources through [`TransactionCtx`]. Each resource is cloned the first time it
+    /// is accessed mutably. If `f` returns `Ok`, all snapshots are written to the world at once, then resource
+    /// mutation reactions are triggered for each accessed resource (in order of first access). Reactors will see
+    /// every change made by the transaction. If `f` returns `Err`, the snapshots are discarded and no reactions run.
+    ///
+    /// This is synchronous: all reactions have run when it returns.
+    fn transaction<E>(&mut self, f: impl FnOnce(&mut TransactionCtx) -> Result<(), E>) -> Result<(), E>;
 }
 
 impl ReactWorldExt for World
@@ -179,6 +255,14 @@ impl ReactWorldExt for World
         SystemCommand(self.spawn(SystemCommandStorage::new(callback)).id())
     }
 
+    fn spawn_system_command_self(
+        &mut self,
+        callback: impl FnMut(SystemCommand, &mut World, SystemCommandCleanup) + Send + Sync + 'static
+    ) -> SystemCommand
+    {
+        spawn_system_command_self(self, callback)
+    }
+
     fn react<T>(&mut self, callback: impl FnOnce(&mut ReactCommands) -> T) -> T
     {
         let mut c = self.commands();
@@ -196,13 +280,38 @@ impl ReactWorldExt for World
 
     fn broadcast<E: Send + Sync + 'static>(&mut self, event: E)
     {
-        self.syscall(event, ReactCache::schedule_broadcast_reaction::<E>);
+        self.broadcast_on_channel(ChannelId::DEFAULT, event);
+    }
+
+    fn broadcast_on_channel<E: Send + Sync + 'static>(&mut self, channel: ChannelId, event: E)
+    {
+        self.syscall((channel, event), ReactCache::schedule_broadcast_reaction::<E>);
     }
 
     fn entity_event<E: Send + Sync + 'static>(&mut self, entity: Entity, event: E)
     {
         self.syscall((entity, event), ReactCache::schedule_entity_event_reaction::<E>);
     }
+
+    fn ensure_component<C: ReactComponent + FromWorld>(&mut self, entity: Entity) -> bool
+    {
+        ensure_react_component::<C>(self, entity)
+    }
+
+    fn begin_stepped_tree(&mut self) -> SteppedTree
+    {
+        SteppedTree::begin(self)
+    }
+
+    fn settle_reactions(&mut self, max_iters: usize) -> usize
+    {
+        settle_reactions(self, max_iters)
+    }
+
+    fn transaction<E>(&mut self, f: impl FnOnce(&mut TransactionCtx) -> Result<(), E>) -> Result<(), E>
+    {
+        run_transaction(self, f)
+    }
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -229,12 +338,23 @@ pub trait ReactCommandsExt
     /// To run the system, schedule it with `commands.queue(system_command)`.
     fn spawn_system_command_from(&mut self, callback: SystemCommandCallback) -> SystemCommand;
 
+    /// Schedules a [`SystemCommand`] to be spawned from a callback that receives the command's own id.
+    ///
+    /// The id is the entity the command will be stored on, so it is known before the command is spawned. This is
+    /// useful for commands that need to reschedule or revoke themselves.
+    ///
+    /// See [`SystemCommandCallback`] for how the [`SystemCommandCleanup`] should be used.
+    fn spawn_system_command_self(
+        &mut self,
+        callback: impl FnMut(SystemCommand, &mut World, SystemCommandCleanup) + Send + Sync + 'static
+    ) -> SystemCommand;
+
     /// Schedules a system event targeting a given [`SystemCommand`].
     ///
     /// The target system can consume the event with the [`SystemEvent`] system parameter.
     ///
-    /// If scheduled from user-land, this will cause a [`reaction_tree()`] to execute, otherwise it will be
-    /// processed within the already-running reaction tree.
+    /// If scheduled from user-land, this will start a new tree of system commands, otherwise it will be processed
+    /// within the already-running tree.
     fn send_system_event<T: Send + Sync + 'static>(&mut self, command: SystemCommand, event: T);
 }
 
@@ -257,6 +377,17 @@ impl<'w, 's> ReactCommandsExt for Commands<'w, 's>
         SystemCommand(self.spawn(SystemCommandStorage::new(callback)).id())
     }
 
+    fn spawn_system_command_self(
+        &mut self,
+        callback: impl FnMut(SystemCommand, &mut World, SystemCommandCleanup) + Send + Sync + 'static
+    ) -> SystemCommand
+    {
+        let command = SystemCommand(self.spawn_empty().id());
+        self.entity(*command)
+            .insert(SystemCommandStorage::new(SystemCommandCallback::with_self(command, callback)));
+        command
+    }
+
     fn send_system_event<T: Send + Sync + 'static>(&mut self, command: SystemCommand, event: T)
     {
         let data_entity = self.spawn(SystemEventData::new(event)).id();
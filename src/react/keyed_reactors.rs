@@ -0,0 +1,99 @@
+//local shortcuts
+use crate::prelude::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+use bevy::utils::{AHasher, HashMap};
+
+//standard shortcuts
+use std::any::{Any, TypeId};
+use std::hash::{Hash, Hasher};
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A keyed reactor entry.
+struct KeyedReactor
+{
+    key   : Box<dyn Any + Send + Sync>,
+    token : RevokeToken,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Resource that stores reactors registered with [`ReactCommands::register_keyed_reactor`].
+///
+/// Reactors are indexed by `(TypeId::of::<K>(), hash(key))`, so keys of different types never collide even if they
+/// have the same hash. Keys of the same type with colliding hashes share an index entry, and are told apart by
+/// comparing the full keys.
+///
+/// Entries are only replaced by re-registering a key. If a keyed reactor is revoked or cleaned up in some other way,
+/// then [`Self::get`] will return a [`SystemCommand`] that no longer exists.
+#[derive(Resource, Default)]
+pub struct KeyedReactors
+{
+    reactors: HashMap<(TypeId, u64), Vec<KeyedReactor>>,
+}
+
+impl KeyedReactors
+{
+    fn index<K: Hash + 'static>(key: &K) -> (TypeId, u64)
+    {
+        let mut hasher = AHasher::default();
+        key.hash(&mut hasher);
+        (TypeId::of::<K>(), hasher.finish())
+    }
+
+    /// Returns the reactor registered for `key`.
+    pub fn get<K: Hash + Eq + Send + Sync + 'static>(&self, key: &K) -> Option<SystemCommand>
+    {
+        self.reactors.get(&Self::index(key))?
+            .iter()
+            .find(|entry| entry.key.downcast_ref::<K>() == Some(key))
+            .map(|entry| entry.token.id)
+    }
+
+    /// Inserts a reactor for `key`, returning the token of the reactor it replaced.
+    fn insert<K: Hash + Eq + Send + Sync + 'static>(&mut self, key: K, token: RevokeToken) -> Option<RevokeToken>
+    {
+        let entries = self.reactors.entry(Self::index(&key)).or_default();
+        match entries.iter_mut().find(|entry| entry.key.downcast_ref::<K>() == Some(&key))
+        {
+            Some(entry) => Some(std::mem::replace(&mut entry.token, token)),
+            None =>
+            {
+                entries.push(KeyedReactor{ key: Box::new(key), token });
+                None
+            }
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Stores a keyed reactor, revoking the reactor previously registered for the same key.
+pub(crate) fn register_keyed_reactor<K: Hash + Eq + Send + Sync + 'static>(
+    In((key, token)) : In<(K, RevokeToken)>,
+    mut c            : Commands,
+    mut keyed        : ResMut<KeyedReactors>,
+){
+    let Some(old) = keyed.insert(key, token) else { return; };
+    c.react().revoke(old);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Runs the keyed reactor registered for a key.
+pub(crate) fn run_keyed_reactor<K: Hash + Eq + Send + Sync + 'static>(
+    In(key) : In<K>,
+    mut c   : Commands,
+    keyed   : Res<KeyedReactors>,
+){
+    let Some(command) = keyed.get(&key) else {
+        tracing::warn!("failed running keyed reactor, no reactor is registered for the key (type: {})",
+            std::any::type_name::<K>());
+        return;
+    };
+    c.queue(command);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -5,45 +5,79 @@
 use crate as bevy_cobweb;
 
 //module tree
+mod async_reactions;
+mod broadcast_topics;
 mod command_queue;
 mod commands;
 mod despawn_reader;
+mod diagnostics;
+mod entity_data_reader;
 mod entity_reaction_readers;
 mod entity_world_reactor;
 mod err;
 mod event_readers;
 mod extensions;
+mod keyed_reactors;
 mod plugin;
 mod react_cache;
 mod react_commands;
 mod react_component;
+mod react_diff;
+mod react_pipeline;
 mod react_resource;
+mod reaction_log;
+mod reader_check;
+mod ready_reactors;
+mod resource_mutation_readers;
 mod reaction_trigger;
 mod reaction_triggers_impl;
+mod run_count_reader;
+mod stepped_tree;
 mod syscommand_runner;
 mod system_command_spawning;
 mod system_event_reader;
+mod throttled_reactions;
+mod transaction;
+mod tree_run_log;
+mod trigger_reader;
 mod utils;
 mod world_reactor;
 
 //API exports
+pub(crate) use async_reactions::*;
+pub use broadcast_topics::*;
 pub(crate) use command_queue::*;
 pub use commands::*;
 pub use despawn_reader::*;
+pub use diagnostics::*;
+pub use entity_data_reader::*;
 pub use entity_reaction_readers::*;
 pub use entity_world_reactor::*;
 pub use err::*;
 pub use event_readers::*;
 pub use extensions::*;
+pub use keyed_reactors::*;
 pub use plugin::*;
 pub(crate) use react_cache::*;
 pub use react_commands::*;
 pub use react_component::*;
+pub use react_diff::*;
+pub use react_pipeline::*;
 pub use react_resource::*;
+pub use reaction_log::*;
+pub use reader_check::*;
+pub use ready_reactors::*;
+pub use resource_mutation_readers::*;
 pub use reaction_trigger::*;
 pub use reaction_triggers_impl::*;
+pub use run_count_reader::*;
+pub use stepped_tree::*;
 pub(crate) use syscommand_runner::*;
 pub use system_command_spawning::*;
 pub use system_event_reader::*;
+pub use throttled_reactions::*;
+pub use transaction::*;
+pub use tree_run_log::*;
+pub use trigger_reader::*;
 pub use utils::*;
 pub use world_reactor::*;
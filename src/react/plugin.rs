@@ -2,9 +2,16 @@
 use crate::prelude::*;
 
 //third-party shortcuts
+use bevy::ecs::system::SystemParam;
+use bevy::hierarchy::HierarchyEvent;
+use bevy::input::InputSystem;
+use bevy::input::keyboard::KeyboardInput;
+use bevy::input::mouse::{MouseButtonInput, MouseWheel};
+use bevy::input::touch::TouchInput;
 use bevy::prelude::*;
 
 //standard shortcuts
+use core::any::TypeId;
 
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -17,8 +24,247 @@ pub(crate) struct SyscommandCounter(usize);
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Schedules reactions to Bevy resources and components that changed.
+fn schedule_resource_change_reactors(world: &mut World)
+{
+    ReactCache::schedule_resource_change_reactions(world);
+    world.flush();
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Entities collected for a reactor registered with [`ReactCommands::on_after_change_detection`].
+///
+/// Stored on the reactor's [`SystemCommand`] entity.
+#[derive(Component, Default)]
+pub(crate) struct ChangeDetectionBatch(Vec<Entity>);
+
+impl ChangeDetectionBatch
+{
+    /// Adds an entity to the batch. Entities are only added once per batch.
+    pub(crate) fn push(&mut self, entity: Entity)
+    {
+        if self.0.contains(&entity) { return; }
+        self.0.push(entity);
+    }
+}
+
+/// Runs reactors registered with [`ReactCommands::on_after_change_detection`] with the entities collected since
+/// their last run.
+fn run_after_change_detection_reactors(mut c: Commands, mut batches: Query<(Entity, &mut ChangeDetectionBatch)>)
+{
+    for (entity, mut batch) in batches.iter_mut()
+    {
+        if batch.0.is_empty() { continue; }
+        c.send_system_event(SystemCommand(entity), std::mem::take(&mut batch.0));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Sends hierarchy entity events to entities that have reactors listening for them.
+#[derive(SystemParam)]
+struct HierarchyReactions<'w, 's>
+{
+    c        : Commands<'w, 's>,
+    cache    : Res<'w, ReactCache>,
+    reactors : Query<'w, 's, &'static EntityReactors>,
+}
+
+impl HierarchyReactions<'_, '_>
+{
+    fn send<E: Send + Sync + 'static>(&mut self, target: Entity, event: E)
+    {
+        let rtype = EntityReactionType::Event(TypeId::of::<E>());
+        let has_entity_reactors = self.reactors.get(target).map(|r| r.count(rtype) > 0).unwrap_or_default();
+        if !has_entity_reactors && !self.cache.has_any_entity_event_reactors::<E>() { return; }
+        self.c.react().entity_event(target, event);
+    }
+
+    fn send_parent_changed(&mut self, child: Entity, old: Option<Entity>, new: Option<Entity>)
+    {
+        if old == new { return; }
+        self.send(child, ParentChanged{ old, new });
+        if let Some(old) = old { self.send(old, ChildrenChanged::Removed(child)); }
+        if let Some(new) = new { self.send(new, ChildrenChanged::Added(child)); }
+    }
+}
+
+/// Schedules reactions to hierarchy changes reported by Bevy's [`HierarchyEvent`]s.
+fn schedule_hierarchy_reactors(mut events: EventReader<HierarchyEvent>, mut reactions: HierarchyReactions)
+{
+    for event in events.read()
+    {
+        match *event
+        {
+            HierarchyEvent::ChildAdded{ child, parent } =>
+            {
+                reactions.send_parent_changed(child, None, Some(parent));
+            }
+            HierarchyEvent::ChildRemoved{ child, parent } =>
+            {
+                reactions.send_parent_changed(child, Some(parent), None);
+            }
+            HierarchyEvent::ChildMoved{ child, previous_parent, new_parent } =>
+            {
+                reactions.send_parent_changed(child, Some(previous_parent), Some(new_parent));
+            }
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Schedules reactions for the next reactors of broadcasts sent with [`ReactCommands::broadcast_spread`].
+fn schedule_spread_broadcast_reactors(world: &mut World)
+{
+    world.resource_scope(|world: &mut World, mut cache: Mut<ReactCache>| {
+        cache.schedule_spread_broadcast_reactions(world);
+    });
+    world.flush();
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Schedules reactions to assets that finished loading.
+#[cfg(feature = "bevy_asset")]
+fn schedule_asset_load_reactors(world: &mut World)
+{
+    ReactCache::schedule_asset_load_reactions(world);
+    world.flush();
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Runs detection passes until no reactions run. See [`ReactWorldExt::settle_reactions`].
+pub(crate) fn settle_reactions(world: &mut World, max_iters: usize) -> usize
+{
+    if **world.resource::<SyscommandCounter>() != 0
+    { panic!("failed settling reactions, a tree of system commands is running"); }
+
+    for iter in 0..max_iters
+    {
+        let start = world.resource::<ReactionCounter>().get();
+
+        world.flush();
+        garbage_collect_entities(world);
+        schedule_resource_change_reactors(world);
+        #[cfg(feature = "bevy_asset")]
+        schedule_asset_load_reactors(world);
+        schedule_removal_and_despawn_reactors(world);
+        world.flush();
+
+        // The world is stable once a pass runs no reactions.
+        if world.resource::<ReactionCounter>().get() == start { return iter; }
+    }
+
+    tracing::warn!(max_iters, "reactions did not settle within the iteration cap, there may be an infinite cascade");
+    max_iters
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Marks that input broadcasts were added to an app.
+#[derive(Resource)]
+struct InputBroadcasts;
+
+/// Broadcasts Bevy input events of type `E`.
+fn broadcast_input_events<E: Event + Clone>(mut c: Commands, mut events: EventReader<E>)
+{
+    for event in events.read()
+    {
+        c.react().broadcast(event.clone());
+    }
+}
+
+/// Adds systems that forward Bevy input events as broadcasts.
+///
+/// See [`ReactAppExt::add_input_broadcasts`].
+pub(crate) fn setup_input_broadcasts(app: &mut App)
+{
+    if app.world().contains_resource::<InputBroadcasts>() { return; }
+    app.insert_resource(InputBroadcasts)
+        .add_event::<KeyboardInput>()
+        .add_event::<MouseButtonInput>()
+        .add_event::<MouseWheel>()
+        .add_event::<TouchInput>()
+        .add_systems(PreUpdate,
+            (
+                broadcast_input_events::<KeyboardInput>,
+                broadcast_input_events::<MouseButtonInput>,
+                broadcast_input_events::<MouseWheel>,
+                broadcast_input_events::<TouchInput>,
+            )
+                .chain()
+                .after(InputSystem)
+        );
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Tracks the number of live system commands (including reactors) to detect leaks in debug builds.
+#[cfg(debug_assertions)]
+#[derive(Resource)]
+struct ReactorLeakGuard
+{
+    /// Warnings are emitted when the count reaches multiples of this threshold.
+    threshold: usize,
+    /// The count at which the next warning may be emitted.
+    next_warning: usize,
+    /// The count from the last check.
+    last_count: usize,
+    /// Whether the count decreased since the last threshold was reached.
+    decreased: bool,
+}
+
+#[cfg(debug_assertions)]
+impl ReactorLeakGuard
+{
+    fn new(threshold: usize) -> Self
+    {
+        let threshold = threshold.max(1);
+        Self{ threshold, next_warning: threshold, last_count: 0, decreased: false }
+    }
+}
+
+/// Warns if the number of live system commands grew monotonically past a multiple of the leak threshold.
+#[cfg(debug_assertions)]
+fn check_reactor_leaks(mut guard: ResMut<ReactorLeakGuard>, syscommands: Query<(), With<SystemCommandStorage>>)
+{
+    let count = syscommands.iter().count();
+    if count < guard.last_count { guard.decreased = true; }
+    guard.last_count = count;
+    if count < guard.next_warning { return; }
+
+    if !guard.decreased
+    {
+        tracing::warn!("{count} system commands (including reactors) are alive and the count has only grown; this \
+            may be a leak (e.g. registering reactors every frame without revoking them or using `once`)");
+    }
+
+    guard.next_warning = (count / guard.threshold + 1) * guard.threshold;
+    guard.decreased = false;
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// Prepares the react framework so that reactors may be registered with [`ReactCommands`].
 /// - Un-handled removals and despawns will be automatically processed in `Last`.
+/// - Changes to Bevy resources for [`bevy_resource_changed`] triggers are processed in `Last`.
+/// - Changes to Bevy components for [`bevy_component_added`] and [`bevy_component_changed`] triggers are processed in
+///   `Last`.
+/// - With the `bevy_asset` feature, asset loads for [`asset_loaded`] triggers are processed in `Last` after
+///   [`AssetEvents`](bevy::asset::AssetEvents).
+/// - Broadcasts sent with [`ReactCommands::broadcast_spread`] continue running their reactors in `First`.
+/// - Reactors rescheduled with [`ReactCommands::reschedule_current_next_tree`] run in `First` if no other tree ran
+///   them first.
+/// - Hierarchy changes for [`entity_parent_changed`] and [`entity_children_changed`] triggers are processed in `Last`.
+/// - Reactors registered with [`ReactCommands::on_after_change_detection`] run at the start of `Last`.
+/// - Use [`ReactPlugin::fixed`] to also process removals, despawns, and resource changes after each fixed timestep.
+///
+/// Reactions are scheduled in a deterministic order, so the same sequence of changes produces the same sequence of
+/// reactions across runs. Reactors for a trigger run in registration order, and removals, Bevy resource changes, and
+/// asset loads are processed by trigger type in the order the trigger types were first registered.
 pub struct ReactPlugin;
 
 impl Plugin for ReactPlugin
@@ -35,8 +281,207 @@ impl Plugin for ReactPlugin
             .init_resource::<EntityReactionAccessTracker>()
             .init_resource::<EventAccessTracker>()
             .init_resource::<DespawnAccessTracker>()
+            .init_resource::<SyscommandTracker>()
+            .init_resource::<TreeResults>()
+            .init_resource::<SteppedTreeQueue>()
+            .init_resource::<AsyncReactionTasks>()
+            .init_resource::<ReactionCounter>()
+            .init_resource::<RemovalValueCaptures>()
+            .init_resource::<ResourceMutationTracker>()
+            .init_resource::<KeyedReactors>()
+            .init_resource::<Time>()
+            .add_event::<HierarchyEvent>()
             .setup_auto_despawn()
-            .add_systems(Last, schedule_removal_and_despawn_reactors.after(AutoDespawnSet));
+            .add_systems(First, schedule_spread_broadcast_reactors)
+            .add_systems(First, poll_async_reactions.after(schedule_spread_broadcast_reactors))
+            .add_systems(First, run_trailing_throttled_reactors.after(poll_async_reactions))
+            .add_systems(First, run_next_tree_reactors.after(run_trailing_throttled_reactors))
+            .add_systems(Last, schedule_removal_and_despawn_reactors.after(AutoDespawnSet))
+            .add_systems(Last, schedule_resource_change_reactors.before(schedule_removal_and_despawn_reactors))
+            .add_systems(Last, schedule_hierarchy_reactors.before(schedule_resource_change_reactors))
+            .add_systems(Last, run_after_change_detection_reactors.before(schedule_hierarchy_reactors));
+
+        #[cfg(feature = "bevy_asset")]
+        app.add_systems(Last,
+            schedule_asset_load_reactors
+                .after(bevy::asset::AssetEvents)
+                .before(schedule_removal_and_despawn_reactors)
+        );
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+impl ReactPlugin
+{
+    /// Makes a [`ReactPlugin`] that warns about leaked reactors in debug builds.
+    ///
+    /// Each reactor registration allocates a system command with its own system state, so registering reactors
+    /// repeatedly (e.g. in a per-frame system) without revoking them or using [`ReactCommands::once`] will leak memory.
+    ///
+    /// A warning is logged each time the number of live system commands reaches a multiple of `threshold`, unless the
+    /// count decreased at some point since the previous multiple was reached. This is only a heuristic: an app that
+    /// legitimately accumulates many long-lived reactors will also trigger it, in which case the threshold should be
+    /// raised.
+    ///
+    /// The leak check is compiled out in release builds.
+    pub fn with_leak_warning(self, threshold: usize) -> ReactPluginWithLeakWarning
+    {
+        ReactPluginWithLeakWarning{ threshold }
+    }
+
+    /// Makes a [`ReactPlugin`] that counts how many times each trigger type fires.
+    ///
+    /// Counts can be read with [`ReactDiagnostics::trigger_fire_counts`] to find hot trigger types. They are
+    /// cumulative by default, use [`ReactPluginWithMetrics::reset`] to reset them each frame instead.
+    ///
+    /// When metrics are disabled, the only overhead is one branch per scheduled trigger. When enabled, each trigger
+    /// fire also locks a mutex and does a hash map lookup.
+    pub fn with_metrics(self, enabled: bool) -> ReactPluginWithMetrics
+    {
+        ReactPluginWithMetrics{ enabled, reset: TriggerMetricsReset::default() }
+    }
+
+    /// Makes a [`ReactPlugin`] that records recent system command runs in a [`ReactionLog`] resource.
+    ///
+    /// Each run of a system command (including reactors) is logged with its trigger, tree of system commands, depth in
+    /// the tree, and [`Time::elapsed`], so reaction cascades can be inspected after the fact. At most `capacity`
+    /// entries are kept.
+    ///
+    /// When the log is disabled, the only overhead is one resource lookup per system command run.
+    pub fn with_reaction_log(self, capacity: usize) -> ReactPluginWithReactionLog
+    {
+        ReactPluginWithReactionLog{ capacity }
+    }
+
+    /// Makes a [`ReactPlugin`] that also processes removals, despawns, and Bevy resource changes at the end of each
+    /// fixed timestep (in `FixedLast`).
+    ///
+    /// Reactions triggered with [`ReactCommands`] always run as soon as the triggering commands are applied, in any
+    /// schedule. Only the detection-based triggers ([`entity_removal`], [`removal`], [`despawn`],
+    /// [`bevy_resource_changed`], [`bevy_component_added`], [`bevy_component_changed`]) are collected by scheduled
+    /// systems. With this plugin they are collected after every
+    /// fixed step, so fixed-step gameplay (e.g. physics despawning entities) sees their reactions within the same
+    /// step regardless of how many fixed steps run in a frame.
+    ///
+    /// They are still collected in `Last`, so removals and despawns from `Update` (or frames with no fixed steps)
+    /// react at the end of the frame, not on the next fixed step. Run [`schedule_removal_and_despawn_reactors`]
+    /// manually if you need them sooner. Asset loads are only collected in `Last`.
+    ///
+    /// The tradeoff is the cost of running the collection systems once per fixed step in addition to once per frame.
+    pub fn fixed() -> ReactPluginFixed
+    {
+        ReactPluginFixed
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A [`ReactPlugin`] that also processes removals, despawns, and Bevy resource changes in `FixedLast`.
+///
+/// See [`ReactPlugin::fixed`].
+pub struct ReactPluginFixed;
+
+impl Plugin for ReactPluginFixed
+{
+    fn build(&self, app: &mut App)
+    {
+        if !app.is_plugin_added::<ReactPlugin>()
+        {
+            app.add_plugins(ReactPlugin);
+        }
+
+        app.add_systems(FixedLast, garbage_collect_entities.in_set(AutoDespawnSet))
+            .add_systems(FixedLast, schedule_removal_and_despawn_reactors.after(AutoDespawnSet))
+            .add_systems(FixedLast, schedule_resource_change_reactors.before(schedule_removal_and_despawn_reactors));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A [`ReactPlugin`] that warns about leaked reactors in debug builds.
+///
+/// See [`ReactPlugin::with_leak_warning`].
+pub struct ReactPluginWithLeakWarning
+{
+    #[cfg_attr(not(debug_assertions), allow(dead_code))]
+    threshold: usize,
+}
+
+impl Plugin for ReactPluginWithLeakWarning
+{
+    fn build(&self, app: &mut App)
+    {
+        if !app.is_plugin_added::<ReactPlugin>()
+        {
+            app.add_plugins(ReactPlugin);
+        }
+
+        #[cfg(debug_assertions)]
+        app.insert_resource(ReactorLeakGuard::new(self.threshold))
+            .add_systems(Last, check_reactor_leaks.after(schedule_removal_and_despawn_reactors));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A [`ReactPlugin`] that counts trigger fires.
+///
+/// See [`ReactPlugin::with_metrics`].
+pub struct ReactPluginWithMetrics
+{
+    enabled: bool,
+    reset: TriggerMetricsReset,
+}
+
+impl ReactPluginWithMetrics
+{
+    /// Sets when the trigger fire counts are reset.
+    pub fn reset(mut self, reset: TriggerMetricsReset) -> Self
+    {
+        self.reset = reset;
+        self
+    }
+}
+
+impl Plugin for ReactPluginWithMetrics
+{
+    fn build(&self, app: &mut App)
+    {
+        if !app.is_plugin_added::<ReactPlugin>()
+        {
+            app.add_plugins(ReactPlugin);
+        }
+        if !self.enabled { return; }
+
+        app.world_mut().resource_mut::<ReactCache>().enable_trigger_metrics();
+        if self.reset == TriggerMetricsReset::PerFrame
+        {
+            app.add_systems(First, reset_trigger_metrics.before(schedule_spread_broadcast_reactors));
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A [`ReactPlugin`] that records a [`ReactionLog`].
+///
+/// See [`ReactPlugin::with_reaction_log`].
+pub struct ReactPluginWithReactionLog
+{
+    capacity: usize,
+}
+
+impl Plugin for ReactPluginWithReactionLog
+{
+    fn build(&self, app: &mut App)
+    {
+        if !app.is_plugin_added::<ReactPlugin>()
+        {
+            app.add_plugins(ReactPlugin);
+        }
+
+        app.insert_resource(ReactionLog::new(self.capacity));
     }
 }
 
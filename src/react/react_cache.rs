@@ -2,12 +2,18 @@
 use crate::prelude::*;
 
 //third-party shortcuts
+#[cfg(feature = "bevy_asset")]
+use bevy::asset::UntypedAssetId;
+use bevy::ecs::world::DeferredWorld;
 use bevy::prelude::*;
 use bevy::utils::{HashMap, HashSet};
 use crossbeam::channel::{Receiver, Sender};
 
 //standard shortcuts
-use core::any::TypeId;
+use core::any::{Any, TypeId};
+use core::marker::PhantomData;
+use std::collections::VecDeque;
+use std::sync::Mutex;
 use std::vec::Vec;
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -62,9 +68,132 @@ fn collect_component_removals<C: ReactComponent>(
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Values of `C` removed from entities since the last removal pass, for [`removal_batched_with_values`] reactors.
+pub(crate) struct BatchedRemovals<C: ReactComponent>(pub(crate) Vec<(Entity, C)>);
+
+/// Broadcast event for [`bevy_component_added`] reactors.
+pub(crate) struct BevyComponentAdded<C: Component>(pub(crate) Entity, PhantomData<C>);
+
+impl<C: Component> BevyComponentAdded<C>
+{
+    fn new(entity: Entity) -> Self
+    {
+        Self(entity, PhantomData)
+    }
+}
+
+/// Broadcast event for [`bevy_component_changed`] reactors.
+pub(crate) struct BevyComponentChanged<C: Component>(pub(crate) Entity, PhantomData<C>);
+
+impl<C: Component> BevyComponentChanged<C>
+{
+    fn new(entity: Entity) -> Self
+    {
+        Self(entity, PhantomData)
+    }
+}
+
+/// Clones the value of a [`React<C>`] that is about to be removed from an entity.
+fn capture_removed_value<C: ReactComponent + Clone>(world: &mut DeferredWorld, entity: Entity)
+{
+    let Some(value) = world.get::<React<C>>(entity).map(|react| react.get().clone()) else { return; };
+    world.resource_mut::<RemovalValueCaptures>().push(entity, value);
+}
+
+/// Broadcasts the values of `C` captured since the last removal pass.
+fn flush_removed_values<C: ReactComponent + Clone>(world: &mut World)
+{
+    let Some(values) = world.resource_mut::<RemovalValueCaptures>().take::<C>() else { return; };
+    world.commands().react().broadcast(BatchedRemovals(values));
+}
+
+struct RemovalValueCapturer
+{
+    capture : fn(&mut DeferredWorld, Entity),
+    flush   : fn(&mut World),
+}
+
+/// Captures values of removed [`React<C>`] components for [`removal_batched_with_values`] reactors.
+///
+/// Removal reactions are detected with `RemovedComponents` in a later removal pass, when the removed value is already
+/// gone. Instead, values are cloned by a `React<C>` remove hook (which runs before the component is dropped) and
+/// stored here until the next removal pass.
+#[derive(Resource, Default)]
+pub(crate) struct RemovalValueCaptures
+{
+    /// Capture callbacks by component type.
+    capturers: HashMap<TypeId, RemovalValueCapturer>,
+    /// Tracked component types in tracking order, so values are flushed in a deterministic order.
+    flush_order: Vec<TypeId>,
+    /// Captured `Vec<(Entity, C)>` by component type.
+    values: HashMap<TypeId, Box<dyn Any + Send + Sync>>,
+}
+
+impl RemovalValueCaptures
+{
+    /// Starts capturing removed values of `C`.
+    pub(crate) fn track<C: ReactComponent + Clone>(&mut self)
+    {
+        if self.capturers.contains_key(&TypeId::of::<C>()) { return; }
+        self.capturers.insert(
+            TypeId::of::<C>(),
+            RemovalValueCapturer{
+                capture : capture_removed_value::<C>,
+                flush   : flush_removed_values::<C>,
+            }
+        );
+        self.flush_order.push(TypeId::of::<C>());
+    }
+
+    /// Gets the capture callback for `C` if removed values of `C` are being captured.
+    pub(crate) fn capturer<C: ReactComponent>(&self) -> Option<fn(&mut DeferredWorld, Entity)>
+    {
+        self.capturers.get(&TypeId::of::<C>()).map(|capturer| capturer.capture)
+    }
+
+    fn push<C: ReactComponent>(&mut self, entity: Entity, value: C)
+    {
+        self.values
+            .entry(TypeId::of::<C>())
+            .or_insert_with(|| Box::new(Vec::<(Entity, C)>::new()))
+            .downcast_mut::<Vec<(Entity, C)>>()
+            .expect("removal value captures should match their type ids")
+            .push((entity, value));
+    }
+
+    fn take<C: ReactComponent>(&mut self) -> Option<Vec<(Entity, C)>>
+    {
+        let values = self.values.remove(&TypeId::of::<C>())?;
+        values.downcast::<Vec<(Entity, C)>>().ok().map(|values| *values)
+    }
+
+    /// Broadcasts all captured values to [`removal_batched_with_values`] reactors.
+    pub(crate) fn flush(world: &mut World)
+    {
+        // Iterate in tracking order instead of over the values map so broadcasts are sent in a deterministic order.
+        let captures = world.resource::<RemovalValueCaptures>();
+        let flushers: Vec<fn(&mut World)> = captures
+            .flush_order
+            .iter()
+            .filter(|id| captures.values.contains_key(*id))
+            .filter_map(|id| captures.capturers.get(id).map(|c| c.flush))
+            .collect();
+
+        for flush in flushers
+        {
+            (flush)(world);
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
 struct RemovalChecker
 {
     component_id : TypeId,
+    /// Captured at registration for diagnostics.
+    type_name    : &'static str,
     checker      : SysCall<(), Vec<Entity>, Vec<Entity>>
 }
 
@@ -74,6 +203,7 @@ impl RemovalChecker
     {
         Self{
             component_id : TypeId::of::<C>(),
+            type_name    : std::any::type_name::<C>(),
             checker      : SysCall::new(|world, buffer| syscall(world, buffer, collect_component_removals::<C>)),
         }
     }
@@ -82,11 +212,133 @@ impl RemovalChecker
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Checks if a Bevy resource changed since the last check.
+///
+/// If `dispatch` is false then the change is discarded. This is used to skip stale changes when a watcher is created.
+fn read_resource_changes<R: Resource>(In(dispatch): In<bool>, resource: Option<Res<R>>) -> bool
+{
+    dispatch && resource.is_some_and(|resource| resource.is_changed())
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Broadcasts `BevyComponentAdded` events for entities where Bevy component `C` was added since the last check.
+///
+/// If `dispatch` is false then the additions are discarded.
+fn broadcast_component_additions<C: Component>(
+    In(dispatch) : In<bool>,
+    query        : Query<Entity, Added<C>>,
+    mut c        : Commands,
+){
+    if !dispatch { return; }
+    for entity in query.iter()
+    {
+        c.react().broadcast(BevyComponentAdded::<C>::new(entity));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Broadcasts `BevyComponentChanged` events for entities where Bevy component `C` changed since the last check.
+///
+/// If `dispatch` is false then the changes are discarded.
+fn broadcast_component_changes<C: Component>(
+    In(dispatch) : In<bool>,
+    query        : Query<Entity, Changed<C>>,
+    mut c        : Commands,
+){
+    if !dispatch { return; }
+    for entity in query.iter()
+    {
+        c.react().broadcast(BevyComponentChanged::<C>::new(entity));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Watches for changes to Bevy resources and components.
+struct ChangeWatcher
+{
+    watcher: SysCall<(), bool, ()>
+}
+
+impl ChangeWatcher
+{
+    fn resource<R: Resource>() -> Self
+    {
+        Self{
+            watcher: SysCall::new(
+                |world, dispatch|
+                {
+                    if !syscall(world, dispatch, read_resource_changes::<R>) { return; }
+                    syscall(world, (), ReactCache::schedule_bevy_resource_reaction::<R>);
+                }
+            ),
+        }
+    }
+
+    fn component_additions<C: Component>() -> Self
+    {
+        Self{ watcher: SysCall::new(|world, dispatch| syscall(world, dispatch, broadcast_component_additions::<C>)) }
+    }
+
+    fn component_changes<C: Component>() -> Self
+    {
+        Self{ watcher: SysCall::new(|world, dispatch| syscall(world, dispatch, broadcast_component_changes::<C>)) }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Reads asset events and schedules reactions to assets that finished loading.
+///
+/// If `dispatch` is false then events are discarded. This is used to skip stale events when a watcher is created.
+#[cfg(feature = "bevy_asset")]
+fn read_asset_loads<A: Asset>(
+    In(dispatch) : In<bool>,
+    mut events   : EventReader<AssetEvent<A>>,
+    mut commands : Commands,
+){
+    if !dispatch { events.clear(); return; }
+
+    for event in events.read()
+    {
+        let AssetEvent::LoadedWithDependencies{ id } = event else { continue; };
+        commands.syscall((*id, None), ReactCache::schedule_asset_loaded_reaction::<A>);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "bevy_asset")]
+struct AssetLoadWatcher
+{
+    watcher: SysCall<(), bool, ()>
+}
+
+#[cfg(feature = "bevy_asset")]
+impl AssetLoadWatcher
+{
+    fn new<A: Asset>() -> Self
+    {
+        Self{
+            watcher: SysCall::new(|world, dispatch| syscall(world, dispatch, read_asset_loads::<A>)),
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
 /// Schedules reactions to an entity mutation.
 fn schedule_entity_reaction_impl(
     buffer          : &mut Vec<ReactionCommand>,
     reaction_source : Entity,
     reaction_type   : EntityReactionType,
+    type_name       : &'static str,
     entity_reactors : &EntityReactors
 ){
     if let EntityReactionType::Event(id) = reaction_type
@@ -98,15 +350,110 @@ fn schedule_entity_reaction_impl(
                 ReactionCommand::EntityReaction{
                     reaction_source,
                     reaction_type,
+                    type_name,
+                    reactor,
+                }
+            );
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// An entity event retained for reactors registered later in the current tree of system commands.
+///
+/// The event's data entity has an extra count in its [`DataEntityCounter`] that is released when the tree ends.
+struct RetainedEntityEvent
+{
+    target      : Entity,
+    event       : TypeId,
+    type_name   : &'static str,
+    data_entity : Entity,
+    /// Reactors that already received the event.
+    notified    : Vec<SystemCommand>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A broadcast whose reactors are spread across frames.
+///
+/// The event's data entity counts every reactor in the snapshot, so it is cleaned up after the final reactor runs.
+struct SpreadBroadcast
+{
+    data_entity : Entity,
+    event       : TypeId,
+    type_name   : &'static str,
+    /// Reactors that haven't run yet, in registration order.
+    reactors    : VecDeque<SystemCommand>,
+    per_frame   : usize,
+}
+
+impl SpreadBroadcast
+{
+    /// Queues reactions for the next `per_frame` reactors.
+    fn queue_next(&mut self, commands: &mut Commands)
+    {
+        for _ in 0..self.per_frame
+        {
+            let Some(reactor) = self.reactors.pop_front() else { return; };
+            commands.queue(
+                ReactionCommand::BroadcastEvent{
+                    data_entity : self.data_entity,
+                    event       : self.event,
+                    type_name   : self.type_name,
                     reactor,
                 }
             );
+        }
     }
 }
 
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Counts how many times each trigger type fired.
+///
+/// Counts are stored in order of the first fire of each trigger type.
+#[derive(Default)]
+struct TriggerMetrics
+{
+    index  : HashMap<ReactorType, usize>,
+    counts : Vec<(ReactorType, u64)>,
+}
+
+impl TriggerMetrics
+{
+    fn record(&mut self, rtype: ReactorType)
+    {
+        match self.index.get(&rtype)
+        {
+            Some(idx) => self.counts[*idx].1 += 1,
+            None =>
+            {
+                self.index.insert(rtype, self.counts.len());
+                self.counts.push((rtype, 1));
+            }
+        }
+    }
+}
+
+/// Records a trigger fire if metrics are enabled.
+///
+/// When metrics are disabled this is a single branch.
+fn record_trigger_fire(metrics: &Option<Mutex<TriggerMetrics>>, rtype: ReactorType)
+{
+    let Some(metrics) = metrics else { return; };
+    metrics.lock().unwrap().record(rtype);
+}
+
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Stores reactor registrations.
+///
+/// Reaction order must be deterministic so reaction sequences are reproducible (e.g. for replays). Reactors for a
+/// trigger are stored in `Vec`s in registration order, and watchers/checkers that are polled in bulk are stored in
+/// `Vec`s in the order they were added. Maps are only used for lookups by key and are never iterated when
+/// scheduling reactions.
 #[derive(Resource)]
 pub(crate) struct ReactCache
 {
@@ -123,6 +470,11 @@ pub(crate) struct ReactCache
     /// Removal checker buffer (cached for reuse)
     removal_buffer: Option<Vec<Entity>>,
 
+    /// Reverse index of entity-specific reactors (entity -> reactors with triggers targeting the entity).
+    ///
+    /// Mirrors the [`EntityReactors`] components.
+    entity_reactor_index: HashMap<Entity, HashSet<SystemCommand>>,
+
     // Entity despawn reactors
     despawn_reactors: HashMap<Entity, Vec<ReactorHandle>>,
     /// Despawn sender (cached for reuse with new despawn trackers)
@@ -134,14 +486,90 @@ pub(crate) struct ReactCache
     any_entity_event_reactors: HashMap<TypeId, Vec<ReactorHandle>>,
 
     /// Resource mutation reactors
+    /// - [`resource_value_changed`] reactors are stored under the id of [`ResourceValueChanged<R>`].
     resource_reactors: HashMap<TypeId, Vec<ReactorHandle>>,
+    /// Value comparisons for resources with [`resource_value_changed`] reactors (resource type : check)
+    resource_change_checks: HashMap<TypeId, fn(&mut World)>,
+
+    /// Bevy resources and component events with change watchers (cached to prevent duplicate watchers)
+    watched_changes: HashSet<TypeId>,
+    /// Bevy resource and component change watchers (as a vec for efficient iteration)
+    change_watchers: Vec<ChangeWatcher>,
+    /// Bevy resource change reactors
+    bevy_resource_reactors: HashMap<TypeId, Vec<ReactorHandle>>,
 
     /// Broadcast event reactors
-    broadcast_reactors: HashMap<TypeId, Vec<ReactorHandle>>,
+    broadcast_reactors: HashMap<(TypeId, ChannelId), Vec<ReactorHandle>>,
+    /// Broadcasts with reactors that will run in later frames
+    spread_broadcasts: Vec<SpreadBroadcast>,
+    /// Broadcast topic reactors
+    topic_reactors: HashMap<TypeId, Vec<ReactorHandle>>,
+    /// Topics of broadcast event types (event type : topics)
+    event_topics: HashMap<TypeId, Vec<TypeId>>,
+
+    /// Entities collected for batched reactors in the current tree of system commands
+    batched_reactions: Vec<(SystemCommand, Vec<Entity>)>,
+
+    /// Entity events retained until the end of the current tree of system commands
+    retained_entity_events: Vec<RetainedEntityEvent>,
+
+    /// The most recent reaction version (used to tag prepared reaction metadata)
+    reaction_version: ReactionVersion,
+
+    /// Trigger fire counts (only collected if metrics are enabled)
+    ///
+    /// Scheduling systems only have shared access to the cache, so the metrics are behind a mutex.
+    trigger_metrics: Option<Mutex<TriggerMetrics>>,
+
+    /// Asset types with load watchers (cached to prevent duplicate watchers)
+    #[cfg(feature = "bevy_asset")]
+    watched_assets: HashSet<TypeId>,
+    /// Asset load watchers (as a vec for efficient iteration)
+    #[cfg(feature = "bevy_asset")]
+    asset_watchers: Vec<AssetLoadWatcher>,
+    /// Asset load reactors
+    #[cfg(feature = "bevy_asset")]
+    asset_reactors: HashMap<(TypeId, UntypedAssetId), Vec<ReactorHandle>>,
 }
 
 impl ReactCache
 {
+    /// Makes a new version for tagging the prepared metadata of one scheduled reaction.
+    pub(crate) fn next_reaction_version(&mut self) -> ReactionVersion
+    {
+        self.reaction_version = self.reaction_version.next();
+        self.reaction_version
+    }
+
+    /// Starts collecting trigger fire counts.
+    pub(crate) fn enable_trigger_metrics(&mut self)
+    {
+        if self.trigger_metrics.is_some() { return; }
+        self.trigger_metrics = Some(Mutex::new(TriggerMetrics::default()));
+    }
+
+    /// Clears the trigger fire counts.
+    pub(crate) fn reset_trigger_metrics(&mut self)
+    {
+        let Some(metrics) = &mut self.trigger_metrics else { return; };
+        *metrics.get_mut().unwrap() = TriggerMetrics::default();
+    }
+
+    /// Gets the trigger fire counts in order of the first fire of each trigger type.
+    ///
+    /// Returns an empty list if metrics are disabled.
+    pub(crate) fn trigger_fire_counts(&self) -> Vec<(ReactorType, u64)>
+    {
+        let Some(metrics) = &self.trigger_metrics else { return Vec::default(); };
+        metrics.lock().unwrap().counts.clone()
+    }
+
+    /// Records a trigger fire if metrics are enabled.
+    fn record_trigger_fire(&self, rtype: ReactorType)
+    {
+        record_trigger_fire(&self.trigger_metrics, rtype);
+    }
+
     pub(crate) fn despawn_sender(&self) -> Sender<Entity>
     {
         self.despawn_sender.clone()
@@ -198,10 +626,116 @@ impl ReactCache
             .push(handle);
     }
 
-    pub(crate) fn register_broadcast_reactor<E: 'static>(&mut self, handle: ReactorHandle)
+    /// Registers a [`resource_value_changed`] reactor for `R`, with the function that checks if `R` changed.
+    pub(crate) fn register_resource_value_changed_reactor<R: ReactResource>(
+        &mut self,
+        handle : ReactorHandle,
+        check  : fn(&mut World),
+    ){
+        self.resource_reactors
+            .entry(TypeId::of::<ResourceValueChanged<R>>())
+            .or_default()
+            .push(handle);
+        self.resource_change_checks.insert(TypeId::of::<R>(), check);
+    }
+
+    /// Returns `true` if there are [`resource_value_changed`] reactors for `R`.
+    pub(crate) fn has_resource_value_changed_reactors<R: ReactResource>(&self) -> bool
+    {
+        self.resource_reactors.contains_key(&TypeId::of::<ResourceValueChanged<R>>())
+    }
+
+    /// Adds a change watcher for Bevy resource `R` if it doesn't exist yet.
+    ///
+    /// New watchers discard changes made before the watcher was created.
+    pub(crate) fn watch_resource_changes<R: Resource>(&mut self, world: &mut World)
+    {
+        self.add_change_watcher(world, TypeId::of::<R>(), ChangeWatcher::resource::<R>);
+    }
+
+    /// Adds a watcher that broadcasts `BevyComponentAdded<C>` events if it doesn't exist yet.
+    ///
+    /// New watchers discard additions made before the watcher was created.
+    pub(crate) fn watch_component_additions<C: Component>(&mut self, world: &mut World)
+    {
+        self.add_change_watcher(world, TypeId::of::<BevyComponentAdded<C>>(), ChangeWatcher::component_additions::<C>);
+    }
+
+    /// Adds a watcher that broadcasts `BevyComponentChanged<C>` events if it doesn't exist yet.
+    ///
+    /// New watchers discard changes made before the watcher was created.
+    pub(crate) fn watch_component_changes<C: Component>(&mut self, world: &mut World)
+    {
+        self.add_change_watcher(world, TypeId::of::<BevyComponentChanged<C>>(), ChangeWatcher::component_changes::<C>);
+    }
+
+    fn add_change_watcher(&mut self, world: &mut World, key: TypeId, watcher: fn() -> ChangeWatcher)
+    {
+        if !self.watched_changes.insert(key) { return; }
+
+        let watcher = (watcher)();
+        watcher.watcher.call(world, false);
+        self.change_watchers.push(watcher);
+    }
+
+    pub(crate) fn register_bevy_resource_reactor<R: Resource>(&mut self, handle: ReactorHandle)
+    {
+        self.bevy_resource_reactors
+            .entry(TypeId::of::<R>())
+            .or_default()
+            .push(handle);
+    }
+
+    pub(crate) fn register_broadcast_reactor<E: 'static>(&mut self, channel: ChannelId, handle: ReactorHandle)
     {
         self.broadcast_reactors
-            .entry(TypeId::of::<E>())
+            .entry((TypeId::of::<E>(), channel))
+            .or_default()
+            .push(handle);
+    }
+
+    pub(crate) fn register_topic_reactor<T: BroadcastTopic>(&mut self, handle: ReactorHandle)
+    {
+        self.topic_reactors
+            .entry(TypeId::of::<T>())
+            .or_default()
+            .push(handle);
+    }
+
+    /// Adds broadcast event type `event_id` to topic `topic_id` if it isn't a member yet.
+    pub(crate) fn add_topic_member(&mut self, topic_id: TypeId, event_id: TypeId)
+    {
+        let topics = self.event_topics.entry(event_id).or_default();
+        if topics.contains(&topic_id) { return; }
+        topics.push(topic_id);
+    }
+
+    /// Adds a load watcher for asset type `A` if it doesn't exist yet.
+    ///
+    /// New watchers discard pending asset events, since assets that already finished loading are handled when
+    /// reactors are registered.
+    #[cfg(feature = "bevy_asset")]
+    pub(crate) fn watch_asset_loads<A: Asset>(&mut self, world: &mut World)
+    {
+        if self.watched_assets.contains(&TypeId::of::<A>()) { return; }
+        if !world.contains_resource::<Events<AssetEvent<A>>>()
+        {
+            tracing::warn!("failed watching asset loads for {}, the asset type is not initialized; add it to your \
+                app with app.init_asset()", std::any::type_name::<A>());
+            return;
+        }
+        self.watched_assets.insert(TypeId::of::<A>());
+
+        let watcher = AssetLoadWatcher::new::<A>();
+        watcher.watcher.call(world, false);
+        self.asset_watchers.push(watcher);
+    }
+
+    #[cfg(feature = "bevy_asset")]
+    pub(crate) fn register_asset_loaded_reactor<A: Asset>(&mut self, id: AssetId<A>, handle: ReactorHandle)
+    {
+        self.asset_reactors
+            .entry((TypeId::of::<A>(), id.untyped()))
             .or_default()
             .push(handle);
     }
@@ -214,6 +748,269 @@ impl ReactCache
             .push(handle);
     }
 
+    /// Adds an entity-specific reactor to the reverse index.
+    pub(crate) fn index_entity_reactor(&mut self, entity: Entity, reactor_id: SystemCommand)
+    {
+        self.entity_reactor_index
+            .entry(entity)
+            .or_default()
+            .insert(reactor_id);
+    }
+
+    /// Removes an entity-specific reactor from the reverse index.
+    pub(crate) fn unindex_entity_reactor(&mut self, entity: Entity, reactor_id: SystemCommand)
+    {
+        let Some(reactors) = self.entity_reactor_index.get_mut(&entity) else { return; };
+        reactors.remove(&reactor_id);
+
+        // cleanup empty hashmap entries
+        if !reactors.is_empty() { return; }
+        let _ = self.entity_reactor_index.remove(&entity);
+    }
+
+    /// Removes a reactor from the reverse index, returning the entities it was indexed for.
+    pub(crate) fn unindex_reactor(&mut self, reactor_id: SystemCommand) -> Vec<Entity>
+    {
+        let mut entities = Vec::default();
+        self.entity_reactor_index.retain(
+            |entity, reactors|
+            {
+                if reactors.remove(&reactor_id) { entities.push(*entity); }
+                !reactors.is_empty()
+            }
+        );
+        entities
+    }
+
+    /// Removes all reactors targeting an entity from the reverse index.
+    pub(crate) fn unindex_entity(&mut self, entity: Entity)
+    {
+        let _ = self.entity_reactor_index.remove(&entity);
+    }
+
+    /// Counts the distinct reactors that have at least one registered trigger.
+    pub(crate) fn count_live_reactors(&self) -> usize
+    {
+        let mut reactors = HashSet::<SystemCommand>::default();
+        let mut collect = |handles: &Vec<ReactorHandle>| reactors.extend(handles.iter().map(|h| h.sys_command()));
+
+        for component_reactors in self.component_reactors.values()
+        {
+            collect(&component_reactors.insertion_callbacks);
+            collect(&component_reactors.mutation_callbacks);
+            collect(&component_reactors.removal_callbacks);
+        }
+        self.despawn_reactors.values().for_each(&mut collect);
+        self.any_entity_event_reactors.values().for_each(&mut collect);
+        self.resource_reactors.values().for_each(&mut collect);
+        self.bevy_resource_reactors.values().for_each(&mut collect);
+        self.broadcast_reactors.values().for_each(&mut collect);
+        self.topic_reactors.values().for_each(&mut collect);
+        #[cfg(feature = "bevy_asset")]
+        self.asset_reactors.values().for_each(&mut collect);
+
+        for entity_reactors in self.entity_reactor_index.values()
+        {
+            reactors.extend(entity_reactors.iter().copied());
+        }
+
+        reactors.len()
+    }
+
+    /// Counts registered reactor handles by trigger category.
+    ///
+    /// A reactor is counted once for each trigger it is registered with.
+    pub(crate) fn reactor_counts(&self) -> ReactorCounts
+    {
+        fn sum<K>(map: &HashMap<K, Vec<ReactorHandle>>) -> usize { map.values().map(Vec::len).sum() }
+
+        ReactorCounts{
+            component: self.component_reactors
+                .values()
+                .map(|r| r.insertion_callbacks.len() + r.mutation_callbacks.len() + r.removal_callbacks.len())
+                .sum(),
+            resource: sum(&self.resource_reactors) + sum(&self.bevy_resource_reactors),
+            broadcast: sum(&self.broadcast_reactors) + sum(&self.topic_reactors),
+            despawn: sum(&self.despawn_reactors),
+            any_entity_event: sum(&self.any_entity_event_reactors),
+        }
+    }
+
+    /// Counts the reactor handles registered for a trigger type that is stored in the cache.
+    ///
+    /// Entity-specific trigger types ([`ReactorType::EntityInsertion`], [`ReactorType::EntityMutation`],
+    /// [`ReactorType::EntityRemoval`], [`ReactorType::EntityEvent`]) are stored in [`EntityReactors`] components, so
+    /// this returns zero for them.
+    pub(crate) fn reactor_count_for(&self, rtype: ReactorType) -> usize
+    {
+        let len = |handles: Option<&Vec<ReactorHandle>>| handles.map(Vec::len).unwrap_or_default();
+        let component = |comp_id: TypeId| self.component_reactors.get(&comp_id);
+
+        match rtype
+        {
+            ReactorType::EntityInsertion(..) |
+            ReactorType::EntityMutation(..) |
+            ReactorType::EntityRemoval(..) |
+            ReactorType::EntityEvent(..) => 0,
+            ReactorType::AnyEntityEvent(event_id) => len(self.any_entity_event_reactors.get(&event_id)),
+            ReactorType::ComponentInsertion(comp_id) => len(component(comp_id).map(|r| &r.insertion_callbacks)),
+            ReactorType::ComponentMutation(comp_id) => len(component(comp_id).map(|r| &r.mutation_callbacks)),
+            ReactorType::ComponentRemoval(comp_id) => len(component(comp_id).map(|r| &r.removal_callbacks)),
+            ReactorType::ResourceMutation(res_id) => len(self.resource_reactors.get(&res_id)),
+            ReactorType::BevyResourceChanged(res_id) => len(self.bevy_resource_reactors.get(&res_id)),
+            ReactorType::Broadcast(event_id) => len(self.broadcast_reactors.get(&(event_id, ChannelId::DEFAULT))),
+            ReactorType::BroadcastChannel(event_id, channel) => len(self.broadcast_reactors.get(&(event_id, channel))),
+            ReactorType::BroadcastTopic(topic_id) => len(self.topic_reactors.get(&topic_id)),
+            ReactorType::Despawn(entity) => len(self.despawn_reactors.get(&entity)),
+            #[cfg(feature = "bevy_asset")]
+            ReactorType::AssetLoaded(asset_id) => len(self.asset_reactors.get(&(asset_id.type_id(), asset_id))),
+        }
+    }
+
+    /// Collects the triggers registered for a reactor, and the entities with entity-specific triggers for it.
+    ///
+    /// Entity-specific triggers other than [`despawn`] are stored on the target entities, so they must be read from
+    /// the [`EntityReactors`] of the returned entities.
+    ///
+    /// Scans all reactors, so this is `O(n)` in the number of registered triggers.
+    pub(crate) fn collect_reactor_triggers(
+        &self,
+        reactor  : SystemCommand,
+        triggers : &mut Vec<ReactorType>,
+        entities : &mut Vec<Entity>,
+    ){
+        let has = |handles: &Vec<ReactorHandle>| handles.iter().any(|h| h.sys_command() == reactor);
+
+        for (id, component_reactors) in self.component_reactors.iter()
+        {
+            if has(&component_reactors.insertion_callbacks) { triggers.push(ReactorType::ComponentInsertion(*id)); }
+            if has(&component_reactors.mutation_callbacks) { triggers.push(ReactorType::ComponentMutation(*id)); }
+            if has(&component_reactors.removal_callbacks) { triggers.push(ReactorType::ComponentRemoval(*id)); }
+        }
+        for (entity, handles) in self.despawn_reactors.iter()
+        {
+            if has(handles) { triggers.push(ReactorType::Despawn(*entity)); }
+        }
+        for (id, handles) in self.any_entity_event_reactors.iter()
+        {
+            if has(handles) { triggers.push(ReactorType::AnyEntityEvent(*id)); }
+        }
+        for (id, handles) in self.resource_reactors.iter()
+        {
+            if has(handles) { triggers.push(ReactorType::ResourceMutation(*id)); }
+        }
+        for (id, handles) in self.bevy_resource_reactors.iter()
+        {
+            if has(handles) { triggers.push(ReactorType::BevyResourceChanged(*id)); }
+        }
+        for ((id, channel), handles) in self.broadcast_reactors.iter()
+        {
+            if !has(handles) { continue; }
+            match *channel == ChannelId::DEFAULT
+            {
+                true  => triggers.push(ReactorType::Broadcast(*id)),
+                false => triggers.push(ReactorType::BroadcastChannel(*id, *channel)),
+            }
+        }
+        for (id, handles) in self.topic_reactors.iter()
+        {
+            if has(handles) { triggers.push(ReactorType::BroadcastTopic(*id)); }
+        }
+        #[cfg(feature = "bevy_asset")]
+        for ((_, asset_id), handles) in self.asset_reactors.iter()
+        {
+            if has(handles) { triggers.push(ReactorType::AssetLoaded(*asset_id)); }
+        }
+
+        for (entity, reactors) in self.entity_reactor_index.iter()
+        {
+            if reactors.contains(&reactor) { entities.push(*entity); }
+        }
+    }
+
+    /// Iterates the reactors in the reverse index that target an entity.
+    pub(crate) fn iter_entity_reactor_index(&self, entity: Entity) -> impl Iterator<Item = SystemCommand> + '_
+    {
+        self.entity_reactor_index
+            .get(&entity)
+            .into_iter()
+            .flat_map(|reactors| reactors.iter().copied())
+    }
+
+    /// Returns `true` if there are [`any_entity_event`] reactors for event type `E`.
+    pub(crate) fn has_any_entity_event_reactors<E: 'static>(&self) -> bool
+    {
+        self.any_entity_event_reactors.contains_key(&TypeId::of::<E>())
+    }
+
+    pub(crate) fn has_despawn_reactor(&self, entity: Entity, reactor_id: SystemCommand) -> bool
+    {
+        self.despawn_reactors
+            .get(&entity)
+            .map(|handles| handles.iter().any(|handle| handle.sys_command() == reactor_id))
+            .unwrap_or_default()
+    }
+
+    /// Adds an entity to the pending batch of a batched reactor.
+    ///
+    /// Entities are only added once per batch.
+    pub(crate) fn push_batched_reaction(&mut self, batch_reactor: SystemCommand, entity: Entity)
+    {
+        match self.batched_reactions.iter_mut().find(|(reactor, _)| *reactor == batch_reactor)
+        {
+            Some((_, entities)) =>
+            {
+                if entities.contains(&entity) { return; }
+                entities.push(entity);
+            }
+            None => self.batched_reactions.push((batch_reactor, vec![entity])),
+        }
+    }
+
+    /// Takes all pending reactor batches.
+    pub(crate) fn take_batched_reactions(&mut self) -> Vec<(SystemCommand, Vec<Entity>)>
+    {
+        std::mem::take(&mut self.batched_reactions)
+    }
+
+    /// Removes retained entity events, returning their data entities so the retention counts can be released.
+    pub(crate) fn take_retained_entity_events(&mut self) -> Vec<Entity>
+    {
+        self.retained_entity_events.drain(..).map(|retained| retained.data_entity).collect()
+    }
+
+    /// Queues reactions to retained entity events for a newly-registered entity event reactor.
+    ///
+    /// If `target` is `None` then the reactor receives retained events for all targets.
+    pub(crate) fn schedule_retained_entity_event_reactions(
+        &mut self,
+        target   : Option<Entity>,
+        event    : TypeId,
+        reactor  : SystemCommand,
+        commands : &mut Commands,
+        counters : &mut Query<&mut DataEntityCounter>,
+    ){
+        for retained in self.retained_entity_events.iter_mut()
+        {
+            if retained.event != event { continue; }
+            if target.is_some_and(|target| target != retained.target) { continue; }
+            if retained.notified.contains(&reactor) { continue; }
+            let Ok(mut counter) = counters.get_mut(retained.data_entity) else { continue; };
+
+            counter.increment();
+            retained.notified.push(reactor);
+            commands.queue(
+                ReactionCommand::EntityEvent{
+                    target      : retained.target,
+                    data_entity : retained.data_entity,
+                    event,
+                    type_name   : retained.type_name,
+                    reactor,
+                }
+            );
+        }
+    }
+
     /// Revokes a component insertion reactor.
     pub(crate) fn revoke_component_reactor(&mut self, rtype: EntityReactionType, reactor_id: SystemCommand)
     {
@@ -239,20 +1036,77 @@ impl ReactCache
         {
             if handle.sys_command() != reactor_id { continue; }
             let _ = callbacks.remove(idx);
-
+
+            break;
+        }
+
+        // cleanup empty hashmap entries
+        if !reactors.is_empty() { return; }
+        let _ = self.component_reactors.remove(&comp_id);
+    }
+
+    /// Revokes a resource mutation reactor.
+    pub(crate) fn revoke_any_entity_event_reactor(&mut self, event_id: TypeId, reactor_id: SystemCommand)
+    {
+        // get callbacks
+        let Some(callbacks) = self.any_entity_event_reactors.get_mut(&event_id) else { return; };
+
+        // revoke reactor
+        for (idx, handle) in callbacks.iter().enumerate()
+        {
+            if handle.sys_command() != reactor_id { continue; }
+            let _ = callbacks.remove(idx);
+            break;
+        }
+
+        // cleanup empty hashmap entries
+        if callbacks.len() > 0 { return; }
+        let _ = self.any_entity_event_reactors.remove(&event_id);
+    }
+
+    /// Revokes a resource mutation reactor.
+    pub(crate) fn revoke_resource_mutation_reactor(&mut self, resource_id: TypeId, reactor_id: SystemCommand)
+    {
+        // get callbacks
+        let Some(callbacks) = self.resource_reactors.get_mut(&resource_id) else { return; };
+
+        // revoke reactor
+        for (idx, handle) in callbacks.iter().enumerate()
+        {
+            if handle.sys_command() != reactor_id { continue; }
+            let _ = callbacks.remove(idx);
+            break;
+        }
+
+        // cleanup empty hashmap entries
+        if callbacks.len() > 0 { return; }
+        let _ = self.resource_reactors.remove(&resource_id);
+    }
+
+    /// Revokes a Bevy resource change reactor.
+    pub(crate) fn revoke_bevy_resource_reactor(&mut self, resource_id: TypeId, reactor_id: SystemCommand)
+    {
+        // get callbacks
+        let Some(callbacks) = self.bevy_resource_reactors.get_mut(&resource_id) else { return; };
+
+        // revoke reactor
+        for (idx, handle) in callbacks.iter().enumerate()
+        {
+            if handle.sys_command() != reactor_id { continue; }
+            let _ = callbacks.remove(idx);
             break;
         }
 
         // cleanup empty hashmap entries
-        if !reactors.is_empty() { return; }
-        let _ = self.component_reactors.remove(&comp_id);
+        if !callbacks.is_empty() { return; }
+        let _ = self.bevy_resource_reactors.remove(&resource_id);
     }
 
-    /// Revokes a resource mutation reactor.
-    pub(crate) fn revoke_any_entity_event_reactor(&mut self, event_id: TypeId, reactor_id: SystemCommand)
+    /// Revokes an event reactor.
+    pub(crate) fn revoke_broadcast_reactor(&mut self, event_id: TypeId, channel: ChannelId, reactor_id: SystemCommand)
     {
         // get callbacks
-        let Some(callbacks) = self.any_entity_event_reactors.get_mut(&event_id) else { return; };
+        let Some(callbacks) = self.broadcast_reactors.get_mut(&(event_id, channel)) else { return; };
 
         // revoke reactor
         for (idx, handle) in callbacks.iter().enumerate()
@@ -264,14 +1118,14 @@ impl ReactCache
 
         // cleanup empty hashmap entries
         if callbacks.len() > 0 { return; }
-        let _ = self.any_entity_event_reactors.remove(&event_id);
+        let _ = self.broadcast_reactors.remove(&(event_id, channel));
     }
 
-    /// Revokes a resource mutation reactor.
-    pub(crate) fn revoke_resource_mutation_reactor(&mut self, resource_id: TypeId, reactor_id: SystemCommand)
+    /// Revokes a broadcast topic reactor.
+    pub(crate) fn revoke_topic_reactor(&mut self, topic_id: TypeId, reactor_id: SystemCommand)
     {
         // get callbacks
-        let Some(callbacks) = self.resource_reactors.get_mut(&resource_id) else { return; };
+        let Some(callbacks) = self.topic_reactors.get_mut(&topic_id) else { return; };
 
         // revoke reactor
         for (idx, handle) in callbacks.iter().enumerate()
@@ -283,14 +1137,16 @@ impl ReactCache
 
         // cleanup empty hashmap entries
         if callbacks.len() > 0 { return; }
-        let _ = self.resource_reactors.remove(&resource_id);
+        let _ = self.topic_reactors.remove(&topic_id);
     }
 
-    /// Revokes an event reactor.
-    pub(crate) fn revoke_broadcast_reactor(&mut self, event_id: TypeId, reactor_id: SystemCommand)
+    /// Revokes an asset load reactor.
+    #[cfg(feature = "bevy_asset")]
+    pub(crate) fn revoke_asset_loaded_reactor(&mut self, asset_id: UntypedAssetId, reactor_id: SystemCommand)
     {
         // get callbacks
-        let Some(callbacks) = self.broadcast_reactors.get_mut(&event_id) else { return; };
+        let key = (asset_id.type_id(), asset_id);
+        let Some(callbacks) = self.asset_reactors.get_mut(&key) else { return; };
 
         // revoke reactor
         for (idx, handle) in callbacks.iter().enumerate()
@@ -302,7 +1158,7 @@ impl ReactCache
 
         // cleanup empty hashmap entries
         if callbacks.len() > 0 { return; }
-        let _ = self.broadcast_reactors.remove(&event_id);
+        let _ = self.asset_reactors.remove(&key);
     }
 
     /// Revokes a despawn reactor.
@@ -324,6 +1180,45 @@ impl ReactCache
         let _ = self.despawn_reactors.remove(&entity);
     }
 
+    /// Revokes all despawn reactors for an entity.
+    pub(crate) fn revoke_all_despawn_reactors(&mut self, entity: Entity)
+    {
+        let _ = self.despawn_reactors.remove(&entity);
+    }
+
+    /// Revokes every registration of a reactor in the cache.
+    ///
+    /// This is used when the reactor's triggers are not known (e.g. there is no [`RevokeToken`]), so all
+    /// registration lists are scanned for handles that reference the reactor.
+    ///
+    /// Entity-specific registrations are stored in [`EntityReactors`] components and must be removed separately.
+    pub(crate) fn revoke_reactor_registrations(&mut self, reactor_id: SystemCommand)
+    {
+        self.component_reactors.retain(
+            |_, reactors|
+            {
+                reactors.insertion_callbacks.retain(|handle| handle.sys_command() != reactor_id);
+                reactors.mutation_callbacks.retain(|handle| handle.sys_command() != reactor_id);
+                reactors.removal_callbacks.retain(|handle| handle.sys_command() != reactor_id);
+                !reactors.is_empty()
+            }
+        );
+
+        let prune = |callbacks: &mut Vec<ReactorHandle>| -> bool
+        {
+            callbacks.retain(|handle| handle.sys_command() != reactor_id);
+            !callbacks.is_empty()
+        };
+        self.any_entity_event_reactors.retain(|_, callbacks| prune(callbacks));
+        self.resource_reactors.retain(|_, callbacks| prune(callbacks));
+        self.bevy_resource_reactors.retain(|_, callbacks| prune(callbacks));
+        self.broadcast_reactors.retain(|_, callbacks| prune(callbacks));
+        self.topic_reactors.retain(|_, callbacks| prune(callbacks));
+        self.despawn_reactors.retain(|_, callbacks| prune(callbacks));
+        #[cfg(feature = "bevy_asset")]
+        self.asset_reactors.retain(|_, callbacks| prune(callbacks));
+    }
+
     /// Queues reactions to a component insertion on an entity.
     pub(crate) fn schedule_insertion_reaction<C: ReactComponent>(
         In(entity)      : In<Entity>,
@@ -332,11 +1227,18 @@ impl ReactCache
         entity_reactors : Query<&EntityReactors>,
     ){
         let rtype = EntityReactionType::Insertion(TypeId::of::<C>());
+        cache.record_trigger_fire(ReactorType::ComponentInsertion(TypeId::of::<C>()));
 
         // entity-specific reactors
         if let Ok(entity_reactors) = entity_reactors.get(entity)
         {
-            let _ = schedule_entity_reaction_impl(&mut cache.reaction_commands_buffer, entity, rtype, &entity_reactors);
+            let _ = schedule_entity_reaction_impl(
+                &mut cache.reaction_commands_buffer,
+                entity,
+                rtype,
+                std::any::type_name::<C>(),
+                &entity_reactors
+            );
         }
 
         for command in cache.reaction_commands_buffer.drain(..) {
@@ -352,6 +1254,7 @@ impl ReactCache
                         ReactionCommand::EntityReaction{
                             reaction_source : entity,
                             reaction_type   : rtype,
+                            type_name       : std::any::type_name::<C>(),
                             reactor         : handle.sys_command(),
                         }
                     );
@@ -367,11 +1270,18 @@ impl ReactCache
         entity_reactors : Query<&EntityReactors>,
     ){
         let rtype = EntityReactionType::Mutation(TypeId::of::<C>());
+        cache.record_trigger_fire(ReactorType::ComponentMutation(TypeId::of::<C>()));
 
         // entity-specific reactors
         if let Ok(entity_reactors) = entity_reactors.get(entity)
         {
-            let _ = schedule_entity_reaction_impl(&mut cache.reaction_commands_buffer, entity, rtype, &entity_reactors);
+            let _ = schedule_entity_reaction_impl(
+                &mut cache.reaction_commands_buffer,
+                entity,
+                rtype,
+                std::any::type_name::<C>(),
+                &entity_reactors
+            );
         }
 
         for command in cache.reaction_commands_buffer.drain(..) {
@@ -387,6 +1297,7 @@ impl ReactCache
                         ReactionCommand::EntityReaction{
                             reaction_source : entity,
                             reaction_type   : rtype,
+                            type_name       : std::any::type_name::<C>(),
                             reactor         : handle.sys_command(),
                         }
                     );
@@ -394,6 +1305,50 @@ impl ReactCache
         }
     }
 
+    /// Queues reactions to a component mutation on an entity, with the component's previous value available to
+    /// [`MutationEvent::previous`].
+    ///
+    /// The previous value is stored on a data entity that is despawned after the last reactor runs.
+    pub(crate) fn schedule_tracked_mutation_reaction<C: ReactComponent>(
+        In((entity, previous)) : In<(Entity, C)>,
+        cache                  : Res<ReactCache>,
+        mut commands           : Commands,
+        entity_reactors        : Query<&EntityReactors>,
+    ){
+        let rtype = EntityReactionType::Mutation(TypeId::of::<C>());
+        cache.record_trigger_fire(ReactorType::ComponentMutation(TypeId::of::<C>()));
+
+        // get reactors
+        let entity_reactors = entity_reactors.get(entity).ok();
+        let handlers = cache.component_reactors.get(&TypeId::of::<C>()).map(|h| &h.mutation_callbacks);
+        let reactors = entity_reactors
+            .into_iter()
+            .flat_map(|e| e.iter_rtype(rtype))
+            .chain(handlers.into_iter().flatten().map(|h| h.sys_command()));
+
+        // if there are no handlers, just drop the previous value
+        let num = entity_reactors.map(|e| e.count(rtype)).unwrap_or_default()
+            + handlers.map(|h| h.len()).unwrap_or_default();
+        if num == 0 { return; }
+
+        // prep previous value
+        let data_entity = commands.spawn((DataEntityCounter::new(num), MutationPrevious::new(entity, previous))).id();
+
+        // queue reactors
+        for reactor in reactors
+        {
+            commands.queue(
+                ReactionCommand::TrackedMutation{
+                    reaction_source : entity,
+                    data_entity,
+                    component       : TypeId::of::<C>(),
+                    type_name       : std::any::type_name::<C>(),
+                    reactor,
+                }
+            );
+        }
+    }
+
     /// Schedules component removal reactors.
     pub(crate) fn schedule_removal_reactions(&mut self, world: &mut World)
     {
@@ -412,6 +1367,8 @@ impl ReactCache
             let rtype = EntityReactionType::Removal(checker.component_id);
             for entity in buffer.iter()
             {
+                record_trigger_fire(&self.trigger_metrics, ReactorType::ComponentRemoval(checker.component_id));
+
                 // entity-specific component reactors
                 if let Some(entity_reactors) = world.get_mut::<EntityReactors>(*entity)
                 {
@@ -419,6 +1376,7 @@ impl ReactCache
                             &mut commands_buff,
                             *entity,
                             rtype,
+                            checker.type_name,
                             &entity_reactors
                         );
                 }
@@ -436,6 +1394,7 @@ impl ReactCache
                             ReactionCommand::EntityReaction{
                                 reaction_source : *entity,
                                 reaction_type   : rtype,
+                                type_name       : checker.type_name,
                                 reactor         : handle.sys_command(),
                             }
                         );
@@ -455,48 +1414,122 @@ impl ReactCache
         cache               : Res<ReactCache>,
         entity_reactors     : Query<&EntityReactors>,
     ){
+        cache.schedule_entity_event_reaction_impl(target, event, usize::MAX, &mut commands, &entity_reactors);
+    }
+
+    /// Queues a reaction to an entity event for the first matching reactor.
+    pub(crate) fn schedule_entity_event_first_reaction<E: Send + Sync + 'static>(
+        In((target, event)) : In<(Entity, E)>,
+        mut commands        : Commands,
+        cache               : Res<ReactCache>,
+        entity_reactors     : Query<&EntityReactors>,
+    ){
+        cache.schedule_entity_event_reaction_impl(target, event, 1, &mut commands, &entity_reactors);
+    }
+
+    /// Queues reactions to an entity event and retains the event until the end of the current tree.
+    ///
+    /// Outside a tree of system commands the event is not retained.
+    pub(crate) fn schedule_retained_entity_event_reaction<E: Send + Sync + 'static>(
+        In((target, event)) : In<(Entity, E)>,
+        mut commands        : Commands,
+        mut cache           : ResMut<ReactCache>,
+        entity_reactors     : Query<&EntityReactors>,
+        counter             : Res<SyscommandCounter>,
+    ){
+        if **counter == 0
+        {
+            cache.schedule_entity_event_reaction_impl(target, event, usize::MAX, &mut commands, &entity_reactors);
+            return;
+        }
+
+        cache.record_trigger_fire(ReactorType::AnyEntityEvent(TypeId::of::<E>()));
+
         // get reactors
-        let entity_reactors = entity_reactors.get(target);
+        let reaction_type = EntityReactionType::Event(TypeId::of::<E>());
         let handlers = cache.any_entity_event_reactors.get(&TypeId::of::<E>());
+        let notified: Vec<SystemCommand> = entity_reactors
+            .get(target)
+            .ok()
+            .into_iter()
+            .flat_map(|e| e.iter_rtype(reaction_type))
+            .chain(handlers.into_iter().flatten().map(|h| h.sys_command()))
+            .collect();
 
-        // if there are no handlers, just drop the event data
+        // prep entity data
+        // - The extra count is released at the end of the tree.
+        let data_entity = commands
+            .spawn((DataEntityCounter::new(notified.len() + 1), EntityEventData::new(target, event)))
+            .id();
+
+        // queue reactors
+        for reactor in notified.iter()
+        {
+            commands.queue(
+                ReactionCommand::EntityEvent{
+                    target,
+                    data_entity,
+                    event     : TypeId::of::<E>(),
+                    type_name : std::any::type_name::<E>(),
+                    reactor   : *reactor,
+                }
+            );
+        }
+
+        cache.retained_entity_events.push(RetainedEntityEvent{
+            target,
+            event     : TypeId::of::<E>(),
+            type_name : std::any::type_name::<E>(),
+            data_entity,
+            notified,
+        });
+    }
+
+    /// Queues reactions to an entity event for up to `max` reactors.
+    ///
+    /// Entity-specific reactors are queued before entity-agnostic reactors, and each group is queued in
+    /// registration order.
+    fn schedule_entity_event_reaction_impl<E: Send + Sync + 'static>(
+        &self,
+        target          : Entity,
+        event           : E,
+        max             : usize,
+        commands        : &mut Commands,
+        entity_reactors : &Query<&EntityReactors>,
+    ){
+        self.record_trigger_fire(ReactorType::AnyEntityEvent(TypeId::of::<E>()));
+
+        // get reactors
         let reaction_type = EntityReactionType::Event(TypeId::of::<E>());
+        let entity_reactors = entity_reactors.get(target).ok();
+        let handlers = self.any_entity_event_reactors.get(&TypeId::of::<E>());
+        let reactors = entity_reactors
+            .into_iter()
+            .flat_map(|e| e.iter_rtype(reaction_type))
+            .chain(handlers.into_iter().flatten().map(|h| h.sys_command()))
+            .take(max);
+
+        // if there are no handlers, just drop the event data
         let num = entity_reactors.map(|e| e.count(reaction_type)).unwrap_or_default()
             + handlers.map(|h| h.len()).unwrap_or_default();
+        let num = num.min(max);
         if num == 0 { return; }
 
         // prep entity data
         let data_entity = commands.spawn((DataEntityCounter::new(num), EntityEventData::new(target, event))).id();
 
-        // entity-specific reactors
-        if let Ok(entity_reactors) = entity_reactors
-        {
-            for reactor in entity_reactors.iter_rtype(reaction_type)
-            {
-                commands.queue(
-                        ReactionCommand::EntityEvent{
-                            target,
-                            data_entity,
-                            reactor,
-                        }
-                    );
-            }
-        }
-
-        // Entity-agnostic reactors
-        if let Some(handlers) = cache.any_entity_event_reactors.get(&TypeId::of::<E>())
+        // queue reactors
+        for reactor in reactors
         {
-            // queue reactors
-            for handle in handlers.iter()
-            {
-                commands.queue(
-                    ReactionCommand::EntityEvent{
-                        target,
-                        data_entity,
-                        reactor: handle.sys_command(),
-                    }
-                );
-            }
+            commands.queue(
+                ReactionCommand::EntityEvent{
+                    target,
+                    data_entity,
+                    event     : TypeId::of::<E>(),
+                    type_name : std::any::type_name::<E>(),
+                    reactor,
+                }
+            );
         }
     }
 
@@ -505,6 +1538,7 @@ impl ReactCache
     {
         while let Ok(despawned_entity) = self.despawn_receiver.try_recv()
         {
+            self.record_trigger_fire(ReactorType::Despawn(Entity::PLACEHOLDER));
             let Some(mut despawn_reactors) = self.despawn_reactors.remove(&despawned_entity) else { continue; };
 
             // queue despawn callbacks
@@ -523,27 +1557,273 @@ impl ReactCache
 
     /// Queues reactions to a resource mutation.
     pub(crate) fn schedule_resource_mutation_reaction<R: ReactResource>(
+        cache        : Res<ReactCache>,
+        tracker      : Res<ResourceMutationTracker>,
+        mut commands : Commands,
+    ){
+        // Hide the metadata of outer mutations from these reactions.
+        let info = tracker.is_tracking(TypeId::of::<R>()).then(ResourceMutationInfo::default);
+        Self::queue_resource_mutation_reactions::<R>(&cache, &mut commands, info);
+    }
+
+    /// Queues [`resource_value_changed`] reactions for `R`.
+    pub(crate) fn schedule_resource_value_changed_reaction<R: ReactResource>(
+        cache        : Res<ReactCache>,
+        mut commands : Commands,
+    ){
+        let resource = TypeId::of::<ResourceValueChanged<R>>();
+        cache.record_trigger_fire(ReactorType::ResourceMutation(resource));
+        let Some(handlers) = cache.resource_reactors.get(&resource) else { return; };
+
+        // queue reactors
+        for handle in handlers.iter()
+        {
+            commands.queue(
+                ReactionCommand::Resource{
+                    resource,
+                    type_name : std::any::type_name::<R>(),
+                    reactor   : handle.sys_command(),
+                }
+            );
+        }
+    }
+
+    /// Queues reactions to a resource mutation that changed `fields`.
+    pub(crate) fn schedule_resource_field_mutation_reaction<R: ReactResource>(
+        In(fields)   : In<FieldMask>,
         cache        : Res<ReactCache>,
         mut commands : Commands,
     ){
+        let info = ResourceMutationInfo{ fields: Some(fields), ..default() };
+        Self::queue_resource_mutation_reactions::<R>(&cache, &mut commands, Some(info));
+    }
+
+    /// Queues reactions to a resource mutation made by `tag`.
+    pub(crate) fn schedule_tagged_resource_mutation_reaction<R: ReactResource>(
+        In(tag)      : In<ChangeTag>,
+        cache        : Res<ReactCache>,
+        mut commands : Commands,
+    ){
+        let info = ResourceMutationInfo{ tag: Some(tag), ..default() };
+        Self::queue_resource_mutation_reactions::<R>(&cache, &mut commands, Some(info));
+    }
+
+    /// Queues resource mutation reactors.
+    ///
+    /// If `info` is set, then it is readable by the reactors with [`ResourceMutationEvent`] and
+    /// [`ResourceMutationSource`].
+    fn queue_resource_mutation_reactions<R: ReactResource>(
+        cache    : &ReactCache,
+        commands : &mut Commands,
+        info     : Option<ResourceMutationInfo>,
+    ){
+        cache.record_trigger_fire(ReactorType::ResourceMutation(TypeId::of::<R>()));
+
+        // The value is compared in order with the reactions, after the mutating access is done.
+        if cache.has_resource_value_changed_reactors::<R>()
+        {
+            if let Some(check) = cache.resource_change_checks.get(&TypeId::of::<R>())
+            {
+                commands.queue(ReactionCommand::Custom(Box::new(*check)));
+            }
+        }
+
         let Some(handlers) = cache.resource_reactors.get(&TypeId::of::<R>()) else { return; };
+        if handlers.is_empty() { return; }
+
+        // The metadata is pushed and popped in order with the reactions.
+        if let Some(info) = info
+        {
+            commands.queue(ReactionCommand::Custom(Box::new(
+                move |world: &mut World| world.resource_mut::<ResourceMutationTracker>().push(TypeId::of::<R>(), info)
+            )));
+        }
+
+        // queue reactors
+        for handle in handlers.iter()
+        {
+            commands.queue(
+                ReactionCommand::Resource{
+                    resource  : TypeId::of::<R>(),
+                    type_name : std::any::type_name::<R>(),
+                    reactor   : handle.sys_command(),
+                }
+            );
+        }
+
+        if info.is_some()
+        {
+            commands.queue(ReactionCommand::Custom(Box::new(
+                |world: &mut World| world.resource_mut::<ResourceMutationTracker>().pop(TypeId::of::<R>())
+            )));
+        }
+    }
+
+    /// Schedules reactions to Bevy resources and components that changed since the last check.
+    ///
+    /// The watchers are extracted while running so the cache is available to the scheduling systems.
+    pub(crate) fn schedule_resource_change_reactions(world: &mut World)
+    {
+        let mut watchers = std::mem::take(&mut world.resource_mut::<ReactCache>().change_watchers);
+
+        for watcher in &watchers
+        {
+            watcher.watcher.call(world, true);
+        }
+
+        // Watchers may have been added while running.
+        let mut cache = world.resource_mut::<ReactCache>();
+        watchers.append(&mut cache.change_watchers);
+        cache.change_watchers = watchers;
+    }
+
+    /// Queues reactions to a Bevy resource change.
+    pub(crate) fn schedule_bevy_resource_reaction<R: Resource>(
+        cache        : Res<ReactCache>,
+        mut commands : Commands,
+    ){
+        cache.record_trigger_fire(ReactorType::BevyResourceChanged(TypeId::of::<R>()));
+        let Some(handlers) = cache.bevy_resource_reactors.get(&TypeId::of::<R>()) else { return; };
 
         // queue reactors
         for handle in handlers.iter()
         {
             commands.queue(
-                ReactionCommand::Resource{ reactor: handle.sys_command() }
+                ReactionCommand::BevyResource{
+                    resource  : TypeId::of::<R>(),
+                    type_name : std::any::type_name::<R>(),
+                    reactor   : handle.sys_command(),
+                }
             );
         }
     }
 
     /// Queues reactions to a broadcasted event.
     pub(crate) fn schedule_broadcast_reaction<E: Send + Sync + 'static>(
-        In(event)    : In<E>,
+        In((channel, event)) : In<(ChannelId, E)>,
+        cache                : Res<ReactCache>,
+        mut commands         : Commands,
+    ){
+        cache.queue_broadcast_reactors(&mut commands, channel, event, ());
+    }
+
+    /// Queues reactions to a batch of broadcasted events.
+    ///
+    /// All events are stored on one data entity, which is despawned after the last reactor of the last event runs.
+    pub(crate) fn schedule_broadcast_batch_reaction<E: Send + Sync + 'static>(
+        In(events)   : In<Vec<E>>,
         cache        : Res<ReactCache>,
         mut commands : Commands,
     ){
-        let Some(handlers) = cache.broadcast_reactors.get(&TypeId::of::<E>()) else { return; };
+        let event_id = TypeId::of::<E>();
+        for _ in 0..events.len()
+        {
+            cache.record_trigger_fire(ReactorType::Broadcast(event_id));
+        }
+        let Some(handlers) = cache.broadcast_reactors.get(&(event_id, ChannelId::DEFAULT)) else { return; };
+
+        // if there are no handlers, just drop the event data
+        let num_events = events.len();
+        let num = handlers.len() * num_events;
+        if num == 0 { return; }
+
+        // prep event data
+        let data_entity = commands.spawn((DataEntityCounter::new(num), BroadcastBatchData::new(events))).id();
+
+        // queue reactors
+        // - Each event is sent to all reactors before the next event.
+        for index in 0..num_events
+        {
+            for handle in handlers.iter()
+            {
+                commands.queue(
+                    ReactionCommand::BroadcastBatchEvent{
+                        data_entity,
+                        index,
+                        event     : event_id,
+                        type_name : std::any::type_name::<E>(),
+                        reactor   : handle.sys_command(),
+                    }
+                );
+            }
+        }
+    }
+
+    /// Queues reactions to a broadcast event whose reactors can respond with [`BroadcastResponder<R>`].
+    pub(crate) fn schedule_broadcast_collect_reaction<E: Send + Sync + 'static, R: Send + 'static>(
+        In((event, handle)) : In<(E, CollectHandle<R>)>,
+        cache               : Res<ReactCache>,
+        mut commands        : Commands,
+    ){
+        cache.queue_broadcast_reactors(&mut commands, ChannelId::DEFAULT, event, BroadcastResponseBuffer::new(&handle));
+    }
+
+    /// Spawns a data entity with `event` and `extra`, and queues reactions for the broadcast reactors of `E` on
+    /// `channel`.
+    ///
+    /// Broadcasts on the default channel also fan out to the reactors of every topic `E` is a member of. If there are
+    /// topic reactors, the event is stored type-erased in [`TopicEventData`] so both [`TopicEvent`] and
+    /// [`BroadcastEvent`] can read it.
+    pub(crate) fn queue_broadcast_reactors<E: Send + Sync + 'static>(
+        &self,
+        commands : &mut Commands,
+        channel  : ChannelId,
+        event    : E,
+        extra    : impl Bundle,
+    ){
+        let event_id = TypeId::of::<E>();
+        match channel == ChannelId::DEFAULT
+        {
+            true  => self.record_trigger_fire(ReactorType::Broadcast(event_id)),
+            false => self.record_trigger_fire(ReactorType::BroadcastChannel(event_id, channel)),
+        }
+        let handlers = self.broadcast_reactors.get(&(event_id, channel)).map(Vec::as_slice).unwrap_or_default();
+
+        // collect topic reactors
+        let mut topic_handlers: Vec<&ReactorHandle> = Vec::default();
+        if channel == ChannelId::DEFAULT
+        {
+            for topic_id in self.event_topics.get(&event_id).into_iter().flatten()
+            {
+                self.record_trigger_fire(ReactorType::BroadcastTopic(*topic_id));
+                topic_handlers.extend(self.topic_reactors.get(topic_id).into_iter().flatten());
+            }
+        }
+
+        // if there are no handlers, just drop the event data
+        let num = handlers.len() + topic_handlers.len();
+        if num == 0 { return; }
+
+        // prep event data
+        let data_entity = match topic_handlers.is_empty()
+        {
+            true  => commands.spawn((DataEntityCounter::new(num), BroadcastEventData::new(event), extra)).id(),
+            false => commands.spawn((DataEntityCounter::new(num), TopicEventData::new(event), extra)).id(),
+        };
+
+        // queue reactors
+        for handle in handlers.iter().chain(topic_handlers)
+        {
+            commands.queue(
+                ReactionCommand::BroadcastEvent{
+                    data_entity,
+                    event     : TypeId::of::<E>(),
+                    type_name : std::any::type_name::<E>(),
+                    reactor   : handle.sys_command(),
+                }
+            );
+        }
+    }
+
+    /// Queues reactions to a broadcast event for the first `per_frame` reactors, and saves the remaining reactors for
+    /// [`Self::schedule_spread_broadcast_reactions`].
+    pub(crate) fn schedule_spread_broadcast_reaction<E: Send + Sync + 'static>(
+        In((per_frame, event)) : In<(usize, E)>,
+        mut cache              : ResMut<ReactCache>,
+        mut commands           : Commands,
+    ){
+        cache.record_trigger_fire(ReactorType::Broadcast(TypeId::of::<E>()));
+        let Some(handlers) = cache.broadcast_reactors.get(&(TypeId::of::<E>(), ChannelId::DEFAULT)) else { return; };
 
         // if there are no handlers, just drop the event data
         let num = handlers.len();
@@ -552,14 +1832,158 @@ impl ReactCache
         // prep event data
         let data_entity = commands.spawn((DataEntityCounter::new(num), BroadcastEventData::new(event))).id();
 
+        // queue the first reactors
+        let mut spread = SpreadBroadcast{
+            data_entity,
+            event     : TypeId::of::<E>(),
+            type_name : std::any::type_name::<E>(),
+            reactors  : handlers.iter().map(|handle| handle.sys_command()).collect(),
+            per_frame : per_frame.max(1),
+        };
+        spread.queue_next(&mut commands);
+
+        if spread.reactors.is_empty() { return; }
+        cache.spread_broadcasts.push(spread);
+    }
+
+    /// Queues reactions for the next reactors of each spread broadcast.
+    pub(crate) fn schedule_spread_broadcast_reactions(&mut self, world: &mut World)
+    {
+        let mut commands = world.commands();
+        for spread in self.spread_broadcasts.iter_mut()
+        {
+            spread.queue_next(&mut commands);
+        }
+        self.spread_broadcasts.retain(|spread| !spread.reactors.is_empty());
+    }
+
+    /// Schedules reactions to assets that finished loading.
+    ///
+    /// The watchers are extracted while running so the cache is available to the scheduling systems.
+    #[cfg(feature = "bevy_asset")]
+    pub(crate) fn schedule_asset_load_reactions(world: &mut World)
+    {
+        let mut watchers = std::mem::take(&mut world.resource_mut::<ReactCache>().asset_watchers);
+
+        for watcher in &watchers
+        {
+            watcher.watcher.call(world, true);
+        }
+
+        // Watchers may have been added while running.
+        let mut cache = world.resource_mut::<ReactCache>();
+        watchers.append(&mut cache.asset_watchers);
+        cache.asset_watchers = watchers;
+    }
+
+    /// Queues reactions to an asset that finished loading.
+    ///
+    /// If `only` is set then only that reactor will be scheduled (used when registering reactors for assets that
+    /// are already loaded).
+    #[cfg(feature = "bevy_asset")]
+    pub(crate) fn schedule_asset_loaded_reaction<A: Asset>(
+        In((id, only)) : In<(AssetId<A>, Option<SystemCommand>)>,
+        cache          : Res<ReactCache>,
+        mut commands   : Commands,
+    ){
+        // Replays for newly-registered reactors are not new loads.
+        if only.is_none() { cache.record_trigger_fire(ReactorType::AssetLoaded(id.untyped())); }
+        let Some(handlers) = cache.asset_reactors.get(&(TypeId::of::<A>(), id.untyped())) else { return; };
+
+        // if there are no handlers, just drop the event data
+        let num = match only
+        {
+            Some(reactor) => handlers.iter().filter(|h| h.sys_command() == reactor).count(),
+            None => handlers.len(),
+        };
+        if num == 0 { return; }
+
+        // prep event data
+        let data_entity = commands.spawn((DataEntityCounter::new(num), BroadcastEventData::new(id))).id();
+
         // queue reactors
         for handle in handlers.iter()
         {
+            if only.is_some_and(|reactor| reactor != handle.sys_command()) { continue; }
             commands.queue(
-                ReactionCommand::BroadcastEvent{ data_entity, reactor: handle.sys_command() }
+                ReactionCommand::BroadcastEvent{
+                    data_entity,
+                    event     : TypeId::of::<AssetId<A>>(),
+                    type_name : std::any::type_name::<AssetId<A>>(),
+                    reactor   : handle.sys_command(),
+                }
             );
         }
     }
+
+    /// Asserts that no reaction state is left over after all queued reactions have run.
+    ///
+    /// Checks that:
+    /// - There are no deferred recursive system commands and the system command counter is reset (i.e. no tree of
+    ///   system commands is running).
+    /// - No reactions are running or prepared in any of the reaction access trackers.
+    /// - No event data entities are waiting for readers.
+    /// - No batched reactions are pending.
+    /// - No tree results or tree completion readers are pending.
+    ///
+    /// Panics with a list of violations.
+    pub(crate) fn assert_tree_drained(world: &mut World)
+    {
+        let mut violations = Vec::new();
+
+        if !world.resource::<CobwebCommandQueue<BufferedSyscommand>>().is_empty()
+        { violations.push("deferred system commands are queued".to_string()); }
+        if **world.resource::<SyscommandCounter>() != 0
+        { violations.push("a system command tree is running".to_string()); }
+        if world.resource::<SteppedTreeQueue>().is_active()
+        { violations.push("a stepped tree is active".to_string()); }
+        if !world.resource::<EntityReactionAccessTracker>().is_idle()
+        { violations.push("entity reactions are running or prepared".to_string()); }
+        if !world.resource::<EventAccessTracker>().is_idle()
+        { violations.push("event reactions are running or prepared".to_string()); }
+        if !world.resource::<DespawnAccessTracker>().is_idle()
+        { violations.push("despawn reactions are running or prepared".to_string()); }
+        if !world.resource::<SystemEventAccessTracker>().is_idle()
+        { violations.push("system events are running or prepared".to_string()); }
+        if !world.resource::<SyscommandTracker>().is_idle()
+        { violations.push("system commands are still tracked as running".to_string()); }
+
+        let orphans = world.query_filtered::<Entity, With<DataEntityCounter>>().iter(world).count();
+        if orphans > 0
+        { violations.push(format!("{orphans} event data entities are orphaned")); }
+
+        let batches = world.resource::<ReactCache>().batched_reactions.len();
+        if batches > 0
+        { violations.push(format!("{batches} batched reactions are pending")); }
+
+        if !world.resource::<TreeResults>().is_empty()
+        { violations.push("tree results are pending".to_string()); }
+
+        let mut entity_reactors = world.query::<(Entity, &EntityReactors)>();
+        let cache = world.resource::<ReactCache>();
+        let mut num_indexed = 0;
+        for (entity, reactors) in entity_reactors.iter(world)
+        {
+            let expected: HashSet<SystemCommand> = reactors.iter_reactors().collect();
+            let indexed = cache.entity_reactor_index.get(&entity);
+            if !expected.is_empty() { num_indexed += 1; }
+            if indexed.map(|indexed| *indexed == expected).unwrap_or(expected.is_empty()) { continue; }
+            violations.push(format!("reactor index for {entity:?} does not match its entity reactors"));
+        }
+        if num_indexed != cache.entity_reactor_index.len()
+        { violations.push("reactor index contains entities without entity reactors".to_string()); }
+
+        let spread = world.resource::<ReactCache>().spread_broadcasts.len();
+        if spread > 0
+        { violations.push(format!("{spread} spread broadcasts are pending")); }
+
+        let retained = world.resource::<ReactCache>().retained_entity_events.len();
+        if retained > 0
+        { violations.push(format!("{retained} retained entity events were not released")); }
+
+        if violations.is_empty() { return; }
+        panic!("reaction tree was not drained: {}", violations.join("; "));
+    }
 }
 
 impl Default for ReactCache
@@ -575,12 +1999,30 @@ impl Default for ReactCache
             tracked_removals      : HashSet::default(),
             removal_checkers      : Vec::new(),
             removal_buffer        : None,
+            entity_reactor_index  : HashMap::new(),
             despawn_reactors      : HashMap::new(),
             despawn_sender,
             despawn_receiver,
             any_entity_event_reactors : HashMap::new(),
             resource_reactors         : HashMap::new(),
+            resource_change_checks    : HashMap::new(),
+            watched_changes           : HashSet::default(),
+            change_watchers           : Vec::new(),
+            bevy_resource_reactors    : HashMap::new(),
             broadcast_reactors        : HashMap::new(),
+            spread_broadcasts         : Vec::new(),
+            topic_reactors            : HashMap::new(),
+            event_topics              : HashMap::new(),
+            batched_reactions         : Vec::new(),
+            retained_entity_events    : Vec::new(),
+            reaction_version          : ReactionVersion::default(),
+            trigger_metrics           : None,
+            #[cfg(feature = "bevy_asset")]
+            watched_assets            : HashSet::default(),
+            #[cfg(feature = "bevy_asset")]
+            asset_watchers            : Vec::new(),
+            #[cfg(feature = "bevy_asset")]
+            asset_reactors            : HashMap::new(),
         }
     }
 }
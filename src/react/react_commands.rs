@@ -2,10 +2,15 @@
 use crate::prelude::*;
 
 //third-party shortcuts
+use bevy::ecs::system::BoxedSystem;
 use bevy::prelude::*;
+use bevy::tasks::{ComputeTaskPool, TaskPool};
+use bevy::utils::HashMap;
 
 //standard shortcuts
-
+use std::any::TypeId;
+use std::hash::Hash;
+use std::sync::{Arc, OnceLock, Weak};
 
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
@@ -37,81 +42,346 @@ fn register_reactors<T: ReactionTriggerBundle>(
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Ties a reactor's lifetime to its [`ReactorGuard`].
+///
+/// If the guard was already dropped, the signal is dropped immediately so the reactor gets cleaned up.
+fn register_scoped_reactor(
+    In((token, guard)) : In<(RevokeToken, Weak<OnceLock<AutoDespawnSignal>>)>,
+    despawner          : Res<AutoDespawner>,
+){
+    let entity = *token.id;
+    let signal = despawner.prepare_with_callback(entity,
+        move |world: &mut World| { world.syscall(token, revoke_reactor); }
+    );
+    let Some(guard) = guard.upgrade() else { return; };
+    let _ = guard.set(signal);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Reactors scoped to one parent entity, plus the shared despawn reactor that revokes them.
+struct ScopedReactorGroup
+{
+    revoker  : RevokeToken,
+    reactors : Vec<RevokeToken>,
+}
+
+/// Tracks reactors registered with [`ReactCommands::on_scoped`], grouped by parent entity.
+#[derive(Resource, Default)]
+struct ScopedReactors
+{
+    parents : HashMap<Entity, ScopedReactorGroup>,
+    scopes  : HashMap<SystemCommand, Entity>,
+}
+
+impl ScopedReactors
+{
+    /// Adds a reactor to `parent`'s group, registering the group's despawn reactor if this is the first reactor.
+    fn add(&mut self, parent: Entity, token: RevokeToken, c: &mut Commands)
+    {
+        self.scopes.insert(token.id, parent);
+        if let Some(group) = self.parents.get_mut(&parent)
+        {
+            group.reactors.push(token);
+            return;
+        }
+
+        let revoker = c.react().once(despawn(parent),
+            move |mut c: Commands, mut scoped: ResMut<ScopedReactors>|
+            {
+                for token in scoped.take_group(parent) { c.react().revoke(token); }
+            }
+        );
+        self.parents.insert(parent, ScopedReactorGroup{ revoker, reactors: vec![token] });
+    }
+
+    /// Removes all reactors scoped to `parent`.
+    fn take_group(&mut self, parent: Entity) -> Vec<RevokeToken>
+    {
+        let Some(group) = self.parents.remove(&parent) else { return Vec::default(); };
+        for token in group.reactors.iter() { self.scopes.remove(&token.id); }
+        group.reactors
+    }
+
+    /// Removes a reactor from its parent's group.
+    ///
+    /// Returns the group's despawn reactor token if the group is now empty.
+    fn unscope(&mut self, reactor: SystemCommand) -> Option<RevokeToken>
+    {
+        let parent = self.scopes.remove(&reactor)?;
+        let group = self.parents.get_mut(&parent)?;
+        group.reactors.retain(|token| token.id != reactor);
+        if !group.reactors.is_empty() { return None; }
+        self.parents.remove(&parent).map(|group| group.revoker)
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Ties a reactor's lifetime to a parent entity.
+///
+/// Only the first reactor scoped to a parent registers a despawn reactor, which revokes all reactors scoped to the
+/// parent. If the parent doesn't exist then the reactor is revoked immediately.
+fn register_parent_scoped_reactor(
+    In((parent, token)) : In<(Entity, RevokeToken)>,
+    mut c               : Commands,
+    scoped              : Option<ResMut<ScopedReactors>>,
+){
+    if c.get_entity(parent).is_none()
+    {
+        c.react().revoke(token);
+        return;
+    }
+
+    match scoped
+    {
+        Some(mut scoped) => scoped.add(parent, token, &mut c),
+        None =>
+        {
+            let mut scoped = ScopedReactors::default();
+            scoped.add(parent, token, &mut c);
+            c.insert_resource(scoped);
+        }
+    }
+}
+
+/// Removes a reactor from [`ScopedReactors`], revoking its parent's despawn reactor if no scoped reactors remain.
+fn unscope_reactor(id: SystemCommand, c: &mut Commands, scoped: Option<&mut ScopedReactors>)
+{
+    let Some(scoped) = scoped else { return; };
+    let Some(revoker) = scoped.unscope(id) else { return; };
+    c.react().revoke(revoker);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
 fn revoke_entity_reactor(
     entity     : Entity,
     rtype      : EntityReactionType,
     reactor_id : SystemCommand,
+    cache      : &mut ReactCache,
     reactors   : &mut Query<&mut EntityReactors>,
 ){
     let Ok(mut entity_reactors) = reactors.get_mut(entity) else { return; };
     entity_reactors.remove(rtype, reactor_id);
+
+    // the reactor may still have other triggers targeting the entity
+    if entity_reactors.contains(reactor_id) { return; }
+    cache.unindex_entity_reactor(entity, reactor_id);
 }
 
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
 
+fn revoke_reactor_type(
+    reactor_type : ReactorType,
+    id           : SystemCommand,
+    cache        : &mut ReactCache,
+    reactors     : &mut Query<&mut EntityReactors>,
+){
+    match reactor_type
+    {
+        ReactorType::EntityInsertion(entity, comp_id) =>
+        {
+            revoke_entity_reactor(entity, EntityReactionType::Insertion(comp_id), id, cache, reactors);
+        }
+        ReactorType::EntityMutation(entity, comp_id) =>
+        {
+            revoke_entity_reactor(entity, EntityReactionType::Mutation(comp_id), id, cache, reactors);
+        }
+        ReactorType::EntityRemoval(entity, comp_id) =>
+        {
+            revoke_entity_reactor(entity, EntityReactionType::Removal(comp_id), id, cache, reactors);
+        }
+        ReactorType::EntityEvent(entity, event_id) =>
+        {
+            revoke_entity_reactor(entity, EntityReactionType::Event(event_id), id, cache, reactors);
+        }
+        ReactorType::AnyEntityEvent(event_id) =>
+        {
+            cache.revoke_any_entity_event_reactor(event_id, id);
+        }
+        ReactorType::ComponentInsertion(comp_id) =>
+        {
+            cache.revoke_component_reactor(EntityReactionType::Insertion(comp_id), id);
+        }
+        ReactorType::ComponentMutation(comp_id) =>
+        {
+            cache.revoke_component_reactor(EntityReactionType::Mutation(comp_id), id);
+        }
+        ReactorType::ComponentRemoval(comp_id) =>
+        {
+            cache.revoke_component_reactor(EntityReactionType::Removal(comp_id), id);
+        }
+        ReactorType::ResourceMutation(res_id) =>
+        {
+            cache.revoke_resource_mutation_reactor(res_id, id);
+        }
+        ReactorType::BevyResourceChanged(res_id) =>
+        {
+            cache.revoke_bevy_resource_reactor(res_id, id);
+        }
+        ReactorType::Broadcast(event_id) =>
+        {
+            cache.revoke_broadcast_reactor(event_id, ChannelId::DEFAULT, id);
+        }
+        ReactorType::BroadcastChannel(event_id, channel) =>
+        {
+            cache.revoke_broadcast_reactor(event_id, channel, id);
+        }
+        ReactorType::BroadcastTopic(topic_id) =>
+        {
+            cache.revoke_topic_reactor(topic_id, id);
+        }
+        ReactorType::Despawn(entity) =>
+        {
+            cache.revoke_despawn_reactor(entity, id);
+        }
+        #[cfg(feature = "bevy_asset")]
+        ReactorType::AssetLoaded(asset_id) =>
+        {
+            cache.revoke_asset_loaded_reactor(asset_id, id);
+        }
+    }
+}
+
 fn revoke_reactor(
     In(token)    : In<RevokeToken>,
+    mut c        : Commands,
     mut cache    : ResMut<ReactCache>,
+    mut scoped   : Option<ResMut<ScopedReactors>>,
     mut reactors : Query<&mut EntityReactors>,
 ){
-    let id = token.id;
-
     for reactor_type in token.reactors.iter()
     {
-        match *reactor_type
-        {
-            ReactorType::EntityInsertion(entity, comp_id) =>
-            {
-                revoke_entity_reactor(entity, EntityReactionType::Insertion(comp_id), id, &mut reactors);
-            }
-            ReactorType::EntityMutation(entity, comp_id) =>
-            {
-                revoke_entity_reactor(entity, EntityReactionType::Mutation(comp_id), id, &mut reactors);
-            }
-            ReactorType::EntityRemoval(entity, comp_id) =>
-            {
-                revoke_entity_reactor(entity, EntityReactionType::Removal(comp_id), id, &mut reactors);
-            }
-            ReactorType::EntityEvent(entity, event_id) =>
-            {
-                revoke_entity_reactor(entity, EntityReactionType::Event(event_id), id, &mut reactors);
-            }
-            ReactorType::AnyEntityEvent(event_id) =>
-            {
-                cache.revoke_any_entity_event_reactor(event_id, id);
-            }
-            ReactorType::ComponentInsertion(comp_id) =>
-            {
-                cache.revoke_component_reactor(EntityReactionType::Insertion(comp_id), id);
-            }
-            ReactorType::ComponentMutation(comp_id) =>
-            {
-                cache.revoke_component_reactor(EntityReactionType::Mutation(comp_id), id);
-            }
-            ReactorType::ComponentRemoval(comp_id) =>
-            {
-                cache.revoke_component_reactor(EntityReactionType::Removal(comp_id), id);
-            }
-            ReactorType::ResourceMutation(res_id) =>
-            {
-                cache.revoke_resource_mutation_reactor(res_id, id);
-            }
-            ReactorType::Broadcast(event_id) =>
-            {
-                cache.revoke_broadcast_reactor(event_id, id);
-            }
-            ReactorType::Despawn(entity) =>
-            {
-                cache.revoke_despawn_reactor(entity, id);
-            }
-        }
+        revoke_reactor_type(*reactor_type, token.id, &mut cache, &mut reactors);
+    }
+    unscope_reactor(token.id, &mut c, scoped.as_deref_mut());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn retarget_entity_reactor(
+    In((type_id, reactor_id, old, new)) : In<(TypeId, SystemCommand, Entity, Entity)>,
+    mut commands                        : Commands,
+    mut cache                           : ResMut<ReactCache>,
+    mut reactors                        : Query<&mut EntityReactors>,
+){
+    let Ok(mut entity_reactors) = reactors.get_mut(old) else { return; };
+
+    // take the reactor's handles from the old entity
+    let mut moved = Vec::default();
+    for rtype in [
+        EntityReactionType::Insertion(type_id),
+        EntityReactionType::Mutation(type_id),
+        EntityReactionType::Removal(type_id),
+        EntityReactionType::Event(type_id),
+    ]
+    {
+        moved.extend(entity_reactors.take(rtype, reactor_id).into_iter().map(|handle| (rtype, handle)));
+    }
+
+    // the reactor may still have other triggers targeting the old entity
+    if !entity_reactors.contains(reactor_id) { cache.unindex_entity_reactor(old, reactor_id); }
+
+    // register the same handles on the new entity
+    for (rtype, handle) in moved
+    {
+        commands.syscall((rtype, new, handle), register_entity_reactor);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Removes all triggers of a reactor when its triggers are not known (e.g. there is no [`RevokeToken`]).
+///
+/// Entity-specific triggers are found with the cache's reverse index, so this doesn't scan all [`EntityReactors`].
+fn revoke_all_reactor_triggers(
+    id       : SystemCommand,
+    cache    : &mut ReactCache,
+    reactors : &mut Query<&mut EntityReactors>,
+){
+    cache.revoke_reactor_registrations(id);
+    for target in cache.unindex_reactor(id)
+    {
+        let Ok(mut entity_reactors) = reactors.get_mut(target) else { continue; };
+        entity_reactors.remove_all(id);
+    }
+}
+
+/// Removes all triggers of a reactor whose system command was despawned without revoking its triggers (e.g. a
+/// reactor registered with [`ReactCommands::once_cleanup`]).
+pub(crate) fn revoke_despawned_reactor(
+    In(id)       : In<SystemCommand>,
+    cache        : Option<ResMut<ReactCache>>,
+    mut reactors : Query<&mut EntityReactors>,
+){
+    let Some(mut cache) = cache else { return; };
+    revoke_all_reactor_triggers(id, &mut cache, &mut reactors);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn revoke_tagged_reactors(
+    In(tag)      : In<ReactorTag>,
+    mut c        : Commands,
+    mut cache    : ResMut<ReactCache>,
+    mut scoped   : Option<ResMut<ScopedReactors>>,
+    tagged       : Query<(Entity, &ReactorTag)>,
+    mut reactors : Query<&mut EntityReactors>,
+){
+    for (entity, reactor_tag) in tagged.iter()
+    {
+        if *reactor_tag != tag { continue; }
+        revoke_all_reactor_triggers(SystemCommand(entity), &mut cache, &mut reactors);
+        unscope_reactor(SystemCommand(entity), &mut c, scoped.as_deref_mut());
     }
 }
 
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
 
+fn revoke_current_reactor(
+    mut commands : Commands,
+    mut cache    : ResMut<ReactCache>,
+    mut scoped   : Option<ResMut<ScopedReactors>>,
+    tracker      : Res<SyscommandTracker>,
+    mut reactors : Query<&mut EntityReactors>,
+){
+    let Some(id) = tracker.current()
+    else
+    {
+        tracing::warn!("ignoring ReactCommands::revoke_current, no system command is running");
+        return;
+    };
+
+    revoke_all_reactor_triggers(id, &mut cache, &mut reactors);
+    unscope_reactor(id, &mut commands, scoped.as_deref_mut());
+    if let Some(mut entity) = commands.get_entity(*id) { entity.despawn(); }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn prune_entity_reactors(
+    In(entity)   : In<Entity>,
+    mut cache    : ResMut<ReactCache>,
+    mut reactors : Query<&mut EntityReactors>,
+){
+    cache.revoke_all_despawn_reactors(entity);
+    cache.unindex_entity(entity);
+    let Ok(mut entity_reactors) = reactors.get_mut(entity) else { return; };
+    entity_reactors.clear();
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
 /// Setting for controlling how reactors are cleaned up.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum ReactorMode
@@ -147,6 +417,36 @@ impl ReactorMode
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Which threshold crossings run a reactor registered with [`ReactCommands::on_threshold`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum CrossDirection
+{
+    /// The value went from below the threshold to at or above it.
+    Up,
+    /// The value went from at or above the threshold to below it.
+    Down,
+    /// The value crossed the threshold in either direction.
+    Either,
+}
+
+impl CrossDirection
+{
+    /// Returns `true` if going from `prev` to `current` is a crossing of `threshold` in this direction.
+    fn crossed(&self, threshold: f32, prev: f32, current: f32) -> bool
+    {
+        let was_below = prev < threshold;
+        let is_below = current < threshold;
+        match self
+        {
+            Self::Up     => was_below && !is_below,
+            Self::Down   => !was_below && is_below,
+            Self::Either => was_below != is_below,
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// Struct that drives reactivity.
 ///
 /// Obtained via [`Commands::react`](ReactCommandsExt::react).
@@ -176,6 +476,9 @@ impl<'w, 's> ReactCommands<'w, 's>
 
     /// Inserts a [`ReactComponent`] to the specified entity. It can be queried with [`React<C>`].
     /// - Does nothing if the entity does not exist.
+    ///
+    /// Reactions are scheduled as commands. When called inside a reactor, they are applied by the already-running
+    /// tree of system commands right after the reactor's own commands, so no additional scheduling is needed.
     pub fn insert<C: ReactComponent>(&mut self, entity: Entity, component: C)
     {
         let Some(mut entity_commands) = self.commands.get_entity(entity) else { return; };
@@ -183,92 +486,1060 @@ impl<'w, 's> ReactCommands<'w, 's>
         self.commands.syscall_with_validation(entity, ReactCache::schedule_insertion_reaction::<C>, validate_rc);
     }
 
-    /// Sends a broadcasted event.
-    /// - Reactors can listen for the event with the [`broadcast()`] trigger.
-    /// - Reactors can read the event with the [`BroadcastEvent`] system parameter.
-    pub fn broadcast<E: Send + Sync + 'static>(&mut self, event: E)
+    /// Inserts a [`ReactComponent`] to the specified entity, or overwrites it if the entity already has one.
+    ///
+    /// If the entity doesn't have [`React<C>`] then this behaves like [`Self::insert`] and insertion reactions are
+    /// scheduled. Otherwise the existing value is overwritten and mutation reactions are scheduled instead, so
+    /// insertion reactors don't re-run for an entity that already had the component. The check happens when the command
+    /// is applied. Does nothing if the entity does not exist.
+    pub fn insert_if_new<C: ReactComponent>(&mut self, entity: Entity, component: C)
     {
-        self.commands.syscall_with_validation(event, ReactCache::schedule_broadcast_reaction::<E>, validate_rc);
+        self.commands.syscall_with_validation((entity, component), apply_react_value::<C>, validate_rc);
     }
 
-    /// Sends an entity-targeted event.
-    /// - Reactors can listen for the event with the [`entity_event()`] trigger.
-    /// - Reactors can read the event with the [`EntityEvent`] system parameter.
-    pub fn entity_event<E: Send + Sync + 'static>(&mut self, entity: Entity, event: E)
+    /// Inserts a [`React<C>`] built with [`FromWorld`] on `entity` if the entity doesn't have one.
+    ///
+    /// `C::from_world` is only invoked when this command is applied and the component is missing, so the default can
+    /// be computed from the world at that time. Insertion reactions are scheduled like [`Self::insert`]. If the entity
+    /// already has `React<C>` then it is left untouched and no reactions are scheduled. Does nothing if the entity
+    /// does not exist.
+    ///
+    /// Use [`ReactWorldExt::ensure_component`] to find out if the component was inserted.
+    pub fn ensure_component<C: ReactComponent + FromWorld>(&mut self, entity: Entity)
     {
-        self.commands.syscall_with_validation(
-            (entity, event),
-            ReactCache::schedule_entity_event_reaction::<E>,
-            validate_rc
+        self.commands.queue(
+            move |world: &mut World|
+            {
+                validate_rc(world);
+                ensure_react_component::<C>(world, entity);
+            }
         );
     }
 
-    /// Triggers resource mutation reactions.
+    /// Starts recording mutations of [`React<C>`] components in a [`ReactDiffBuffer<C>`] resource.
     ///
-    /// Useful for initializing state after a reactor is registered.
-    pub fn trigger_resource_mutation<R: ReactResource + Send + Sync + 'static>(&mut self)
-    {
-        self.commands.syscall_with_validation((), ReactCache::schedule_resource_mutation_reaction::<R>, validate_rc);
-    }
-
-    /// Revokes a reactor.
-    pub fn revoke(&mut self, token: RevokeToken)
+    /// The buffer is inserted when this command is applied, along with persistent reactors that record every
+    /// insertion, mutation, and removal of `C`. Drain the buffer each frame (e.g. in a networking system) to get the
+    /// components that changed that frame. See [`ReactDiffBuffer`] for how diffs are coalesced.
+    ///
+    /// Does nothing if diff tracking is already enabled for `C`.
+    pub fn enable_diff_tracking<C: ReactComponent + Clone + PartialEq>(&mut self)
     {
-        self.commands.syscall_with_validation(token, revoke_reactor, validate_rc);
+        self.commands.queue(
+            move |world: &mut World|
+            {
+                validate_rc(world);
+                enable_react_diff_tracking::<C>(world);
+            }
+        );
     }
 
-    /// Registers a reactor triggered by ECS changes.
-    ///
-    /// You can tie a reactor to multiple reaction triggers.
-    /// Duplicate triggers will be ignored.
+    /// Applies a [`React<C>`] value serialized with [`serialize_react`] to `entity`.
     ///
-    /// Reactions are not merged together. If you register a reactor for triggers
-    /// `(resource_mutation::<A>(), resource_mutation::<B>())`, then mutate `A` and `B` in succession, the reactor will
-    /// execute twice.
+    /// The bytes are deserialized immediately. The value is then applied like a normal reactive change, so reactors
+    /// on the receiving world fire: if the entity has `React<C>` the value is set with mutation reactions, otherwise it
+    /// is inserted with insertion reactions (see [`Self::insert`]).
     ///
-    /// Uses [`ReactorMode::Cleanup`].
+    /// `entity` is an entity in the receiving world (mapping entities between worlds is up to the caller). Does
+    /// nothing if the entity does not exist.
     ///
-    /// Example:
-    /// ```no_run
-    /// rcommands.on((resource_mutation::<MyRes>(), mutation::<MyComponent>()), my_reactor_system);
-    /// ```
-    pub fn on<M, R: CobwebResult>(
+    /// Returns an error if the bytes can't be deserialized as `C`.
+    #[cfg(feature = "serde")]
+    pub fn apply_serialized<C: ReactComponent + serde::de::DeserializeOwned>(
         &mut self,
-        triggers : impl ReactionTriggerBundle,
-        reactor  : impl IntoSystem<(), R, M> + Send + Sync + 'static
-    ){
-        let sys_command = self.commands.spawn_system_command(reactor);
-        let _ = self.with(triggers, sys_command, ReactorMode::Cleanup);
+        entity : Entity,
+        bytes  : &[u8]
+    ) -> Result<(), CobwebReactError>
+    {
+        let value = serde_json::from_slice::<C>(bytes)
+            .map_err(|_| CobwebReactError::Deserialize(std::any::type_name::<C>()))?;
+        self.commands.syscall_with_validation((entity, value), apply_react_value::<C>, validate_rc);
+        Ok(())
     }
 
-    /// Registers a reactor triggered by ECS changes using [`ReactorMode::Persistent`].
+    /// Applies a [`ReactResource`] value serialized with [`serialize_react_resource`].
     ///
-    /// See [`Self::on`].
-    pub fn on_persistent<M, R: CobwebResult>(
+    /// The bytes are deserialized immediately. If the resource exists the value is set with mutation reactions,
+    /// otherwise it is inserted (resource insertion does not trigger reactions).
+    ///
+    /// Returns an error if the bytes can't be deserialized as `R`.
+    #[cfg(feature = "serde")]
+    pub fn apply_serialized_resource<R: ReactResource + serde::de::DeserializeOwned>(
         &mut self,
-        triggers : impl ReactionTriggerBundle,
-        reactor  : impl IntoSystem<(), R, M> + Send + Sync + 'static
-    ) -> SystemCommand
+        bytes: &[u8]
+    ) -> Result<(), CobwebReactError>
     {
-        let sys_command = self.commands.spawn_system_command(reactor);
-        self.with(triggers, sys_command, ReactorMode::Persistent);
-        sys_command
+        let value = serde_json::from_slice::<R>(bytes)
+            .map_err(|_| CobwebReactError::Deserialize(std::any::type_name::<R>()))?;
+        self.commands.syscall_with_validation(value, apply_react_resource_value::<R>, validate_rc);
+        Ok(())
     }
 
-    /// Registers a reactor triggered by ECS changes using [`ReactorMode::Revokable`].
+    /// Inserts a [`ReactComponent`] to the specified entity and registers a reactor for the component on that entity.
     ///
-    /// See [`Self::on`].
-    pub fn on_revokable<M, R: CobwebResult>(
+    /// The component is inserted, then the reactor is registered for [`entity_insertion::<C>(entity)`](entity_insertion)
+    /// and [`entity_mutation::<C>(entity)`](entity_mutation), then the insertion reaction is scheduled. This means the
+    /// reactor runs once for the initial insertion (along with other insertion reactors for the entity), then again
+    /// for each mutation.
+    ///
+    /// Entity-specific triggers are removed when the entity is despawned, so the reactor is cleaned up automatically
+    /// when the entity despawns. It can also be revoked early with the returned token.
+    ///
+    /// If the entity does not exist, nothing is inserted and the reactor is cleaned up without running.
+    pub fn insert_and_react<C: ReactComponent, M, R: CobwebResult>(
         &mut self,
-        triggers : impl ReactionTriggerBundle,
-        reactor  : impl IntoSystem<(), R, M> + Send + Sync + 'static
+        entity    : Entity,
+        component : C,
+        reactor   : impl IntoSystem<(), R, M> + Send + Sync + 'static
     ) -> RevokeToken
     {
-        let sys_command = self.commands.spawn_system_command(reactor);
-        self.with(triggers, sys_command, ReactorMode::Revokable).unwrap()
+        if let Some(mut entity_commands) = self.commands.get_entity(entity)
+        {
+            entity_commands.try_insert( React{ entity, component } );
+        }
+        let token = self.on_revokable((entity_insertion::<C>(entity), entity_mutation::<C>(entity)), reactor);
+        self.commands.syscall_with_validation(entity, ReactCache::schedule_insertion_reaction::<C>, validate_rc);
+
+        token
     }
 
-    /// Registers a reactor triggered by ECS changes with a [`SystemCommand`] and [`ReactorMode`].
+    /// Sends a broadcasted event.
+    /// - Reactors can listen for the event with the [`broadcast()`] trigger.
+    /// - Reactors can read the event with the [`BroadcastEvent`] system parameter.
+    pub fn broadcast<E: Send + Sync + 'static>(&mut self, event: E)
+    {
+        self.broadcast_on_channel(ChannelId::DEFAULT, event);
+    }
+
+    /// Sends a batch of broadcasted events of the same type.
+    /// - Reactors can listen for the events with the [`broadcast()`] trigger.
+    /// - Reactors can read the events with the [`BroadcastEvent`] system parameter.
+    ///
+    /// Each reactor runs once per event, and each event is sent to all reactors before the next event. This is
+    /// cheaper than calling [`Self::broadcast`] for each event, because the whole batch is stored on one data entity
+    /// and scheduled by one command.
+    ///
+    /// Only reactors on [`ChannelId::DEFAULT`] receive the events. Unlike [`Self::broadcast`], events are not sent
+    /// to [`broadcast_topic`] reactors.
+    pub fn broadcast_batch<E: Send + Sync + 'static>(&mut self, events: impl IntoIterator<Item = E>)
+    {
+        let events: Vec<E> = events.into_iter().collect();
+        if events.is_empty() { return; }
+        self.commands.syscall_with_validation(events, ReactCache::schedule_broadcast_batch_reaction::<E>, validate_rc);
+    }
+
+    /// Sends a broadcasted event that reactors can share without copying.
+    /// - Reactors can listen for the event with the [`broadcast::<Arc<E>>()`](broadcast) trigger.
+    /// - Reactors can read the event with the [`ArcBroadcastEvent`] system parameter, which hands out clones of
+    ///   `event` that may be kept after the tree ends.
+    ///
+    /// This is equivalent to [`Self::broadcast`] with an `Arc<E>` event.
+    pub fn broadcast_arc<E: Send + Sync + 'static>(&mut self, event: Arc<E>)
+    {
+        self.broadcast(event);
+    }
+
+    /// Sends a broadcasted event and collects responses from its reactors.
+    /// - Reactors can listen for the event with the [`broadcast()`] trigger.
+    /// - Reactors can respond with the [`BroadcastResponder<R>`] system parameter.
+    ///
+    /// Responses are stored in reactor run order (registration order, unless a reactor's reaction is deferred because
+    /// it is already running). Read them from the returned [`CollectHandle`] after the tree of system commands
+    /// ends, e.g. in [`Self::on_tree_complete`] or after [`World::broadcast`](ReactWorldExt::broadcast) returns. The
+    /// response buffer is shared with the event data, which is dropped after the last reactor has run.
+    pub fn broadcast_collect<E: Send + Sync + 'static, R: Send + 'static>(&mut self, event: E) -> CollectHandle<R>
+    {
+        let handle = CollectHandle::new();
+        self.commands.syscall_with_validation(
+            (event, handle.clone()),
+            ReactCache::schedule_broadcast_collect_reaction::<E, R>,
+            validate_rc
+        );
+        handle
+    }
+
+    /// Sends a broadcasted event whose reactors are spread across frames, running at most `per_frame` reactors per
+    /// frame (minimum 1).
+    ///
+    /// The first `per_frame` reactors run immediately like a normal [`Self::broadcast`]. The remaining reactors run
+    /// in batches of `per_frame` at the start of each following frame (in `First`) until all have run. Each reactor
+    /// runs in its own tree of system commands.
+    ///
+    /// The reactors are snapshotted when the event is sent, so reactors registered later will not receive the event.
+    /// Reactors run in registration order, and reactors despawned before their turn are skipped. The event data is
+    /// kept alive until the final reactor has run.
+    pub fn broadcast_spread<E: Send + Sync + 'static>(&mut self, event: E, per_frame: usize)
+    {
+        self.commands.syscall_with_validation(
+            (per_frame, event),
+            ReactCache::schedule_spread_broadcast_reaction::<E>,
+            validate_rc
+        );
+    }
+
+    /// Sends a broadcasted event on a specific channel.
+    /// - Reactors can listen for the event with the [`broadcast_channel()`] trigger.
+    /// - Reactors can read the event with the [`BroadcastEvent`] system parameter.
+    ///
+    /// Only reactors registered for the same channel will receive the event. [`Self::broadcast`] is equivalent to
+    /// broadcasting on [`ChannelId::DEFAULT`].
+    pub fn broadcast_on_channel<E: Send + Sync + 'static>(&mut self, channel: ChannelId, event: E)
+    {
+        self.commands.syscall_with_validation(
+            (channel, event),
+            ReactCache::schedule_broadcast_reaction::<E>,
+            validate_rc
+        );
+    }
+
+    /// Registers broadcast event type `E` as a member of topic `T`.
+    /// - After registration, [`Self::broadcast`] and [`Self::broadcast_collect`] for `E` also schedule reactors
+    ///   listening with the [`broadcast_topic::<T>()`](broadcast_topic) trigger.
+    /// - Topic reactors can read the event with the [`TopicEvent`] system parameter.
+    ///
+    /// Broadcasts on other channels and [`Self::broadcast_spread`] do not reach topic reactors. Registering the same
+    /// membership twice does nothing.
+    pub fn add_topic_member<T: BroadcastTopic, E: TopicMember<T>>(&mut self)
+    {
+        self.commands.syscall_with_validation((), add_topic_member::<T, E>, validate_rc);
+    }
+
+    /// Sends an entity-targeted event.
+    /// - Reactors can listen for the event with the [`entity_event()`] trigger.
+    /// - Reactors can read the event with the [`EntityEvent`] system parameter.
+    pub fn entity_event<E: Send + Sync + 'static>(&mut self, entity: Entity, event: E)
+    {
+        self.commands.syscall_with_validation(
+            (entity, event),
+            ReactCache::schedule_entity_event_reaction::<E>,
+            validate_rc
+        );
+    }
+
+    /// Sends an entity-targeted event that is retained for reactors registered later in the current tree of system
+    /// commands.
+    /// - Reactors registered when the event is sent react as with [`Self::entity_event`].
+    /// - With [`RetentionScope::Tree`], an [`entity_event()`] or [`any_entity_event()`] reactor registered later in the
+    ///   same tree (e.g. by one of the event's reactors) will also react to the event. Each reactor receives the
+    ///   event at most once.
+    ///
+    /// Normal events are fire-and-forget: the event data is dropped after the last reactor registered at send time
+    /// reads it. Retained event data is kept alive until the end of the tree instead. If sent outside a tree of
+    /// system commands (e.g. from a normal system), there is no tree to retain the event for so it behaves like
+    /// [`Self::entity_event`].
+    pub fn entity_event_retained<E: Send + Sync + 'static>(&mut self, entity: Entity, event: E, within: RetentionScope)
+    {
+        match within
+        {
+            RetentionScope::Tree =>
+            {
+                self.commands.syscall_with_validation(
+                    (entity, event),
+                    ReactCache::schedule_retained_entity_event_reaction::<E>,
+                    validate_rc
+                );
+            }
+        }
+    }
+
+    /// Sends an entity-targeted event to the first matching reactor only.
+    /// - "First" is registration order. Reactors registered for the specific entity come before reactors
+    ///   registered with [`any_entity_event()`].
+    /// - The event data is dropped after the reactor runs, since that reactor is its only reader.
+    /// - Useful for request/response patterns where exactly one handler should respond.
+    pub fn entity_event_first<E: Send + Sync + 'static>(&mut self, entity: Entity, event: E)
+    {
+        self.commands.syscall_with_validation(
+            (entity, event),
+            ReactCache::schedule_entity_event_first_reaction::<E>,
+            validate_rc
+        );
+    }
+
+    /// Triggers resource mutation reactions.
+    ///
+    /// Useful for initializing state after a reactor is registered.
+    pub fn trigger_resource_mutation<R: ReactResource + Send + Sync + 'static>(&mut self)
+    {
+        self.commands.syscall_with_validation((), ReactCache::schedule_resource_mutation_reaction::<R>, validate_rc);
+    }
+
+    /// Triggers mutation reactions for the `React<C>` component on `entity`.
+    ///
+    /// Useful after changing components with [`ReactiveMut::get_noreact`] or [`React::get_noreact`] (e.g. while loading
+    /// a save file), to run mutation reactors once at the end. Reactors are not notified of `noreact` changes, so they
+    /// will be stale until the mutation is triggered.
+    ///
+    /// Reactions are still scheduled if the entity doesn't have the component.
+    pub fn trigger_mutation<C: ReactComponent>(&mut self, entity: Entity)
+    {
+        self.commands.syscall_with_validation(entity, ReactCache::schedule_mutation_reaction::<C>, validate_rc);
+    }
+
+    /// Registers how values of `T` passed to [`Self::accumulate`] are combined within a tree of system commands.
+    ///
+    /// Registering again replaces the previous combiner.
+    pub fn register_tree_result<T: Default + Send + Sync + 'static>(
+        &mut self,
+        combine: impl Fn(&mut T, T) + Send + Sync + 'static
+    ){
+        let combine: TreeResultCombiner<T> = Arc::new(combine);
+        self.commands.syscall_with_validation(combine, register_tree_result_combiner::<T>, validate_rc);
+    }
+
+    /// Folds `value` into the `T` accumulated in the current tree of system commands.
+    ///
+    /// The accumulated value starts at `T::default()` and uses the combiner from [`Self::register_tree_result`].
+    /// Values are dropped with a warning if there is no combiner for `T`.
+    ///
+    /// Accumulated values are reset at the end of each tree. Outside a tree, values accumulate until the next tree
+    /// ends or [`Self::on_tree_complete`] is used.
+    pub fn accumulate<T: Default + Send + Sync + 'static>(&mut self, value: T)
+    {
+        self.commands.syscall_with_validation(value, accumulate_tree_result::<T>, validate_rc);
+    }
+
+    /// Registers a callback that reads the `T` accumulated in the current tree of system commands when the tree ends.
+    ///
+    /// Callbacks run in LIFO order after the tree ends, so reactions they trigger run in new trees. The accumulated
+    /// value is moved into the first callback that runs for `T` (i.e. the last one registered), and other callbacks
+    /// for `T` receive `T::default()`.
+    ///
+    /// Outside a tree, the callback runs immediately.
+    pub fn on_tree_complete<T: Default + Send + Sync + 'static>(
+        &mut self,
+        reader: impl FnOnce(T, &mut World) + Send + Sync + 'static
+    ){
+        let reader: TreeResultCallback<T> = Box::new(reader);
+        self.commands.syscall_with_validation(reader, add_tree_result_reader::<T>, validate_rc);
+    }
+
+    /// Queues a plain world mutation that runs in order with reactions in the current tree of system commands.
+    ///
+    /// The callback runs after reactions scheduled before it and before reactions scheduled after it (e.g. to clear
+    /// a buffer before the next batch of reactions). It does not trigger any reactors or produce readable event data.
+    ///
+    /// Reactions for a reactor that is already running are deferred until it finishes, so they may run after a
+    /// callback queued later.
+    pub fn queue_in_tree(&mut self, callback: impl FnOnce(&mut World) + Send + Sync + 'static)
+    {
+        self.commands.queue(ReactionCommand::Custom(Box::new(callback)));
+    }
+
+    /// Reschedules the current [`SystemCommand`] (e.g. a reactor) to run once more at the start of the next tree of
+    /// system commands.
+    ///
+    /// The next tree to start runs all rescheduled system commands before its root system command. If no tree starts
+    /// before `First`, then the rescheduled system commands run in their own tree in `First`. They never run in the
+    /// current tree, so a reactor that always reschedules itself runs once per tree instead of looping.
+    ///
+    /// Rescheduled runs are not reactions, so reaction readers like [`BroadcastEvent`] will be empty. Rescheduling
+    /// multiple times before the next tree only schedules one run.
+    ///
+    /// Does nothing (with a warning) if no system command is running.
+    pub fn reschedule_current_next_tree(&mut self)
+    {
+        self.commands.syscall_with_validation((), reschedule_current_reactor, validate_rc);
+    }
+
+    /// Queues `continuation` to run after the reactions to the commands queued so far.
+    ///
+    /// This is a continuation API: the rest of a reactor's work is passed in as a separate `continuation` system,
+    /// which runs once in the current tree after the reactions to commands queued before this call (including their
+    /// nested reactions). Commands queued after this call are applied after the continuation runs. It gives long
+    /// reactor bodies that enqueue many sub-reactions a breadth-first-ish ordering option. Normally, everything a
+    /// reactor does in its body happens before any of the reactions it triggers.
+    ///
+    /// Queued reactions can't be drained in the middle of a reactor. A reactor is a Bevy system, and its [`Commands`]
+    /// are only applied after the system returns, since the system holds borrows of the world while it runs. This
+    /// is why the continuation must be a separate system instead of a point the reactor resumes from.
+    ///
+    /// Telescoping: the reaction data of the calling reactor (e.g. [`BroadcastEvent`]) is scoped to that reactor's
+    /// run and is released before the queued reactions run, so nested reactions never see it. The continuation is
+    /// not a reaction, so reaction readers will be empty in it. Pass any data the continuation needs into the
+    /// closure.
+    ///
+    /// This is an advanced tool.
+    /*
+    ```rust
+    rc.on(broadcast::<Load>(),
+        |mut c: Commands|
+        {
+            for part in parts() { c.react().broadcast(part); }
+            c.react().then_after_queued(|| finish_loading());
+        }
+    );
+    ```
+    */
+    pub fn then_after_queued<M, R: CobwebResult>(
+        &mut self,
+        continuation: impl IntoSystem<(), R, M> + Send + Sync + 'static
+    ){
+        let sys_command = self.commands.spawn_system_command(continuation);
+        self.commands.queue(
+            move |world: &mut World|
+            {
+                sys_command.apply(world);
+                let _ = world.try_despawn(*sys_command);
+            }
+        );
+    }
+
+    /// Revokes the current [`SystemCommand`] (e.g. a reactor), removing all of its triggers and despawning it.
+    ///
+    /// This lets a reactor revoke itself without holding its own [`RevokeToken`]. The reactor finishes its current
+    /// run, and reactions to commands queued before this call still run.
+    ///
+    /// Does nothing (with a warning) if no system command is running.
+    pub fn revoke_current(&mut self)
+    {
+        self.commands.syscall_with_validation((), revoke_current_reactor, validate_rc);
+    }
+
+    /// Revokes a reactor.
+    pub fn revoke(&mut self, token: RevokeToken)
+    {
+        self.commands.syscall_with_validation(token, revoke_reactor, validate_rc);
+    }
+
+    /// Removes all reaction triggers that target `entity`.
+    ///
+    /// Entity-specific triggers are removed automatically when an entity is despawned, so this is only needed to
+    /// control ordering (e.g. to stop reactions to an entity before manually despawning it). All entity-specific
+    /// triggers ([`entity_insertion`], [`entity_mutation`], [`entity_removal`], [`entity_event`]) and [`despawn`]
+    /// triggers for the entity are removed, so despawning the entity afterward will not run despawn reactors.
+    ///
+    /// Reactors that also have triggers not targeting the entity are unaffected apart from losing the pruned
+    /// triggers. Reactors with no remaining triggers are cleaned up according to their [`ReactorMode`].
+    pub fn prune_entity_reactors(&mut self, entity: Entity)
+    {
+        self.commands.syscall_with_validation(entity, prune_entity_reactors, validate_rc);
+    }
+
+    /// Moves the entity-specific triggers of type `T` for `command` from `old` to `new`.
+    ///
+    /// `T` is the component type of [`entity_insertion`], [`entity_mutation`], and [`entity_removal`] triggers, or
+    /// the event type of [`entity_event`] triggers. Each trigger keeps its kind, so e.g. a mutation trigger on `old`
+    /// becomes a mutation trigger on `new`. Other triggers of the reactor are unaffected. The reactor keeps its
+    /// [`SystemCommand`] and system state, which makes this useful for reusing reactors with pooled entities.
+    ///
+    /// Retained entity events targeting `new` are replayed for moved event triggers, as if they were newly
+    /// registered. Does nothing if the reactor has no such triggers on `old`. If `new` doesn't exist, the triggers
+    /// are removed and the reactor is cleaned up according to its [`ReactorMode`] if it has no other triggers.
+    ///
+    /// [`RevokeToken`]s still name `old`, so this is intended for [`ReactorMode::Persistent`] reactors (see
+    /// [`Self::on_persistent`]).
+    pub fn retarget<T: Send + Sync + 'static>(&mut self, command: SystemCommand, old: Entity, new: Entity)
+    {
+        self.commands.syscall_with_validation(
+            (TypeId::of::<T>(), command, old, new),
+            retarget_entity_reactor,
+            validate_rc
+        );
+    }
+
+    /// Registers a reactor triggered by ECS changes.
+    ///
+    /// You can tie a reactor to multiple reaction triggers.
+    /// Duplicate triggers will be ignored.
+    ///
+    /// Reactions are not merged together. If you register a reactor for triggers
+    /// `(resource_mutation::<A>(), resource_mutation::<B>())`, then mutate `A` and `B` in succession, the reactor will
+    /// execute twice.
+    ///
+    /// Uses [`ReactorMode::Cleanup`].
+    ///
+    /// Example:
+    /// ```no_run
+    /// rcommands.on((resource_mutation::<MyRes>(), mutation::<MyComponent>()), my_reactor_system);
+    /// ```
+    pub fn on<M, R: CobwebResult>(
+        &mut self,
+        triggers : impl ReactionTriggerBundle,
+        reactor  : impl IntoSystem<(), R, M> + Send + Sync + 'static
+    ){
+        let sys_command = self.commands.spawn_system_command(reactor);
+        let _ = self.with(triggers, sys_command, ReactorMode::Cleanup);
+    }
+
+    /// Registers a reactor triggered by mutations of `C` on `entity`, with reactor-local `data` stored on the entity.
+    ///
+    /// The reactor can access `data` with the [`EntityData<D>`] system parameter. The data is stored in a component on
+    /// `entity` (keyed by the reactor), so it is dropped when the entity is despawned. The reactor is cleaned up at
+    /// the same time, since its only trigger targets the entity.
+    ///
+    /// Does nothing if the entity doesn't exist.
+    ///
+    /// Uses [`ReactorMode::Cleanup`].
+    pub fn on_entity_with_data<C: ReactComponent, D: Send + Sync + 'static, M, R: CobwebResult>(
+        &mut self,
+        entity  : Entity,
+        data    : D,
+        reactor : impl IntoSystem<(), R, M> + Send + Sync + 'static
+    ){
+        let sys_command = self.commands.spawn_system_command(reactor);
+        self.commands.syscall((entity, sys_command, data), insert_entity_reactor_data::<D>);
+        let _ = self.with(entity_mutation::<C>(entity), sys_command, ReactorMode::Cleanup);
+    }
+
+    /// Registers a reactor triggered by ECS changes using [`ReactorMode::Persistent`].
+    ///
+    /// See [`Self::on`].
+    pub fn on_persistent<M, R: CobwebResult>(
+        &mut self,
+        triggers : impl ReactionTriggerBundle,
+        reactor  : impl IntoSystem<(), R, M> + Send + Sync + 'static
+    ) -> SystemCommand
+    {
+        let sys_command = self.commands.spawn_system_command(reactor);
+        self.with(triggers, sys_command, ReactorMode::Persistent);
+        sys_command
+    }
+
+    /// Registers a reactor triggered by ECS changes using [`ReactorMode::Revokable`].
+    ///
+    /// See [`Self::on`].
+    pub fn on_revokable<M, R: CobwebResult>(
+        &mut self,
+        triggers : impl ReactionTriggerBundle,
+        reactor  : impl IntoSystem<(), R, M> + Send + Sync + 'static
+    ) -> RevokeToken
+    {
+        let sys_command = self.commands.spawn_system_command(reactor);
+        self.with(triggers, sys_command, ReactorMode::Revokable).unwrap()
+    }
+
+    /// Registers a reactor triggered by ECS changes that is owned by `owner`.
+    ///
+    /// When `owner` is despawned the reactor is revoked, regardless of what its `triggers` are.
+    ///
+    /// Equivalent to [`Self::on_scoped`].
+    pub fn on_owned_by<M, R: CobwebResult>(
+        &mut self,
+        owner    : Entity,
+        triggers : impl ReactionTriggerBundle,
+        reactor  : impl IntoSystem<(), R, M> + Send + Sync + 'static
+    ) -> RevokeToken
+    {
+        self.on_scoped(owner, triggers, reactor)
+    }
+
+    /// Registers a reactor triggered by ECS changes whose lifetime is scoped to `parent`.
+    ///
+    /// When `parent` is despawned the reactor is revoked, regardless of what its `triggers` are. This is useful for
+    /// tying many reactors to one entity (e.g. a UI widget's root node) so they are all cleaned up with it.
+    /// All reactors scoped to the same parent share one [`despawn`] reactor, which only revokes them, so the scoped
+    /// reactors do not run when the parent despawns (add a [`despawn`] trigger for that). Revoking a scoped reactor
+    /// early removes it from the parent's group, and the shared despawn reactor is revoked once the group is empty.
+    ///
+    /// If `parent` doesn't exist when the commands are applied then the reactor is revoked immediately.
+    ///
+    /// Uses [`ReactorMode::Cleanup`]. The reactor can be revoked early with the returned token.
+    pub fn on_scoped<M, R: CobwebResult>(
+        &mut self,
+        parent   : Entity,
+        triggers : impl ReactionTriggerBundle,
+        reactor  : impl IntoSystem<(), R, M> + Send + Sync + 'static
+    ) -> RevokeToken
+    {
+        let sys_command = self.commands.spawn_system_command(reactor);
+        let _ = self.with(triggers, sys_command, ReactorMode::Cleanup);
+        let token = RevokeToken::new_from(sys_command, triggers);
+        self.commands.syscall_with_validation(
+            (parent, token.clone()),
+            register_parent_scoped_reactor,
+            validate_rc
+        );
+
+        token
+    }
+
+    /// Registers a reactor triggered by ECS changes using [`ReactorMode::Revokable`], and initializes the reactor's
+    /// system immediately.
+    ///
+    /// Normally a reactor's system is initialized the first time it runs, so reactors that never fire cost nothing.
+    /// This is useful for latency-sensitive reactors, where the first reaction shouldn't be slowed down by
+    /// initialization. The system is initialized with [`SystemCommandCallback::new_initialized`] when the commands
+    /// are applied.
+    ///
+    /// See [`Self::on`].
+    pub fn on_eager<M, R: CobwebResult>(
+        &mut self,
+        triggers : impl ReactionTriggerBundle,
+        reactor  : impl IntoSystem<(), R, M> + Send + Sync + 'static
+    ) -> RevokeToken
+    {
+        let sys_command = SystemCommand(self.commands.spawn_empty().id());
+        self.commands.queue(
+            move |world: &mut World|
+            {
+                let callback = SystemCommandCallback::new_initialized(world, reactor);
+                let Ok(mut entity) = world.get_entity_mut(*sys_command) else { return; };
+                entity.insert(SystemCommandStorage::new(callback));
+            }
+        );
+        self.with(triggers, sys_command, ReactorMode::Revokable).unwrap()
+    }
+
+    /// Registers a reactor triggered by ECS changes whose lifetime is tied to the returned [`ReactorGuard`].
+    ///
+    /// Dropping the guard revokes the reactor, so guards can be stored in other structs for automatic cleanup. The
+    /// guard can't access the world, so the reactor entity is despawned and its triggers are revoked the next time
+    /// entities are garbage collected (see [`AutoDespawner`]).
+    ///
+    /// Uses [`ReactorMode::Persistent`] internally, so the guard is the only thing keeping the reactor alive.
+    ///
+    /// See [`Self::on`].
+    pub fn on_scoped_handle<M, R: CobwebResult>(
+        &mut self,
+        triggers : impl ReactionTriggerBundle,
+        reactor  : impl IntoSystem<(), R, M> + Send + Sync + 'static
+    ) -> ReactorGuard
+    {
+        let sys_command = self.commands.spawn_system_command(reactor);
+        self.with(triggers, sys_command, ReactorMode::Persistent);
+
+        let guard = ReactorGuard{ id: sys_command, signal: Arc::default() };
+        let token = RevokeToken::new_from(sys_command, triggers);
+        self.commands.syscall_with_validation(
+            (token, Arc::downgrade(&guard.signal)),
+            register_scoped_reactor,
+            validate_rc
+        );
+
+        guard
+    }
+
+    /// Registers a fallible reactor triggered by ECS changes, with an error handler that can write errors into the
+    /// world (e.g. to display them in UI).
+    ///
+    /// The reactor runs like a normal [`ReactorMode::Cleanup`] reactor. After its deferred commands are applied, an
+    /// `Err` result is passed to `on_error` instead of to [`CobwebResult::handle`]. The error does not abort the
+    /// reaction tree, so other reactors (and any reactions scheduled by the failing reactor) still run.
+    ///
+    /// See [`Self::on`].
+    pub fn on_fallible<M, E: Send + Sync + 'static>(
+        &mut self,
+        triggers : impl ReactionTriggerBundle,
+        reactor  : impl IntoSystem<(), Result<(), E>, M> + Send + Sync + 'static,
+        on_error : impl Fn(&E, &mut World) + Send + Sync + 'static
+    ){
+        let mut callback = RawCallbackSystem::new(reactor);
+        let command = move |world: &mut World, cleanup: SystemCommandCleanup|
+        {
+            let result = callback.run_with_cleanup(world, (), move |world: &mut World| cleanup.run(world));
+            if let Err(err) = result { (on_error)(&err, world); }
+        };
+        let sys_command = self.commands.spawn_system_command_from(SystemCommandCallback::with(command));
+        let _ = self.with(triggers, sys_command, ReactorMode::Cleanup);
+    }
+
+    /// Starts a [`ReactPipeline`] of reactor stages, where each stage receives the previous stage's output.
+    ///
+    /// Add stages with [`EmptyReactPipeline::then`] and register the pipeline with [`ReactPipeline::on`].
+    pub fn pipeline(&mut self) -> EmptyReactPipeline<'_>
+    {
+        EmptyReactPipeline::new(self.reborrow())
+    }
+
+    /// Registers a reactor that offloads heavy work to Bevy's [`ComputeTaskPool`].
+    ///
+    /// When a trigger fires, `input` runs as a normal reactor and its output is moved into a `compute` task on the
+    /// [`ComputeTaskPool`]. When the task finishes, its output is sent to `apply` as a system event at the start of the
+    /// next frame that observes it (in `First`), so `apply` runs in a new tree of system commands and reactions it
+    /// triggers telescope as usual. Multiple tasks can be in flight at once, and their outputs are applied in task
+    /// spawn order among tasks that finished by the same frame.
+    ///
+    /// Revoking the returned token despawns `apply` along with the reactor. In-flight tasks are then dropped (which
+    /// cancels them if they have not finished), and their outputs are never applied.
+    pub fn on_async<I: Send + Sync + 'static, O: Send + Sync + 'static, M1, M2>(
+        &mut self,
+        triggers : impl ReactionTriggerBundle,
+        input    : impl IntoSystem<(), I, M1> + Send + Sync + 'static,
+        compute  : impl Fn(I) -> O + Send + Sync + 'static,
+        apply    : impl IntoSystem<In<O>, (), M2> + Send + Sync + 'static,
+    ) -> RevokeToken
+    {
+        let apply_command = self.commands.spawn_system_command_from(
+            SystemCommandCallback::with_event(apply, |_, _| ())
+        );
+
+        let compute = Arc::new(compute);
+        let token = self.on_revokable(triggers, input.pipe(
+            move |In(input): In<I>, mut tasks: ResMut<AsyncReactionTasks>|
+            {
+                let compute = compute.clone();
+                let task = ComputeTaskPool::get_or_init(TaskPool::default).spawn(async move {
+                    let output = (compute)(input);
+                    Box::new(move |c: &mut Commands| c.send_system_event(apply_command, output)) as AsyncReactionApply
+                });
+                tasks.push(apply_command, task);
+            }
+        ));
+        self.commands.entity(*token.id).add_child(*apply_command);
+
+        token
+    }
+
+    /// Registers a reactor that runs at most once per `throttle.min_interval`.
+    ///
+    /// With [`ThrottleMode::Leading`], triggers that fire before the interval has elapsed since the last run are
+    /// dropped. With [`ThrottleMode::Trailing`], triggers schedule a single run that executes in `First` once the
+    /// interval has elapsed. See [`Throttle`].
+    ///
+    /// Throttled triggers still schedule reactions, so the reaction tree is unchanged, but the reactor system is
+    /// skipped when those reactions run. Time is measured with the [`Time`] resource.
+    ///
+    /// See [`Self::on_revokable`].
+    pub fn on_throttled<M, R: CobwebResult>(
+        &mut self,
+        throttle : impl Into<Throttle>,
+        triggers : impl ReactionTriggerBundle,
+        reactor  : impl IntoSystem<(), R, M> + Send + Sync + 'static
+    ) -> RevokeToken
+    {
+        let mut callback = SystemCommandCallback::new(reactor);
+        let sys_command = self.commands.spawn_system_command_self(
+            move |command: SystemCommand, world: &mut World, cleanup: SystemCommandCleanup|
+            {
+                let now = world.resource::<Time>().elapsed();
+                let run = world.get_mut::<ReactorThrottle>(*command).is_some_and(|mut t| t.try_run(now));
+                if !run { cleanup.run(world); return; }
+                callback.run(world, cleanup);
+            }
+        );
+        self.commands.entity(*sys_command).insert(ReactorThrottle::new(throttle.into()));
+        self.with(triggers, sys_command, ReactorMode::Revokable).unwrap()
+    }
+
+    /// Registers a reactor that only runs while `gate_check` returns `true` for the [`ReactResource`] `Gate`.
+    ///
+    /// The gate is checked each time a reaction for the reactor runs. When the gate is off (or `Gate` doesn't exist),
+    /// the reaction is still scheduled and its data cleaned up as usual, but the reactor system is skipped. This is
+    /// cheaper than revoking and re-registering a reactor when the gate toggles often. Triggers that fire while the
+    /// gate is off are not replayed when it turns on.
+    ///
+    /// See [`Self::on_revokable`].
+    pub fn on_gated<Gate: ReactResource, M, R: CobwebResult>(
+        &mut self,
+        gate_check : impl Fn(&Gate) -> bool + Send + Sync + 'static,
+        triggers   : impl ReactionTriggerBundle,
+        reactor    : impl IntoSystem<(), R, M> + Send + Sync + 'static
+    ) -> RevokeToken
+    {
+        let mut callback = SystemCommandCallback::new(reactor);
+        let sys_command = self.commands.spawn_system_command_from(SystemCommandCallback::with(
+            move |world: &mut World, cleanup: SystemCommandCleanup|
+            {
+                if !world.get_react_resource::<Gate>().is_some_and(&gate_check) { cleanup.run(world); return; }
+                callback.run(world, cleanup);
+            }
+        ));
+        self.with(triggers, sys_command, ReactorMode::Revokable).unwrap()
+    }
+
+    /// Registers a reactor that waits for the [`ReactResource`] `Res` to exist before running.
+    ///
+    /// Triggers that fire before `Res` is inserted are buffered and replayed when it is inserted with
+    /// [`ReactResWorldExt::insert_react_resource`] (or the equivalent commands/app methods), so the reactor can use
+    /// [`ReactRes<Res>`] without checking if it exists. See [`Self::on_when_ready_with`].
+    pub fn on_when_ready<Res: ReactResource, M, R: CobwebResult>(
+        &mut self,
+        triggers : impl ReactionTriggerBundle,
+        reactor  : impl IntoSystem<(), R, M> + Send + Sync + 'static
+    ) -> RevokeToken
+    {
+        self.on_when_ready_with::<Res, M, R>(EarlyTriggers::Replay, triggers, reactor)
+    }
+
+    /// Registers a reactor that waits for the [`ReactResource`] `Res` to exist before running, with control over
+    /// triggers that fire earlier.
+    ///
+    /// Early triggers are stored in a buffer resource for `Res` (in the order they fired), and the buffer is drained
+    /// right after `Res` is inserted. Replayed runs are not reactions, since reaction data is cleaned up when the trigger
+    /// fires, so reaction readers like [`BroadcastEvent`] will be empty. Use [`EarlyTriggers::Drop`] if the reactor
+    /// needs reaction data, or [`EarlyTriggers::ReplayOnce`] if it only needs to catch up on state once.
+    ///
+    /// Triggers that fire after `Res` is removed are buffered again until it is re-inserted.
+    ///
+    /// See [`Self::on_revokable`].
+    pub fn on_when_ready_with<Res: ReactResource, M, R: CobwebResult>(
+        &mut self,
+        early    : EarlyTriggers,
+        triggers : impl ReactionTriggerBundle,
+        reactor  : impl IntoSystem<(), R, M> + Send + Sync + 'static
+    ) -> RevokeToken
+    {
+        let mut callback = SystemCommandCallback::new(reactor);
+        let sys_command = self.commands.spawn_system_command_self(
+            move |command: SystemCommand, world: &mut World, cleanup: SystemCommandCleanup|
+            {
+                if !world.contains_react_resource::<Res>()
+                {
+                    cleanup.run(world);
+                    buffer_early_trigger::<Res>(world, command, early);
+                    return;
+                }
+                callback.run(world, cleanup);
+            }
+        );
+        self.with(triggers, sys_command, ReactorMode::Revokable).unwrap()
+    }
+
+    /// Registers a reactor that runs once per tree of system commands with all entities on which `C` was mutated.
+    ///
+    /// Mutations are collected by a [`mutation::<C>()`](mutation) reactor, and when the outermost system command in
+    /// the tree finishes the batched reactor runs with the collected entities (deduplicated, in order of first
+    /// mutation). Mutations made by different reactors in the same tree are merged into one batch. Mutations outside
+    /// system commands (e.g. in normal systems) are each handled by their own tree.
+    ///
+    /// The batched reactor runs in a new tree, so mutations of `C` made while it runs (including by the batched
+    /// reactor itself) are collected into a follow-up batch.
+    ///
+    /// Revoking the returned token will despawn the batched reactor.
+    pub fn on_batched<C: ReactComponent, M>(
+        &mut self,
+        reactor: impl IntoSystem<In<Vec<Entity>>, (), M> + Send + Sync + 'static
+    ) -> RevokeToken
+    {
+        let batch_reactor = self.commands.spawn_system_command(
+            (|mut event: SystemEvent<Vec<Entity>>| event.take().unwrap_or_default()).pipe(reactor)
+        );
+        let token = self.on_revokable(mutation::<C>(),
+            move |event: MutationEvent<C>, mut cache: ResMut<ReactCache>|
+            {
+                cache.push_batched_reaction(batch_reactor, event.entity());
+            }
+        );
+        self.commands.entity(*token.id).add_child(*batch_reactor);
+
+        token
+    }
+
+    /// Registers a reactor that runs in `Last` with all entities on which `C` was mutated since the reactor's last
+    /// run.
+    ///
+    /// Mutating a [`React<C>`] runs normal mutation reactors immediately in the current tree of system commands, but
+    /// Bevy's tick-based change detection (e.g. `Changed<React<C>>` filters) is only observed when polling systems run
+    /// later in the frame. If the same change is handled both reactively and by polling, this helper lets the
+    /// reactive side wait until the polling systems in `Update` and `PostUpdate` have seen the change, so work is not
+    /// done twice or in an inconsistent order.
+    ///
+    /// Mutations are collected by a [`mutation::<C>()`](mutation) reactor (deduplicated, in order of first mutation).
+    /// The reactor runs at the start of `Last` in its own tree, before the [`ReactPlugin`] collects removals,
+    /// despawns, and other detection-based reactions. Mutations made after that point (e.g. by reactions to removals
+    /// in `Last`) are collected into the next frame's run.
+    ///
+    /// Revoking the returned token will despawn the reactor.
+    pub fn on_after_change_detection<C: ReactComponent, M>(
+        &mut self,
+        reactor: impl IntoSystem<In<Vec<Entity>>, (), M> + Send + Sync + 'static
+    ) -> RevokeToken
+    {
+        let batch_reactor = self.commands.spawn_system_command(
+            (|mut event: SystemEvent<Vec<Entity>>| event.take().unwrap_or_default()).pipe(reactor)
+        );
+        self.commands.entity(*batch_reactor).insert(ChangeDetectionBatch::default());
+        let token = self.on_revokable(mutation::<C>(),
+            move |event: MutationEvent<C>, mut batches: Query<&mut ChangeDetectionBatch>|
+            {
+                let Ok(mut batch) = batches.get_mut(*batch_reactor) else { return; };
+                batch.push(event.entity());
+            }
+        );
+        self.commands.entity(*token.id).add_child(*batch_reactor);
+
+        token
+    }
+
+    /// Registers a reactor that runs once for each entity on which `C` was mutated in a tree of system commands, in
+    /// descending order of the entities' current `C` values (e.g. highest priority first).
+    ///
+    /// Mutations are collected the same way as [`Self::on_batched`]. When the tree ends, the collected entities are
+    /// sorted by reading their current [`React<C>`] values, and the reactor runs for each entity in order (ties keep
+    /// the order of first mutation). Entities that no longer have `C` are skipped. Sorting only applies to mutations
+    /// batched in the same tree; mutations outside system commands (e.g. in normal systems) are each handled by their
+    /// own tree.
+    ///
+    /// Revoking the returned token will despawn the reactor.
+    pub fn on_mutation_sorted<C: ReactComponent + Ord, M>(
+        &mut self,
+        reactor: impl IntoSystem<In<Entity>, (), M> + Send + Sync + 'static
+    ) -> RevokeToken
+    {
+        let entity_reactor = self.commands.spawn_system_command(
+            (|mut event: SystemEvent<Entity>| event.take().unwrap_or(Entity::PLACEHOLDER)).pipe(reactor)
+        );
+        let token = self.on_batched::<C, _>(
+            move |In(mut entities): In<Vec<Entity>>, mut c: Commands, components: Reactive<C>|
+            {
+                entities.retain(|entity| components.get(*entity).is_ok());
+                entities.sort_by(|a, b| components.get(*b).unwrap().cmp(components.get(*a).unwrap()));
+
+                for entity in entities
+                {
+                    c.send_system_event(entity_reactor, entity);
+                }
+            }
+        );
+        self.commands.entity(*token.id).add_child(*entity_reactor);
+
+        token
+    }
+
+    /// Registers a reactor that recomputes the [`ReactResource`] `R` whenever `triggers` fire.
+    ///
+    /// When a trigger fires, `compute` runs and its output is stored in `R` with [`ReactResMut::set_if_neq`], so
+    /// `R`'s [`resource_mutation`] reactors only run if the derived value actually changed. This avoids cascades of
+    /// reactions when a dependency changes without affecting the derived value.
+    ///
+    /// `R` is not computed on registration, and it must be inserted (e.g. with `init_react_resource`) before the
+    /// first trigger fires.
+    ///
+    /// Beware of dependency cycles. If `R` (directly or through other derived resources) is one of its own triggers
+    /// then every change will recompute it again, which may recurse until the values stop changing (or forever).
+    pub fn derive_resource<R: ReactResource + PartialEq, M>(
+        &mut self,
+        triggers : impl ReactionTriggerBundle,
+        compute  : impl IntoSystem<(), R, M> + Send + Sync + 'static
+    ) -> RevokeToken
+    {
+        self.on_revokable(triggers, compute.pipe(
+            |In(value): In<R>, mut c: Commands, mut derived: ReactResMut<R>|
+            {
+                derived.set_if_neq(&mut c, value);
+            }
+        ))
+    }
+
+    /// Binds the [`React<C>`] component on `entity` to the [`ReactResource`] config `Cfg`.
+    ///
+    /// Whenever `Cfg` is mutated, `extract` computes a new `C` from the config and it is stored on the entity with
+    /// [`React::set_if_neq`], so `C`'s mutation reactors only run if the extracted value actually changed. The
+    /// component is not updated on registration, and nothing happens if the entity doesn't have `React<C>`.
+    ///
+    /// The binding is revoked automatically when `entity` is despawned, or manually with the returned token.
+    pub fn bind_component_to_config<C: ReactComponent + PartialEq, Cfg: ReactResource>(
+        &mut self,
+        entity  : Entity,
+        extract : impl Fn(&Cfg) -> C + Send + Sync + 'static
+    ) -> RevokeToken
+    {
+        let token = self.on_revokable(resource_mutation::<Cfg>(),
+            move |mut c: Commands, config: ReactRes<Cfg>, mut components: ReactiveMut<C>|
+            {
+                components.set_if_neq(&mut c, entity, (extract)(&config));
+            }
+        );
+
+        let despawn_token = token.clone();
+        self.once(despawn(entity), move |mut c: Commands| c.react().revoke(despawn_token.clone()));
+
+        token
+    }
+
+    /// Registers a reactor that runs when a value extracted from the [`React<C>`] component on `entity` crosses
+    /// `threshold` (e.g. health dropping below zero).
+    ///
+    /// Each time `C` is inserted or mutated on `entity`, `extract` is applied to the current component and the result
+    /// is compared with the previous extracted value. A value is 'below' the threshold if it is less than `threshold`,
+    /// so a [`CrossDirection::Down`] crossing means the previous value was at or above the threshold and the current
+    /// value is below it. Mutations that stay on the same side of the threshold don't run the reactor.
+    ///
+    /// The previous value is recorded on registration if the entity has `C`, otherwise the first insertion of `C` only
+    /// records the value.
+    ///
+    /// The reactor is cleaned up automatically when `entity` is despawned, or it can be revoked with the returned token.
+    pub fn on_threshold<C: ReactComponent, M, R: CobwebResult>(
+        &mut self,
+        entity    : Entity,
+        threshold : f32,
+        extract   : impl Fn(&C) -> f32 + Send + Sync + 'static,
+        direction : CrossDirection,
+        reactor   : impl IntoSystem<(), R, M> + Send + Sync + 'static
+    ) -> RevokeToken
+    {
+        let mut callback = SystemCommandCallback::new(reactor);
+        let mut prev: Option<f32> = None;
+        let sys_command = self.commands.spawn_system_command_from(SystemCommandCallback::with(
+            move |world: &mut World, cleanup: SystemCommandCleanup|
+            {
+                let Some(current) = world.get::<React<C>>(entity).map(|c| (extract)(c)) else {
+                    cleanup.run(world);
+                    return;
+                };
+                let crossed = prev.is_some_and(|prev| direction.crossed(threshold, prev, current));
+                prev = Some(current);
+                if !crossed { cleanup.run(world); return; }
+                callback.run(world, cleanup);
+            }
+        ));
+        let triggers = (entity_insertion::<C>(entity), entity_mutation::<C>(entity));
+        let token = self.with(triggers, sys_command, ReactorMode::Revokable).unwrap();
+
+        // record the starting value
+        self.commands.queue(sys_command);
+
+        token
+    }
+
+    /// Registers a reactor that is stored in the [`KeyedReactors`] resource under `key`.
+    ///
+    /// Keyed reactors can be looked up with [`KeyedReactors::get`] and run with [`Self::run_keyed_reactor`] from any
+    /// system, without passing the [`SystemCommand`] around. Keys are compared by type and value, so e.g. `1u32` and
+    /// `1u64` are different keys. Re-registering a key revokes the reactor previously registered for that key.
+    ///
+    /// Revoking the returned token does not remove the reactor from [`KeyedReactors`].
+    pub fn register_keyed_reactor<K: Hash + Eq + Send + Sync + 'static, M, R: CobwebResult>(
+        &mut self,
+        key      : K,
+        triggers : impl ReactionTriggerBundle,
+        reactor  : impl IntoSystem<(), R, M> + Send + Sync + 'static
+    ) -> RevokeToken
+    {
+        let token = self.on_revokable(triggers, reactor);
+        self.commands.syscall_with_validation((key, token.clone()), register_keyed_reactor::<K>, validate_rc);
+        token
+    }
+
+    /// Runs the reactor registered for `key` with [`Self::register_keyed_reactor`].
+    ///
+    /// The run is not a reaction, so reaction readers like [`BroadcastEvent`] will be empty. Does nothing (with a
+    /// warning) if no reactor is registered for `key`.
+    pub fn run_keyed_reactor<K: Hash + Eq + Send + Sync + 'static>(&mut self, key: K)
+    {
+        self.commands.syscall_with_validation(key, run_keyed_reactor::<K>, validate_rc);
+    }
+
+    /// Registers a reactor triggered by ECS changes and labels it with a [`ReactorTag`].
+    ///
+    /// All reactors with the same tag can be revoked together with [`Self::revoke_tagged`].
+    ///
+    /// Uses [`ReactorMode::Cleanup`].
+    pub fn on_tagged<M, R: CobwebResult>(
+        &mut self,
+        tag      : impl Into<ReactorTag>,
+        triggers : impl ReactionTriggerBundle,
+        reactor  : impl IntoSystem<(), R, M> + Send + Sync + 'static
+    ){
+        let sys_command = self.commands.spawn_system_command(reactor);
+        self.commands.entity(*sys_command).insert(tag.into());
+        let _ = self.with(triggers, sys_command, ReactorMode::Cleanup);
+    }
+
+    /// Revokes all reactors labeled with `tag`.
+    ///
+    /// Tagged reactors don't have [`RevokeTokens`](RevokeToken), so instead we look up every reactor entity with a
+    /// matching [`ReactorTag`] and remove all trigger registrations that reference that reactor's [`SystemCommand`].
+    /// Since tagged reactors use [`ReactorMode::Cleanup`], they are despawned once their last registration is dropped.
+    ///
+    /// Triggers added to a tagged reactor via [`Self::with`] will also be revoked.
+    pub fn revoke_tagged(&mut self, tag: impl Into<ReactorTag>)
+    {
+        self.commands.syscall_with_validation(tag.into(), revoke_tagged_reactors, validate_rc);
+    }
+
+    /// Registers a reactor triggered by ECS changes with a [`SystemCommand`] and [`ReactorMode`].
     ///
     /// You can tie a reactor to multiple reaction triggers.
     /// Duplicate triggers will be ignored.
@@ -305,6 +1576,67 @@ impl<'w, 's> ReactCommands<'w, 's>
         }
     }
 
+    /// Registers a reactor with a raw system command callback and [`ReactorMode`].
+    ///
+    /// This is a low-level alternative to [`Self::on`] for reactors that need custom control over how they run, clean
+    /// up, and revoke themselves ([`Self::once`] is built the same way). The callback receives the world and the
+    /// [`SystemCommandCleanup`] of the current run.
+    ///
+    /// The callback must call `cleanup.run(world)` exactly once each time it runs, including runs where it skips its
+    /// work. If it runs a system, cleanup should be called after the system runs but before the system's deferred
+    /// commands are applied (see [`RawCallbackSystem::run_with_cleanup`]). Otherwise, reaction data (e.g. for
+    /// [`BroadcastEvent`]) may leak into other reactors or be dropped before the system can read it.
+    ///
+    /// Returns `None` unless [`ReactorMode::Revokable`] is used.
+    pub fn on_raw(
+        &mut self,
+        triggers : impl ReactionTriggerBundle,
+        callback : impl FnMut(&mut World, SystemCommandCleanup) + Send + Sync + 'static,
+        mode     : ReactorMode,
+    ) -> Option<RevokeToken>
+    {
+        let sys_command = self.commands.spawn_system_command_from(SystemCommandCallback::with(callback));
+        self.with(triggers, sys_command, mode)
+    }
+
+    /// Registers a reactor from an already-boxed system with a [`ReactorMode`].
+    ///
+    /// This is useful when reactors are generated at runtime (e.g. by a scripting layer), so the system doesn't have a
+    /// concrete type. New systems are initialized the first time they run. A system that was already initialized keeps
+    /// its state, but must have been initialized with this world (see [`SystemCommandCallback::from_boxed`]).
+    ///
+    /// Returns `None` unless [`ReactorMode::Revokable`] is used.
+    pub fn on_boxed(
+        &mut self,
+        triggers : impl ReactionTriggerBundle,
+        system   : BoxedSystem<(), ()>,
+        mode     : ReactorMode,
+    ) -> Option<RevokeToken>
+    {
+        let sys_command = self.commands.spawn_system_command_from(SystemCommandCallback::from_boxed(system));
+        self.with(triggers, sys_command, mode)
+    }
+
+    /// Registers a reactor with exclusive world access and a [`ReactorMode`].
+    ///
+    /// The reactor runs in the tree of system commands like any other reactor. Reaction data (e.g. for
+    /// [`BroadcastEvent`]) is available while the reactor runs and is cleaned up when it returns (or when the reactor
+    /// flushes the world's command queue, e.g. with [`ReactWorldExt::react`]). Commands queued with
+    /// `world.commands()` are then applied, so reactions they trigger run before the next reactor in the tree, the same
+    /// as for a reactor that uses `Commands`. Changes made directly to the world do not trigger reactions.
+    ///
+    /// Returns `None` unless [`ReactorMode::Revokable`] is used.
+    pub fn on_exclusive(
+        &mut self,
+        triggers : impl ReactionTriggerBundle,
+        reactor  : impl FnMut(&mut World) + Send + Sync + 'static,
+        mode     : ReactorMode,
+    ) -> Option<RevokeToken>
+    {
+        let sys_command = self.commands.spawn_system_command_from(SystemCommandCallback::from_exclusive(reactor));
+        self.with(triggers, sys_command, mode)
+    }
+
     /// Registers a one-off reactor triggered by ECS changes.
     ///
     /// Similar to [`Self::on_revokable`] except the reaction will run exactly once then get cleaned up.
@@ -322,30 +1654,72 @@ impl<'w, 's> ReactCommands<'w, 's>
         reactor  : S
     ) -> RevokeToken
     {
-        // register reactors
-        let entity = self.commands.spawn_empty().id();
-        let syscommand = SystemCommand(entity);
-        let mode = ReactorMode::Revokable;
+        let syscommand = SystemCommand(self.commands.spawn_empty().id());
         let revoke_token = RevokeToken::new_from(syscommand, triggers);
+        self.with_once(triggers, syscommand, reactor, ReactorMode::Revokable, Some(revoke_token.clone()));
+
+        revoke_token
+    }
+
+    /// Registers a one-off reactor triggered by ECS changes, without a [`RevokeToken`].
+    ///
+    /// Similar to [`Self::once`] except no token is made, so the reactor can't be revoked before it runs. This is
+    /// cheaper for fire-and-forget reactors. After the reaction runs the reactor is despawned, but its triggers are
+    /// not revoked. Uses [`ReactorMode::Cleanup`], so the remaining triggers are dropped the next time they fire
+    /// (without running anything).
+    ///
+    /// If an empty trigger bundle is used then the system will be dropped without running.
+    pub fn once_cleanup<M, R: CobwebResult, S: IntoSystem<(), R, M> + Send + Sync + 'static>(
+        &mut self,
+        triggers : impl ReactionTriggerBundle,
+        reactor  : S
+    ){
+        let syscommand = SystemCommand(self.commands.spawn_empty().id());
+        self.with_once(triggers, syscommand, reactor, ReactorMode::Cleanup, None);
+    }
+
+    /// Registers a reactor that will run once then despawn itself, revoking `revoke_token` if there is one.
+    fn with_once<M, R: CobwebResult, S: IntoSystem<(), R, M> + Send + Sync + 'static>(
+        &mut self,
+        triggers     : impl ReactionTriggerBundle,
+        syscommand   : SystemCommand,
+        reactor      : S,
+        mode         : ReactorMode,
+        revoke_token : Option<RevokeToken>,
+    ){
+        // register reactors
         self.commands.syscall_with_validation((triggers, syscommand, mode), register_reactors, validate_rc);
 
         // wrap reactor in a system that will be called once, then clean itself up
-        let revoke_token_clone = revoke_token.clone();
-        let mut once_reactor = Some(move |world: &mut World, cleanup: SystemCommandCleanup|
+        let mut once_reactor = Some((reactor, revoke_token));
+        let once_system = move |world: &mut World, cleanup: SystemCommandCleanup|
         {
+            let Some((reactor, revoke_token)) = once_reactor.take() else { return; };
             let mut callback = RawCallbackSystem::new(reactor);
             let result = callback.run_with_cleanup(world, (), move |w| cleanup.run(w));
             result.handle(world);
-            world.get_entity_mut(entity).ok().map(|e| e.despawn());
-            world.react(|rc| rc.revoke(revoke_token_clone));
-        });
-        let once_system = move |world: &mut World, cleanup: SystemCommandCleanup|
-        {
-            if let Some(reactor) = once_reactor.take() { (reactor)(world, cleanup); };
+            if let Ok(entity) = world.get_entity_mut(*syscommand) { entity.despawn(); }
+            if let Some(revoke_token) = revoke_token { world.react(|rc| rc.revoke(revoke_token)); }
         };
-        self.commands.entity(entity).try_insert(SystemCommandStorage::new(SystemCommandCallback::with(once_system)));
+        self.commands.entity(*syscommand)
+            .try_insert(SystemCommandStorage::new(SystemCommandCallback::with(once_system)));
+    }
 
-        revoke_token
+    /// Registers a one-off reactor that races several triggers, like `select`.
+    ///
+    /// The reactor runs once on whichever trigger fires first, then all of the triggers are revoked, including the
+    /// ones that did not fire. This is the same as [`Self::once`], which already fires on the first of any trigger in
+    /// the bundle.
+    ///
+    /// The reactor can read which trigger fired with the [`WinningTrigger`] system parameter. Reaction readers (e.g.
+    /// [`BroadcastEvent`]) will only have data for the winning trigger.
+    pub fn once_race<M, R: CobwebResult, S: IntoSystem<(), R, M> + Send + Sync + 'static>(
+        &mut self,
+        triggers : impl ReactionTriggerBundle,
+        reactor  : S
+    ) -> RevokeToken
+    {
+        self.once(triggers, reactor)
     }
 }
 
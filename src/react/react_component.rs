@@ -3,7 +3,9 @@ use crate::prelude::*;
 
 //third-party shortcuts
 use bevy::prelude::*;
+use bevy::ecs::component::{ComponentHooks, ComponentId, StorageType};
 use bevy::ecs::system::SystemParam;
+use bevy::ecs::world::DeferredWorld;
 
 //standard shortcuts
 use core::ops::Deref;
@@ -18,16 +20,55 @@ pub trait ReactComponent: Send + Sync + 'static {}
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Schedules insertion reactions for [`React`] components that were not inserted with [`ReactCommands::insert`]
+/// (e.g. when `React<C>` is inserted as a required component).
+///
+/// Such components are created with [`Default`], so their entity is [`Entity::PLACEHOLDER`] until the hook sets it.
+fn react_on_insert<C: ReactComponent>(mut world: DeferredWorld, entity: Entity, _: ComponentId)
+{
+    let Some(mut react) = world.get_mut::<React<C>>(entity) else { return; };
+    if react.entity != Entity::PLACEHOLDER { return; }
+    react.bypass_change_detection().entity = entity;
+
+    // no reactors can exist without the react plugin
+    if !world.contains_resource::<ReactCache>() { return; }
+    world.commands().syscall(entity, ReactCache::schedule_insertion_reaction::<C>);
+}
+
+/// Captures the value of a removed [`React`] component for [`removal_batched_with_values`] reactors.
+fn react_on_remove<C: ReactComponent>(mut world: DeferredWorld, entity: Entity, _: ComponentId)
+{
+    let Some(captures) = world.get_resource::<RemovalValueCaptures>() else { return; };
+    let Some(capture) = captures.capturer::<C>() else { return; };
+    (capture)(&mut world, entity);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// Component wrapper that enables reacting to component mutations.
 /// - WARNING: It is possible to remove a `React` from one entity and manually insert it to another entity. That WILL
 ///            break the react framework. Instead use `react_commands.insert(new_entity, react_component.take());`.
-#[derive(Component)]
+///
+/// `React<C>` implements [`Default`] when `C` does, so it can be used as a required component (e.g.
+/// `#[require(React<C>)]`). Insertion reactions are scheduled by a component hook when a default-constructed `React<C>`
+/// is inserted.
 pub struct React<C: ReactComponent>
 {
     pub(crate) entity    : Entity,
     pub(crate) component : C,
 }
 
+impl<C: ReactComponent> Component for React<C>
+{
+    const STORAGE_TYPE: StorageType = StorageType::Table;
+
+    fn register_component_hooks(hooks: &mut ComponentHooks)
+    {
+        hooks.on_insert(react_on_insert::<C>)
+            .on_remove(react_on_remove::<C>);
+    }
+}
+
 impl<C: ReactComponent> React<C>
 {
     /// Immutably accesses the component.
@@ -37,12 +78,27 @@ impl<C: ReactComponent> React<C>
     }
 
     /// Mutably accesses the component and triggers reactions.
+    ///
+    /// When accessed through a [`Mut<React<C>>`](Mut), dereferencing marks the component as changed for Bevy change
+    /// detection, so `Changed<React<C>>` filters stay in sync with mutation reactions.
     pub fn get_mut<'a>(&'a mut self, c: &mut Commands) -> &'a mut C
     {
         c.syscall(self.entity, ReactCache::schedule_mutation_reaction::<C>);
         &mut self.component
     }
 
+    /// Mutably accesses the component and triggers reactions, stashing a clone of the current value so reactors can
+    /// read it with [`MutationEvent::previous`].
+    ///
+    /// The clone is dropped after the last reactor runs.
+    pub fn get_mut_tracked<'a>(&'a mut self, c: &mut Commands) -> &'a mut C
+    where
+        C: Clone
+    {
+        c.syscall((self.entity, self.component.clone()), ReactCache::schedule_tracked_mutation_reaction::<C>);
+        &mut self.component
+    }
+
     /// Allows manually triggering mutation reactions when in an exclusive context.
     pub fn trigger_mutation(entity: Entity, world: &mut World)
     {
@@ -50,6 +106,10 @@ impl<C: ReactComponent> React<C>
     }
 
     /// Mutably accesses the component without triggering reactions.
+    ///
+    /// Mutation reactors will not see the change. Use [`ReactCommands::trigger_mutation`] (or
+    /// [`Self::trigger_mutation`] in an exclusive context) afterward if reactors should run, otherwise they may be
+    /// left with stale state.
     pub fn get_noreact(&mut self) -> &mut C
     {
         &mut self.component
@@ -76,6 +136,14 @@ impl<C: ReactComponent> React<C>
     }
 }
 
+impl<C: ReactComponent + Default> Default for React<C>
+{
+    fn default() -> Self
+    {
+        Self{ entity: Entity::PLACEHOLDER, component: C::default() }
+    }
+}
+
 impl<C: ReactComponent> Deref for React<C>
 {
     type Target = C;
@@ -88,6 +156,110 @@ impl<C: ReactComponent> Deref for React<C>
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Reflection type data for [`ReactComponent`] types, for inspecting and editing [`React<C>`] components (e.g. in an
+/// editor) with mutation reactions.
+///
+/// Register it with `#[reflect(ReactComponent)]` on a type that derives `Reflect` and [`ReactComponent`], then look it
+/// up in the `AppTypeRegistry` with `registry.get_type_data::<ReflectReactComponent>(type_id)`.
+#[cfg(feature = "reflect")]
+#[derive(Clone)]
+pub struct ReflectReactComponent
+{
+    reflect : fn(&World, Entity) -> Option<&dyn Reflect>,
+    apply   : fn(&mut World, Entity, &dyn PartialReflect) -> bool,
+}
+
+#[cfg(feature = "reflect")]
+impl ReflectReactComponent
+{
+    /// Gets the reflected component on `entity`.
+    pub fn reflect<'w>(&self, world: &'w World, entity: Entity) -> Option<&'w dyn Reflect>
+    {
+        (self.reflect)(world, entity)
+    }
+
+    /// Applies a reflected value to the component on `entity` and triggers mutation reactions.
+    ///
+    /// Returns `false` if the entity doesn't have the component or if the value could not be applied. Reactions have
+    /// run when this returns.
+    pub fn apply(&self, world: &mut World, entity: Entity, value: &dyn PartialReflect) -> bool
+    {
+        (self.apply)(world, entity, value)
+    }
+}
+
+#[cfg(feature = "reflect")]
+impl<C: ReactComponent + Reflect> bevy::reflect::FromType<C> for ReflectReactComponent
+{
+    fn from_type() -> Self
+    {
+        Self{
+            reflect: |world, entity|
+            {
+                world.get::<React<C>>(entity).map(|react| react.get() as &dyn Reflect)
+            },
+            apply: |world, entity, value|
+            {
+                let Some(mut react) = world.get_mut::<React<C>>(entity) else { return false; };
+                if react.get_noreact().try_apply(value).is_err() { return false; }
+                React::<C>::trigger_mutation(entity, world);
+                true
+            },
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Serializes the [`React<C>`] component on `entity`.
+///
+/// The format is JSON of the inner `C` (the `React` wrapper is not included). Apply the bytes on another world with
+/// [`ReactCommands::apply_serialized`].
+///
+/// Returns an error if the entity doesn't have the component or if serialization fails.
+#[cfg(feature = "serde")]
+pub fn serialize_react<C: ReactComponent + serde::Serialize>(
+    world  : &World,
+    entity : Entity
+) -> Result<Vec<u8>, CobwebReactError>
+{
+    let react = world.get::<React<C>>(entity).ok_or(CobwebReactError::Reactive(entity, type_name::<C>()))?;
+    serde_json::to_vec(react.get()).map_err(|_| CobwebReactError::Serialize(type_name::<C>()))
+}
+
+/// Sets the [`React<C>`] component on `entity` to `value` with reactions, or inserts it if missing.
+pub(crate) fn apply_react_value<C: ReactComponent>(
+    In((entity, value)) : In<(Entity, C)>,
+    mut c               : Commands,
+    mut components      : Query<&mut React<C>>
+){
+    match components.get_mut(entity)
+    {
+        Ok(mut react) => *react.get_mut(&mut c) = value,
+        Err(_) => c.react().insert(entity, value),
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Inserts a [`React<C>`] built with [`FromWorld`] on `entity` with reactions if the entity doesn't have one.
+///
+/// Returns `true` if the component was inserted.
+pub(crate) fn ensure_react_component<C: ReactComponent + FromWorld>(world: &mut World, entity: Entity) -> bool
+{
+    if world.get_entity(entity).map_or(true, |e| e.contains::<React<C>>()) { return false; }
+
+    // The entity may be despawned or given a `C` while building the component.
+    let component = C::from_world(world);
+    if world.get_entity(entity).map_or(true, |e| e.contains::<React<C>>()) { return false; }
+
+    world.commands().react().insert(entity, component);
+    world.flush();
+    true
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// System parameter for accessing [`React<T>`] components immutably.
 ///
 /// See [`ReactiveMut`] for the mutable version.
@@ -176,7 +348,8 @@ impl<'w, 's, T: ReactComponent> ReactiveMut<'w, 's, T>
 
     /// Gets a mutable reference to `T` on `entity`.
     ///
-    /// Does not trigger reactions.
+    /// Does not trigger reactions. This is useful for bulk changes where mutation reactors should run once at the
+    /// end. Use [`ReactCommands::trigger_mutation`] afterward, otherwise reactors may be left with stale state.
     pub fn get_noreact(&mut self, entity: Entity) -> Result<&mut T, CobwebReactError>
     {
         let t = type_name::<T>();
@@ -198,25 +371,33 @@ impl<'w, 's, T: ReactComponent> ReactiveMut<'w, 's, T>
     /// Sets a new value on the specified entity if it would change.
     ///
     /// Returns the previous value if changed.
+    ///
+    /// The component is only marked as changed for Bevy change detection if the value changed.
     pub fn set_if_neq(&mut self, c: &mut Commands, entity: Entity, new: T) -> Option<T>
     where
         T: PartialEq
     {
         let (_, mut x) = self.components.get_mut(entity).ok()?;
-        (*x).set_if_neq(c, new)
+        let old = x.bypass_change_detection().set_if_neq(c, new)?;
+        x.set_changed();
+        Some(old)
     }
 
     /// Sets a new value on a single entity if it would change.
     ///
     /// Returns the previous value if changed.
     ///
+    /// The component is only marked as changed for Bevy change detection if the value changed.
+    ///
     /// Panics if the inner query doesn't have exactly one entity.
     pub fn set_single_if_not_eq(&mut self, c: &mut Commands, new: T) -> (Entity, Option<T>)
     where
         T: PartialEq
     {
         let (e, mut x) = self.components.single_mut();
-        (e, (*x).set_if_neq(c, new))
+        let old = x.bypass_change_detection().set_if_neq(c, new);
+        if old.is_some() { x.set_changed(); }
+        (e, old)
     }
 }
 
@@ -0,0 +1,183 @@
+//local shortcuts
+use crate::prelude::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+//standard shortcuts
+
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn record_diff_insertion<C: ReactComponent + Clone + PartialEq>(
+    event      : InsertionEvent<C>,
+    components : Reactive<C>,
+    mut buffer : ResMut<ReactDiffBuffer<C>>,
+){
+    let Ok(entity) = event.get() else { return; };
+    let Ok(value) = components.get(entity) else { return; };
+    buffer.record_insertion(entity, value);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn record_diff_mutation<C: ReactComponent + Clone + PartialEq>(
+    event      : MutationEvent<C>,
+    components : Reactive<C>,
+    mut buffer : ResMut<ReactDiffBuffer<C>>,
+){
+    let Ok(entity) = event.get() else { return; };
+    let Ok(value) = components.get(entity) else { return; };
+    buffer.record_mutation(entity, value);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn record_diff_removal<C: ReactComponent + Clone + PartialEq>(
+    event      : RemovalEvent<C>,
+    mut buffer : ResMut<ReactDiffBuffer<C>>,
+){
+    let Ok(entity) = event.get() else { return; };
+    buffer.record_removal(entity);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Sets up diff tracking for `C`. See [`ReactCommands::enable_diff_tracking`].
+pub(crate) fn enable_react_diff_tracking<C: ReactComponent + Clone + PartialEq>(world: &mut World)
+{
+    if world.contains_resource::<ReactDiffBuffer<C>>() { return; }
+
+    // record the current values so the first mutation of each entity has an old value
+    let mut buffer = ReactDiffBuffer::<C>::default();
+    let mut query = world.query::<(Entity, &React<C>)>();
+    for (entity, react) in query.iter(world)
+    {
+        buffer.values.insert(entity, react.get().clone());
+    }
+    world.insert_resource(buffer);
+
+    world.react(
+        |rc|
+        {
+            rc.on_persistent(insertion::<C>(), record_diff_insertion::<C>);
+            rc.on_persistent(mutation::<C>(), record_diff_mutation::<C>);
+            rc.on_persistent(removal::<C>(), record_diff_removal::<C>);
+        }
+    );
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A change to a [`React<C>`] component recorded by a [`ReactDiffBuffer<C>`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ReactDiff<C>
+{
+    /// The entity with the component.
+    pub entity: Entity,
+    /// The value before the first mutation since the buffer was last drained.
+    pub old: C,
+    /// The value after the most recent mutation.
+    pub new: C,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Resource that records mutations of [`React<C>`] components as [`ReactDiffs`](ReactDiff), e.g. for network delta
+/// sync.
+///
+/// Only exists after [`ReactCommands::enable_diff_tracking`] is called for `C`. Mutations before that are not recorded.
+///
+/// The buffer keeps a copy of the latest value of every `React<C>` component, which is used as the `old` value when
+/// the component is mutated. Mutations of the same entity are coalesced until the buffer is drained with
+/// [`Self::drain`]: the diff keeps the `old` value from the first mutation and the `new` value from the latest one.
+/// Diffs where the value ends up unchanged are skipped. Drain the buffer once per frame to get per-frame diffs.
+///
+/// Insertions are not recorded as diffs. Re-inserting `C` on an entity discards its pending diff, and removing `C`
+/// discards the pending diff and the stored value.
+#[derive(Resource)]
+pub struct ReactDiffBuffer<C: ReactComponent + Clone + PartialEq>
+{
+    /// Latest known value of each entity's component.
+    values: HashMap<Entity, C>,
+    /// Pending diffs in the order entities were first mutated since the last drain.
+    diffs: Vec<Option<ReactDiff<C>>>,
+    /// Index of each entity's pending diff (entity : index into `diffs`).
+    pending: HashMap<Entity, usize>,
+}
+
+impl<C: ReactComponent + Clone + PartialEq> ReactDiffBuffer<C>
+{
+    fn record_insertion(&mut self, entity: Entity, value: &C)
+    {
+        self.discard_pending(entity);
+        self.values.insert(entity, value.clone());
+    }
+
+    fn record_mutation(&mut self, entity: Entity, value: &C)
+    {
+        // if there is no previous value, start tracking the entity
+        let Some(prev) = self.values.get_mut(&entity) else {
+            self.values.insert(entity, value.clone());
+            return;
+        };
+        if *prev == *value { return; }
+        let old = std::mem::replace(prev, value.clone());
+
+        // coalesce with the pending diff
+        if let Some(diff) = self.pending.get(&entity).and_then(|idx| self.diffs[*idx].as_mut())
+        {
+            diff.new = value.clone();
+            return;
+        }
+        self.pending.insert(entity, self.diffs.len());
+        self.diffs.push(Some(ReactDiff{ entity, old, new: value.clone() }));
+    }
+
+    fn record_removal(&mut self, entity: Entity)
+    {
+        self.discard_pending(entity);
+        let _ = self.values.remove(&entity);
+    }
+
+    fn discard_pending(&mut self, entity: Entity)
+    {
+        let Some(idx) = self.pending.remove(&entity) else { return; };
+        self.diffs[idx] = None;
+    }
+
+    /// Returns the number of entities with pending diffs.
+    ///
+    /// This includes diffs where the value ends up unchanged, which are skipped by [`Self::drain`].
+    pub fn len(&self) -> usize
+    {
+        self.pending.len()
+    }
+
+    /// Returns `true` if there are no pending diffs.
+    pub fn is_empty(&self) -> bool
+    {
+        self.pending.is_empty()
+    }
+
+    /// Removes all pending diffs, in the order entities were first mutated.
+    pub fn drain(&mut self) -> impl Iterator<Item = ReactDiff<C>> + '_
+    {
+        self.pending.clear();
+        self.diffs
+            .drain(..)
+            .flatten()
+            .filter(|diff| diff.old != diff.new)
+    }
+}
+
+impl<C: ReactComponent + Clone + PartialEq> Default for ReactDiffBuffer<C>
+{
+    fn default() -> Self
+    {
+        Self{ values: HashMap::default(), diffs: Vec::default(), pending: HashMap::default() }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
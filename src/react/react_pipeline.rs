@@ -0,0 +1,140 @@
+//local shortcuts
+use crate::prelude::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+
+//standard shortcuts
+use std::marker::PhantomData;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Spawns one pipeline stage, given the stage that should receive its output (if any).
+type StageBuilder = Box<dyn FnOnce(&mut Commands, Option<SystemCommand>) -> SystemCommand>;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A reactor pipeline with no stages.
+///
+/// Made with [`ReactCommands::pipeline`]. Add the first stage with [`Self::then`].
+pub struct EmptyReactPipeline<'a>
+{
+    rc: ReactCommands<'a, 'a>,
+}
+
+impl<'a> EmptyReactPipeline<'a>
+{
+    pub(crate) fn new(rc: ReactCommands<'a, 'a>) -> Self
+    {
+        Self{ rc }
+    }
+
+    /// Adds the first stage of the pipeline.
+    ///
+    /// The first stage runs as the reactor when the pipeline's triggers fire, so it can read reaction events (e.g.
+    /// with [`BroadcastEvent`]).
+    pub fn then<O: Send + Sync + 'static, M>(
+        self,
+        stage: impl IntoSystem<(), O, M> + Send + Sync + 'static
+    ) -> ReactPipeline<'a, O>
+    {
+        let builder: StageBuilder = Box::new(
+            move |commands: &mut Commands, next: Option<SystemCommand>| -> SystemCommand
+            {
+                let mut stage = RawCallbackSystem::new(stage);
+                commands.spawn_system_command_from(SystemCommandCallback::with(
+                    move |world: &mut World, cleanup: SystemCommandCleanup|
+                    {
+                        let output = stage.run_with_cleanup(world, (), move |world: &mut World| cleanup.run(world));
+                        let Some(next) = next else { return; };
+                        world.send_system_event(next, output);
+                    }
+                ))
+            }
+        );
+
+        ReactPipeline{ rc: self.rc, stages: vec![builder], _phantom: PhantomData }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A chain of reactor stages where each stage receives the previous stage's output as input.
+///
+/// Made with [`ReactCommands::pipeline`]. Add stages with [`Self::then`] and register the pipeline with [`Self::on`].
+///
+/// Each stage is a separate [`SystemCommand`]. A stage's output is sent to the next stage as a system event, so it
+/// is stored on a system event data entity until the next stage takes it as input. The next stage runs immediately
+/// (after the previous stage's deferred commands are applied) in the same tree of system commands, and the data
+/// entity is despawned after it runs. The output of the last stage is dropped.
+///
+/*
+```rust
+let token = rc.pipeline()
+    .then(|event: BroadcastEvent<usize>| *event.read())
+    .then(|In(value): In<usize>| value * 2)
+    .then(|In(doubled): In<usize>| println!("{doubled}"))
+    .on(broadcast::<usize>());
+```
+*/
+pub struct ReactPipeline<'a, O: Send + Sync + 'static>
+{
+    rc: ReactCommands<'a, 'a>,
+    stages: Vec<StageBuilder>,
+    _phantom: PhantomData<fn() -> O>,
+}
+
+impl<'a, O: Send + Sync + 'static> ReactPipeline<'a, O>
+{
+    /// Adds a stage that receives the output of the previous stage.
+    pub fn then<N: Send + Sync + 'static, M>(
+        mut self,
+        stage: impl IntoSystem<In<O>, N, M> + Send + Sync + 'static
+    ) -> ReactPipeline<'a, N>
+    {
+        let builder: StageBuilder = Box::new(
+            move |commands: &mut Commands, next: Option<SystemCommand>| -> SystemCommand
+            {
+                commands.spawn_system_command_from(SystemCommandCallback::with_event(stage,
+                    move |world: &mut World, output: N|
+                    {
+                        let Some(next) = next else { return; };
+                        world.send_system_event(next, output);
+                    }
+                ))
+            }
+        );
+        self.stages.push(builder);
+
+        ReactPipeline{ rc: self.rc, stages: self.stages, _phantom: PhantomData }
+    }
+
+    /// Registers the pipeline with [`ReactorMode::Revokable`].
+    ///
+    /// The first stage is the reactor for `triggers`, and later stages are despawned along with it. Revoking the
+    /// returned token will despawn the whole pipeline.
+    pub fn on(mut self, triggers: impl ReactionTriggerBundle) -> RevokeToken
+    {
+        let mut commands = self.rc.commands();
+        let mut next = None;
+        let mut later_stages = Vec::with_capacity(self.stages.len());
+        while let Some(builder) = self.stages.pop()
+        {
+            let stage = (builder)(&mut commands, next);
+            later_stages.push(stage);
+            next = Some(stage);
+        }
+        let first = later_stages.pop().expect("pipelines have at least one stage");
+
+        let token = self.rc.with(triggers, first, ReactorMode::Revokable).unwrap();
+        let mut first_commands = self.rc.commands.entity(*first);
+        for stage in later_stages
+        {
+            first_commands.add_child(*stage);
+        }
+
+        token
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -7,7 +7,7 @@ use bevy::ecs::component::Tick;
 use bevy::ecs::system::SystemParam;
 
 //standard shortcuts
-use core::ops::Deref;
+use core::ops::{Deref, DerefMut};
 
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
@@ -17,6 +17,53 @@ fn trigger_resource_mutation<R: ReactResource>(mut c: Commands)
     c.react().trigger_resource_mutation::<R>();
 }
 
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Takes the fields changed since the last field-tracked mutation reactions were scheduled.
+fn take_pending_fields<R: ReactResource>(mut inner: ResMut<ReactResInner<R>>) -> FieldMask
+{
+    std::mem::take(&mut inner.bypass_change_detection().pending_fields)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Snapshot of a reactive resource for [`resource_value_changed`] reactors.
+#[derive(Resource)]
+struct ResourceChangeSnapshot<R: ReactResource>(Option<R>);
+
+/// Schedules [`resource_value_changed`] reactions if `R` differs from its snapshot, then updates the snapshot.
+fn check_resource_value_changed<R: ReactResource + PartialEq + Clone>(world: &mut World)
+{
+    let current = world.get_react_resource::<R>();
+    let snapshot = world.get_resource::<ResourceChangeSnapshot<R>>().and_then(|snapshot| snapshot.0.as_ref());
+    if current == snapshot { return; }
+
+    let current = current.cloned();
+    world.insert_resource(ResourceChangeSnapshot(current));
+    world.syscall((), ReactCache::schedule_resource_value_changed_reaction::<R>);
+}
+
+/// Registers a [`resource_value_changed`] reactor.
+///
+/// The snapshot is refreshed when the first reactor is registered, since changes are not tracked while there are no
+/// reactors.
+pub(crate) fn register_resource_value_changed_reactor<R: ReactResource + PartialEq + Clone>(
+    In(handle) : In<ReactorHandle>,
+    world      : &mut World
+){
+    world.resource_scope(
+        move |world, mut cache: Mut<ReactCache>|
+        {
+            if !cache.has_resource_value_changed_reactors::<R>()
+            {
+                let current = world.get_react_resource::<R>().cloned();
+                world.insert_resource(ResourceChangeSnapshot(current));
+            }
+            cache.register_resource_value_changed_reactor::<R>(handle, check_resource_value_changed::<R>);
+        }
+    );
+}
+
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
 
@@ -25,6 +72,8 @@ fn trigger_resource_mutation<R: ReactResource>(mut c: Commands)
 struct ReactResInner<R: ReactResource>
 {
     resource: R,
+    /// Fields changed with [`ReactResMut::get_field_mut`] whose reactions have not been scheduled yet.
+    pending_fields: FieldMask,
 }
 
 impl<R: ReactResource> ReactResInner<R>
@@ -32,14 +81,7 @@ impl<R: ReactResource> ReactResInner<R>
     /// New react resource.
     fn new(resource: R) -> Self
     {
-        Self{ resource }
-    }
-
-    /// Mutably access the resource and trigger reactions.
-    fn get_mut<'a>(&'a mut self, c: &mut Commands) -> &'a mut R
-    {
-        c.react().trigger_resource_mutation::<R>();
-        &mut self.resource
+        Self{ resource, pending_fields: FieldMask::NONE }
     }
 
     /// Mutably access the resource without triggering reactions.
@@ -62,6 +104,15 @@ impl<R: ReactResource> ReactResInner<R>
         Some(old)
     }
 
+    /// Mutably access the resource and trigger reactions if the callback reports a change.
+    fn modify(&mut self, c: &mut Commands, f: impl FnOnce(&mut R) -> bool) -> bool
+    {
+        if !(f)(&mut self.resource) { return false; }
+
+        c.react().trigger_resource_mutation::<R>();
+        true
+    }
+
     /// Unwrap the resource.
     fn take(self) -> R
     {
@@ -125,9 +176,66 @@ pub struct ReactResMut<'w, R: ReactResource>
 impl<'w, R: ReactResource> ReactResMut<'w, R>
 {
     /// Mutably access the resource and trigger reactions.
-    pub fn get_mut<'a>(&'a mut self, c: &mut Commands) -> &'a mut R
+    ///
+    /// Reactions are only triggered if the returned guard is mutably dereferenced. See [`ReactResMutGuard`].
+    pub fn get_mut<'a, 'cw, 'cs>(&'a mut self, c: &'a mut Commands<'cw, 'cs>) -> ReactResMutGuard<'a, 'cw, 'cs, R>
+    {
+        ReactResMutGuard{
+            commands : c,
+            resource : self.inner.reborrow().map_unchanged(|inner| &mut inner.resource),
+            mutated  : false,
+        }
+    }
+
+    /// Begins a batch of changes to the resource.
+    ///
+    /// Resource mutation reactions are scheduled once when the returned guard is dropped. See
+    /// [`ReactResChangeGuard`].
+    /*
+    ```rust
+    let mut guard = react_res.begin_change(&mut rc);
+    guard.field = x;
+    guard.other = y;
+    drop(guard);
+    ```
+    */
+    pub fn begin_change<'a, 'cw, 'cs>(
+        &'a mut self,
+        rc: &'a mut ReactCommands<'cw, 'cs>
+    ) -> ReactResChangeGuard<'a, 'cw, 'cs, R>
+    {
+        ReactResChangeGuard{
+            rc,
+            resource : self.inner.reborrow().map_unchanged(|inner| &mut inner.resource),
+            mutated  : false,
+        }
+    }
+
+    /// Mutably access the resource and trigger reactions that can read which fields changed.
+    ///
+    /// Only `fields` should be changed through the returned reference. [`ResourceMutationEvent`] readers will see
+    /// `fields` OR'd with the fields of other calls to this method made before the reactions are scheduled (i.e.
+    /// before the commands are applied), since those calls are coalesced into one mutation reaction.
+    pub fn get_field_mut(&mut self, c: &mut Commands, fields: FieldMask) -> &mut R
+    where
+        R: TrackFields
     {
-        self.inner.get_mut(c)
+        let inner = self.inner.as_mut();
+        if inner.pending_fields.is_empty()
+        {
+            c.syscall((), take_pending_fields::<R>.pipe(ReactCache::schedule_resource_field_mutation_reaction::<R>));
+        }
+        inner.pending_fields |= fields;
+        &mut inner.resource
+    }
+
+    /// Mutably access the resource and trigger reactions that can read `tag` with [`ResourceMutationSource`].
+    ///
+    /// Each call schedules its own reactions.
+    pub fn get_mut_tagged(&mut self, rc: &mut ReactCommands, tag: ChangeTag) -> &mut R
+    {
+        rc.commands.syscall(tag, ReactCache::schedule_tagged_resource_mutation_reaction::<R>);
+        self.inner.get_noreact()
     }
 
     /// Mutably access the resource without triggering reactions.
@@ -145,6 +253,18 @@ impl<'w, R: ReactResource> ReactResMut<'w, R>
     {
         (*self.inner).set_if_neq(c, new)
     }
+
+    /// Mutably accesses the resource with a callback, and triggers mutation reactions only if the callback returns
+    /// `true`.
+    ///
+    /// The callback should return `true` if it made a meaningful change. This is a generalization of
+    /// [`Self::set_if_neq`] that is useful for collection-like resources where comparing the whole value is expensive.
+    ///
+    /// Returns the callback's return value.
+    pub fn modify(&mut self, c: &mut Commands, f: impl FnOnce(&mut R) -> bool) -> bool
+    {
+        self.inner.modify(c, f)
+    }
 }
 
 impl<'w, R: ReactResource> DetectChanges for ReactResMut<'w, R>
@@ -166,6 +286,172 @@ impl<'w, R: ReactResource> Deref for ReactResMut<'w, R>
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Mutable access to a [`ReactResource`] obtained from [`ReactResMut::get_mut`].
+///
+/// Reading through the guard does nothing. The first time the guard is mutably dereferenced, resource mutation
+/// reactions are scheduled and the resource is marked changed. This way a caller that only reads through a mutable
+/// handle won't trigger reactions.
+pub struct ReactResMutGuard<'a, 'w, 's, R: ReactResource>
+{
+    commands : &'a mut Commands<'w, 's>,
+    resource : Mut<'a, R>,
+    mutated  : bool,
+}
+
+impl<'a, 'w, 's, R: ReactResource> Deref for ReactResMutGuard<'a, 'w, 's, R>
+{
+    type Target = R;
+
+    fn deref(&self) -> &R
+    {
+        &self.resource
+    }
+}
+
+impl<'a, 'w, 's, R: ReactResource> DerefMut for ReactResMutGuard<'a, 'w, 's, R>
+{
+    fn deref_mut(&mut self) -> &mut R
+    {
+        if !self.mutated
+        {
+            self.mutated = true;
+            self.commands.react().trigger_resource_mutation::<R>();
+        }
+        &mut self.resource
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Scoped mutable access to a [`ReactResource`] obtained from [`ReactResMut::begin_change`].
+///
+/// Unlike [`ReactResMutGuard`], reactions are not scheduled until the guard is dropped. If the guard was mutably
+/// dereferenced at least once, exactly one resource mutation reaction is scheduled on drop, no matter how many
+/// changes were made.
+///
+/// If the guard is leaked (e.g. with [`std::mem::forget`]), no reaction will be scheduled. The resource will still
+/// be marked changed for Bevy change detection.
+pub struct ReactResChangeGuard<'a, 'w, 's, R: ReactResource>
+{
+    rc       : &'a mut ReactCommands<'w, 's>,
+    resource : Mut<'a, R>,
+    mutated  : bool,
+}
+
+impl<'a, 'w, 's, R: ReactResource> Deref for ReactResChangeGuard<'a, 'w, 's, R>
+{
+    type Target = R;
+
+    fn deref(&self) -> &R
+    {
+        &self.resource
+    }
+}
+
+impl<'a, 'w, 's, R: ReactResource> DerefMut for ReactResChangeGuard<'a, 'w, 's, R>
+{
+    fn deref_mut(&mut self) -> &mut R
+    {
+        self.mutated = true;
+        &mut self.resource
+    }
+}
+
+impl<'a, 'w, 's, R: ReactResource> Drop for ReactResChangeGuard<'a, 'w, 's, R>
+{
+    fn drop(&mut self)
+    {
+        if !self.mutated { return; }
+        self.rc.trigger_resource_mutation::<R>();
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Serializes the [`ReactResource`] `R`.
+///
+/// The format is JSON of `R`. Apply the bytes on another world with [`ReactCommands::apply_serialized_resource`].
+///
+/// Returns an error if the resource doesn't exist or if serialization fails.
+#[cfg(feature = "serde")]
+pub fn serialize_react_resource<R: ReactResource + serde::Serialize>(world: &World) -> Result<Vec<u8>, CobwebReactError>
+{
+    let resource = world.get_react_resource::<R>().ok_or(CobwebReactError::ReactRes(std::any::type_name::<R>()))?;
+    serde_json::to_vec(resource).map_err(|_| CobwebReactError::Serialize(std::any::type_name::<R>()))
+}
+
+/// Sets the [`ReactResource`] `R` to `value` with reactions, or inserts it if missing.
+#[cfg(feature = "serde")]
+pub(crate) fn apply_react_resource_value<R: ReactResource>(In(value): In<R>, world: &mut World)
+{
+    match world.get_resource_mut::<ReactResInner<R>>()
+    {
+        Some(mut inner) =>
+        {
+            inner.resource = value;
+            world.trigger_resource_mutation::<R>();
+        }
+        None => world.insert_react_resource(value),
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Reflection type data for [`ReactResource`] types, for inspecting and editing reactive resources (e.g. in an editor)
+/// with mutation reactions.
+///
+/// Register it with `#[reflect(ReactResource)]` on a type that derives `Reflect` and [`ReactResource`], then look it
+/// up in the `AppTypeRegistry` with `registry.get_type_data::<ReflectReactResource>(type_id)`.
+#[cfg(feature = "reflect")]
+#[derive(Clone)]
+pub struct ReflectReactResource
+{
+    reflect : fn(&World) -> Option<&dyn Reflect>,
+    apply   : fn(&mut World, &dyn PartialReflect) -> bool,
+}
+
+#[cfg(feature = "reflect")]
+impl ReflectReactResource
+{
+    /// Gets the reflected resource.
+    pub fn reflect<'w>(&self, world: &'w World) -> Option<&'w dyn Reflect>
+    {
+        (self.reflect)(world)
+    }
+
+    /// Applies a reflected value to the resource and triggers mutation reactions.
+    ///
+    /// Returns `false` if the resource doesn't exist or if the value could not be applied. Reactions have run when
+    /// this returns.
+    pub fn apply(&self, world: &mut World, value: &dyn PartialReflect) -> bool
+    {
+        (self.apply)(world, value)
+    }
+}
+
+#[cfg(feature = "reflect")]
+impl<R: ReactResource + Reflect> bevy::reflect::FromType<R> for ReflectReactResource
+{
+    fn from_type() -> Self
+    {
+        Self{
+            reflect: |world|
+            {
+                world.get_react_resource::<R>().map(|resource| resource as &dyn Reflect)
+            },
+            apply: |world, value|
+            {
+                let Some(resource) = world.get_react_resource_noreact::<R>() else { return false; };
+                if resource.try_apply(value).is_err() { return false; }
+                world.trigger_resource_mutation::<R>();
+                true
+            },
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// Extends the `World` API with reactive resource methods.
 ///
 /// Does NOT include `react_resource_mut()` because reactions need to be queued to run *after* a resource is mutated,
@@ -190,7 +476,10 @@ pub trait ReactResWorldExt
         &mut self,
         func: impl FnOnce() -> R,
     ) -> &R;
-    /// Panics if the resource doesn't exist.
+    /// Triggers resource mutation reactions.
+    ///
+    /// Unlike [`ReactCommands::trigger_resource_mutation`], this is synchronous: all reactions have run when it
+    /// returns.
     fn trigger_resource_mutation<R: ReactResource>(&mut self);
 }
 
@@ -206,6 +495,7 @@ impl ReactResWorldExt for World
     fn insert_react_resource<R: ReactResource>(&mut self, value: R)
     {
         self.insert_resource(ReactResInner::new(value));
+        replay_ready_reactors::<R>(self);
     }
 
     fn remove_react_resource<R: ReactResource>(&mut self) -> Option<R>
@@ -253,7 +543,11 @@ impl ReactResWorldExt for World
         func: impl FnOnce() -> R,
     ) -> &R
     {
-        self.get_resource_or_insert_with(move || ReactResInner::new((func)())).into_inner()
+        if !self.contains_react_resource::<R>()
+        {
+            self.insert_react_resource((func)());
+        }
+        self.react_resource::<R>()
     }
 
     fn trigger_resource_mutation<R: ReactResource>(&mut self)
@@ -269,6 +563,16 @@ pub trait ReactResAppExt
     /// Does nothing if the resource already exists.
     fn init_react_resource<R: ReactResource + FromWorld>(&mut self) -> &mut Self;
     fn insert_react_resource<R: ReactResource>(&mut self, value: R) -> &mut Self;
+    /// Inserts a react resource and adds a persistent reactor for [`resource_mutation::<R>()`](resource_mutation).
+    ///
+    /// Resource mutation reactions are triggered once in `Startup`, so state that depends on the resource is
+    /// initialized without a manual [`ReactCommands::trigger_resource_mutation`]. All reactors for
+    /// `resource_mutation::<R>()` will run at that time, not only `reactor`.
+    fn init_react_resource_with_reactor<R: ReactResource, M, O: CobwebResult>(
+        &mut self,
+        value   : R,
+        reactor : impl IntoSystem<(), O, M> + Send + Sync + 'static
+    ) -> &mut Self;
 }
 
 impl ReactResAppExt for App
@@ -284,6 +588,17 @@ impl ReactResAppExt for App
         self.world_mut().insert_react_resource(value);
         self
     }
+
+    fn init_react_resource_with_reactor<R: ReactResource, M, O: CobwebResult>(
+        &mut self,
+        value   : R,
+        reactor : impl IntoSystem<(), O, M> + Send + Sync + 'static
+    ) -> &mut Self
+    {
+        self.insert_react_resource(value)
+            .add_reactor(resource_mutation::<R>(), reactor)
+            .add_systems(Startup, |mut c: Commands| c.react().trigger_resource_mutation::<R>())
+    }
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -305,7 +620,7 @@ impl<'w, 's> ReactResCommandsExt for Commands<'w, 's>
 
     fn insert_react_resource<R: ReactResource>(&mut self, value: R)
     {
-        self.insert_resource(ReactResInner::new(value));
+        self.queue(move |world: &mut World| world.insert_react_resource(value));
     }
 
     fn remove_react_resource<R: ReactResource>(&mut self)
@@ -0,0 +1,189 @@
+//local shortcuts
+use crate::prelude::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+
+//standard shortcuts
+use std::collections::VecDeque;
+use std::fmt::Write;
+use std::time::Duration;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Records a system command that is starting, if the [`ReactionLog`] is enabled.
+pub(crate) fn record_reaction(world: &mut World, reactor: SystemCommand, depth: usize, trigger: ReactionTriggerInfo)
+{
+    if !world.contains_resource::<ReactionLog>() { return; }
+    let timestamp = world.resource::<Time>().elapsed();
+    let name = world.get::<Name>(*reactor).map(|name| name.as_str().to_owned());
+    world.resource_mut::<ReactionLog>().push(reactor, name, depth, timestamp, trigger);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Marks the end of the current tree of system commands, if the [`ReactionLog`] is enabled.
+pub(crate) fn record_tree_end(world: &mut World)
+{
+    let Some(mut log) = world.get_resource_mut::<ReactionLog>() else { return; };
+    log.in_tree = false;
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// An entry in the [`ReactionLog`].
+///
+/// Each entry is one run of a [`SystemCommand`] (usually a reactor running in response to a reaction).
+#[derive(Debug, Clone)]
+pub struct ReactionLogEntry
+{
+    /// Id of the tree of system commands the run was part of.
+    ///
+    /// Trees are numbered in the order they started, starting at 1. Entries with the same tree id form one cascade.
+    pub tree: u64,
+    /// Depth of the run within its tree.
+    ///
+    /// The root system command of a tree has depth 0, and system commands that run while another system command is
+    /// running (e.g. reactions to its commands) have the depth of the outer command plus 1.
+    pub depth: usize,
+    /// The system command that ran.
+    pub reactor: SystemCommand,
+    /// The [`Name`] of the system command entity, if it has one.
+    pub name: Option<String>,
+    /// [`Time::elapsed`] when the system command started running.
+    pub timestamp: Duration,
+    /// The trigger that scheduled the run.
+    trigger: ReactionTriggerInfo,
+}
+
+impl ReactionLogEntry
+{
+    /// Returns the trigger that scheduled the run.
+    ///
+    /// Returns `None` if the run was not a reaction (e.g. a system command or system event run directly).
+    pub fn trigger(&self) -> Option<ReactorType>
+    {
+        self.trigger.reactor_type()
+    }
+
+    /// Returns a description of the trigger that scheduled the run.
+    pub fn trigger_description(&self) -> String
+    {
+        self.trigger.to_string()
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Ring buffer of recent system command runs, for postmortem debugging of reaction cascades.
+///
+/// Only exists if enabled with [`ReactPlugin::with_reaction_log`]. When the buffer is full, the oldest entries are
+/// dropped.
+///
+/// Entries are stored in the order the system commands started running. The shape of a cascade can be
+/// reconstructed from the [`tree`](ReactionLogEntry::tree) and [`depth`](ReactionLogEntry::depth) of each entry:
+/// an entry's parent is the closest earlier entry in the same tree with a smaller depth. See [`Self::dump`].
+#[derive(Resource, Debug)]
+pub struct ReactionLog
+{
+    entries: VecDeque<ReactionLogEntry>,
+    capacity: usize,
+    /// Id of the most recent tree.
+    tree: u64,
+    /// Whether the most recent tree is still running.
+    in_tree: bool,
+}
+
+impl ReactionLog
+{
+    /// Makes a new log that stores at most `capacity` entries (minimum 1).
+    pub fn new(capacity: usize) -> Self
+    {
+        let capacity = capacity.max(1);
+        Self{ entries: VecDeque::with_capacity(capacity), capacity, tree: 0, in_tree: false }
+    }
+
+    fn push(
+        &mut self,
+        reactor   : SystemCommand,
+        name      : Option<String>,
+        depth     : usize,
+        timestamp : Duration,
+        trigger   : ReactionTriggerInfo
+    ){
+        if !self.in_tree
+        {
+            self.tree += 1;
+            self.in_tree = true;
+        }
+        if self.entries.len() >= self.capacity
+        {
+            let _ = self.entries.pop_front();
+        }
+        self.entries.push_back(ReactionLogEntry{ tree: self.tree, depth, reactor, name, timestamp, trigger });
+    }
+
+    /// Iterates the log entries from oldest to newest.
+    pub fn entries(&self) -> impl Iterator<Item = &ReactionLogEntry> + '_
+    {
+        self.entries.iter()
+    }
+
+    /// Returns the number of entries in the log.
+    pub fn len(&self) -> usize
+    {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the log has no entries.
+    pub fn is_empty(&self) -> bool
+    {
+        self.entries.is_empty()
+    }
+
+    /// Returns the maximum number of entries in the log.
+    pub fn capacity(&self) -> usize
+    {
+        self.capacity
+    }
+
+    /// Removes all entries.
+    ///
+    /// Tree ids keep counting up, so entries added later can't be confused with removed entries.
+    pub fn clear(&mut self)
+    {
+        self.entries.clear();
+    }
+
+    /// Prints the log as text, with one line per entry indented by depth under a header for each tree.
+    ///
+    /*
+    ```text
+    tree 1
+      [1.250s] 5v1 (trigger: broadcast u32)
+        [1.250s] 7v1 "ui_update" (trigger: mutation of my_crate::Health on 3v1)
+    ```
+    */
+    pub fn dump(&self) -> String
+    {
+        let mut dump = String::new();
+        let mut tree = None;
+
+        for entry in self.entries.iter()
+        {
+            if tree != Some(entry.tree)
+            {
+                tree = Some(entry.tree);
+                let _ = writeln!(dump, "tree {}", entry.tree);
+            }
+            let indent = "  ".repeat(entry.depth + 1);
+            let _ = write!(dump, "{indent}[{:.3}s] {}", entry.timestamp.as_secs_f64(), *entry.reactor);
+            if let Some(name) = &entry.name { let _ = write!(dump, " {name:?}"); }
+            let _ = writeln!(dump, " (trigger: {})", entry.trigger);
+        }
+
+        dump
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -64,9 +64,15 @@ fn register_removal_reactor<C: ReactComponent>(In(handle): In<ReactorHandle>, mu
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
 
-fn register_any_entity_event_reactor<E: 'static>(In(handle): In<ReactorHandle>, mut cache: ResMut<ReactCache>)
-{
+fn register_any_entity_event_reactor<E: 'static>(
+    In(handle)   : In<ReactorHandle>,
+    mut commands : Commands,
+    mut cache    : ResMut<ReactCache>,
+    mut counters : Query<&mut DataEntityCounter>,
+){
+    let reactor = handle.sys_command();
     cache.register_any_entity_event_reactor::<E>(handle);
+    cache.schedule_retained_entity_event_reactions(None, TypeId::of::<E>(), reactor, &mut commands, &mut counters);
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -80,9 +86,35 @@ fn register_resource_mutation_reactor<R: ReactResource>(In(handle): In<ReactorHa
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
 
-fn register_broadcast_reactor<E: Send + Sync + 'static>(In(handle): In<ReactorHandle>, mut cache: ResMut<ReactCache>)
+fn register_bevy_resource_reactor<R: Resource>(In(handle): In<ReactorHandle>, world: &mut World)
 {
-    cache.register_broadcast_reactor::<E>(handle);
+    world.resource_scope(
+        move |world, mut cache: Mut<ReactCache>|
+        {
+            cache.watch_resource_changes::<R>(world);
+            cache.register_bevy_resource_reactor::<R>(handle);
+        }
+    );
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn register_broadcast_reactor<E: Send + Sync + 'static>(
+    In((channel, handle)) : In<(ChannelId, ReactorHandle)>,
+    mut cache             : ResMut<ReactCache>
+){
+    cache.register_broadcast_reactor::<E>(channel, handle);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn register_topic_reactor<T: BroadcastTopic>(
+    In(handle) : In<ReactorHandle>,
+    mut cache  : ResMut<ReactCache>
+){
+    cache.register_topic_reactor::<T>(handle);
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -99,6 +131,8 @@ fn register_despawn_reactor(
             let Ok(mut entity_mut) = world.get_entity_mut(entity) else { return; };
 
             // Register the reactor.
+            // - Duplicate registrations are ignored so repeated despawn hooks only fire once.
+            if cache.has_despawn_reactor(entity, handle.sys_command()) { return; }
             cache.register_despawn_reactor(entity, handle);
 
             // Leave if the entity already has a despawn tracker.
@@ -114,22 +148,87 @@ fn register_despawn_reactor(
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Returns `true` if an asset is loaded (including its dependencies).
+///
+/// Assets that weren't loaded by the asset server are considered loaded if they are in [`Assets<A>`].
+#[cfg(feature = "bevy_asset")]
+fn asset_is_loaded<A: Asset>(world: &World, id: AssetId<A>) -> bool
+{
+    if let Some(server) = world.get_resource::<AssetServer>()
+    {
+        if server.get_load_state(id).is_some() { return server.is_loaded_with_dependencies(id); }
+    }
+
+    world.get_resource::<Assets<A>>().is_some_and(|assets| assets.contains(id))
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "bevy_asset")]
+fn register_asset_loaded_reactor<A: Asset>(
+    In((id, handle)) : In<(AssetId<A>, ReactorHandle)>,
+    world            : &mut World,
+){
+    let reactor = handle.sys_command();
+    world.resource_scope(
+        move |world, mut cache: Mut<ReactCache>|
+        {
+            cache.watch_asset_loads::<A>(world);
+            cache.register_asset_loaded_reactor(id, handle);
+        }
+    );
+
+    // React immediately if the asset is already loaded.
+    if !asset_is_loaded(world, id) { return; }
+    world.syscall((id, Some(reactor)), ReactCache::schedule_asset_loaded_reaction::<A>);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Broadcast event sent when an entity gets its first entity-specific reactor.
+///
+/// Listen for it with [`broadcast::<ReactorsAttached>()`](broadcast). It is sent once per entity, when the first
+/// reactor with a trigger targeting the entity (e.g. [`entity_mutation`], [`entity_event`], but not [`despawn`]) is
+/// registered. Later reactors on the same entity do not send it, even if earlier reactors were revoked.
+///
+/// The event is queued as a normal broadcast, so its reactors run after the registration finishes. Reactors can
+/// safely attach reactors to other entities, which will send new `ReactorsAttached` events for those entities.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ReactorsAttached(pub Entity);
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// Adds a reactor to an entity.
 ///
 /// The reactor will be invoked when the trigger targets the entity.
-fn register_entity_reactor(
+pub(crate) fn register_entity_reactor(
     In((
         rtype,
         entity,
         handle
     ))                  : In<(EntityReactionType, Entity, ReactorHandle)>,
     mut commands        : Commands,
+    mut cache           : ResMut<ReactCache>,
     mut entity_reactors : Query<&mut EntityReactors>,
+    mut counters        : Query<&mut DataEntityCounter>,
 ){
+    // catch up on retained events
+    if let EntityReactionType::Event(event) = rtype
+    {
+        let reactor = handle.sys_command();
+        cache.schedule_retained_entity_event_reactions(Some(entity), event, reactor, &mut commands, &mut counters);
+    }
+
     // add callback to entity
     match entity_reactors.get_mut(entity)
     {
-        Ok(mut entity_reactors) => entity_reactors.insert(rtype, handle),
+        Ok(mut entity_reactors) =>
+        {
+            // new components are indexed by the component's insertion hook
+            cache.index_entity_reactor(entity, handle.sys_command());
+            entity_reactors.insert(rtype, handle);
+        }
         _ =>
         {
             let Some(mut entity_commands) = commands.get_entity(entity) else { return; };
@@ -140,6 +239,9 @@ fn register_entity_reactor(
             // add callback and insert to entity
             entity_reactors.insert(rtype, handle);
             entity_commands.insert(entity_reactors);
+
+            // notify listeners that the entity is now reactive
+            cache.queue_broadcast_reactors(&mut commands, ChannelId::DEFAULT, ReactorsAttached(entity), ());
         }
     }
 }
@@ -197,6 +299,36 @@ pub fn mutation<C: ReactComponent>() -> MutationTrigger<C> { MutationTrigger::de
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Reaction trigger for [`ReactComponent`] insertions and mutations on any entity.
+/// - For reactors that take the entity where the component's value changed. Read the entity with [`ChangeEvent`].
+/// - Equivalent to `(insertion::<C>(), mutation::<C>())`, but a single trigger.
+pub struct ChangeTrigger<C: ReactComponent>(PhantomData<C>);
+impl<C: ReactComponent> Default for ChangeTrigger<C> { fn default() -> Self { Self(PhantomData::default()) } }
+impl<C: ReactComponent> Clone for ChangeTrigger<C> { fn clone(&self) -> Self { *self } }
+impl<C: ReactComponent> Copy for ChangeTrigger<C> {}
+
+impl<C: ReactComponent> ReactionTriggerBundle for ChangeTrigger<C>
+{
+    fn len(&self) -> usize { 2 }
+
+    fn collect_reactor_types(self, func: &mut impl FnMut(ReactorType))
+    {
+        insertion::<C>().collect_reactor_types(func);
+        mutation::<C>().collect_reactor_types(func);
+    }
+
+    fn register_triggers(self, commands: &mut Commands, handle: &ReactorHandle)
+    {
+        insertion::<C>().register_triggers(commands, handle);
+        mutation::<C>().register_triggers(commands, handle);
+    }
+}
+
+/// Returns a [`ChangeTrigger`] reaction trigger.
+pub fn change<C: ReactComponent>() -> ChangeTrigger<C> { ChangeTrigger::default() }
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// Reaction trigger for [`ReactComponent`] removals from any entity.
 /// - Reactions are not triggered if the entity was despawned.
 pub struct RemovalTrigger<C: ReactComponent>(PhantomData<C>);
@@ -304,6 +436,47 @@ pub fn entity_mutation<C: ReactComponent>(entity: Entity) -> EntityMutationTrigg
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Reaction trigger for [`ReactComponent`] insertions and mutations on a specific entity.
+/// - Read the entity with [`ChangeEvent`].
+/// - Equivalent to `(entity_insertion::<C>(entity), entity_mutation::<C>(entity))`, but a single trigger.
+/// - Registration does nothing if the entity does not exist.
+pub struct EntityChangeTrigger<C: ReactComponent>(Entity, PhantomData<C>);
+impl<C: ReactComponent> Clone for EntityChangeTrigger<C> { fn clone(&self) -> Self { *self } }
+impl<C: ReactComponent> Copy for EntityChangeTrigger<C> {}
+
+impl<C: ReactComponent> ReactionTriggerBundle for EntityChangeTrigger<C>
+{
+    fn len(&self) -> usize { 2 }
+
+    fn collect_reactor_types(self, func: &mut impl FnMut(ReactorType))
+    {
+        entity_insertion::<C>(self.0).collect_reactor_types(func);
+        entity_mutation::<C>(self.0).collect_reactor_types(func);
+    }
+
+    fn register_triggers(self, commands: &mut Commands, handle: &ReactorHandle)
+    {
+        entity_insertion::<C>(self.0).register_triggers(commands, handle);
+        entity_mutation::<C>(self.0).register_triggers(commands, handle);
+    }
+}
+
+impl<C: ReactComponent> EntityTriggerBundle for EntityChangeTrigger<C>
+{
+    fn new_bundle(entity: Entity) -> Self
+    {
+        entity_change(entity)
+    }
+}
+
+/// Returns a [`EntityChangeTrigger`] reaction trigger.
+pub fn entity_change<C: ReactComponent>(entity: Entity) -> EntityChangeTrigger<C>
+{
+    EntityChangeTrigger(entity, PhantomData::default())
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// Reaction trigger for [`ReactComponent`] removals from a specific entity.
 /// - Registration does nothing if the entity does not exist.
 pub struct EntityRemovalTrigger<C: ReactComponent>(Entity, PhantomData<C>);
@@ -346,6 +519,31 @@ pub fn entity_removal<C: ReactComponent>(entity: Entity) -> EntityRemovalTrigger
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Reaction triggers for the full lifecycle of a [`ReactComponent`] on a specific entity.
+///
+/// See [`entity_component`].
+pub type EntityComponentTriggers<C> = (
+    EntityInsertionTrigger<C>,
+    EntityMutationTrigger<C>,
+    EntityRemovalTrigger<C>,
+    DespawnTrigger,
+);
+
+/// Returns [`EntityComponentTriggers`] for insertions, mutations, and removals of `C` on an entity, and for the
+/// entity's despawn.
+///
+/// Reactors can check which lifecycle event occurred with the [`InsertionEvent`], [`MutationEvent`],
+/// [`RemovalEvent`], and [`DespawnEvent`] system parameters (only the one matching the event will be non-empty).
+///
+/// Since this is one trigger bundle, a single [`RevokeToken`] from [`ReactCommands::on_revokable`] revokes all
+/// four triggers.
+pub fn entity_component<C: ReactComponent>(entity: Entity) -> EntityComponentTriggers<C>
+{
+    (entity_insertion::<C>(entity), entity_mutation::<C>(entity), entity_removal::<C>(entity), despawn(entity))
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// Reaction trigger for entity events.
 /// - Reactions only occur for events sent via [`ReactCommands::<E>::entity_event()`].
 pub struct EntityEventTrigger<E: Send + Sync + 'static>(Entity, PhantomData<E>);
@@ -387,6 +585,50 @@ pub fn entity_event<E: Send + Sync + 'static>(target: Entity) -> EntityEventTrig
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Entity event sent to an entity when its [`Parent`] changes.
+///
+/// See [`entity_parent_changed`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ParentChanged
+{
+    /// The previous parent, if the entity had one.
+    pub old: Option<Entity>,
+    /// The new parent, if the entity still has one.
+    pub new: Option<Entity>,
+}
+
+/// Entity event sent to an entity when a child is added to or removed from its [`Children`].
+///
+/// See [`entity_children_changed`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ChildrenChanged
+{
+    Added(Entity),
+    Removed(Entity),
+}
+
+/// Returns an [`EntityEventTrigger`] reaction trigger for [`ParentChanged`] events on an entity.
+/// - Reactions occur when the entity gains, loses, or switches its [`Parent`]. Use `EntityEvent<ParentChanged>` to
+///   read the old and new parent.
+/// - Hierarchy changes are read from Bevy's `HierarchyEvent`s by the [`ReactPlugin`] in `Last`, so reactions occur at
+///   most once per frame for each change. Events are only sent if a reactor is listening for them.
+/// - Changes made without Bevy's hierarchy commands (e.g. by inserting [`Parent`] directly) are not detected.
+pub fn entity_parent_changed(entity: Entity) -> EntityEventTrigger<ParentChanged>
+{
+    entity_event(entity)
+}
+
+/// Returns an [`EntityEventTrigger`] reaction trigger for [`ChildrenChanged`] events on an entity.
+/// - Reactions occur once for each child added to or removed from the entity (including when a child is moved to a
+///   different parent). Use `EntityEvent<ChildrenChanged>` to read the change.
+/// - See [`entity_parent_changed`] for how hierarchy changes are detected.
+pub fn entity_children_changed(entity: Entity) -> EntityEventTrigger<ChildrenChanged>
+{
+    entity_event(entity)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// Reaction trigger for any entity event of a given type.
 /// - Reactions only occur for events sent via [`ReactCommands::<E>::entity_event()`].
 pub struct AnyEntityEventTrigger<E: Send + Sync + 'static>(PhantomData<E>);
@@ -438,6 +680,188 @@ pub fn resource_mutation<R: ReactResource>() -> ResourceMutationTrigger<R> { Res
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Marker type that identifies [`resource_value_changed`] reactors in [`ReactorType::ResourceMutation`].
+pub(crate) struct ResourceValueChanged<R>(PhantomData<R>);
+
+/// Reaction trigger for [`ReactResource`] mutations that change the resource's value.
+/// - The resource is compared with a snapshot of its previous value each time mutation reactions are scheduled for
+///   it (after the mutating access is done), and reactions only run if the value differs. This costs a clone and
+///   comparison per mutation while there are `resource_value_changed` reactors for the resource.
+/// - The snapshot is taken when the first reactor for the resource is registered.
+/// - The trigger's [`ReactorType`] is [`ReactorType::ResourceMutation`] with the id of an internal marker type, so
+///   reactors can't read [`ResourceMutationEvent`] metadata.
+pub struct ResourceValueChangedTrigger<R: ReactResource + PartialEq + Clone>(PhantomData<R>);
+impl<R: ReactResource + PartialEq + Clone> Default for ResourceValueChangedTrigger<R>
+{
+    fn default() -> Self { Self(PhantomData) }
+}
+impl<R: ReactResource + PartialEq + Clone> Clone for ResourceValueChangedTrigger<R>
+{
+    fn clone(&self) -> Self { *self }
+}
+impl<R: ReactResource + PartialEq + Clone> Copy for ResourceValueChangedTrigger<R> {}
+
+impl<R: ReactResource + PartialEq + Clone> ReactionTrigger for ResourceValueChangedTrigger<R>
+{
+    fn reactor_type(&self) -> ReactorType
+    {
+        ReactorType::ResourceMutation(TypeId::of::<ResourceValueChanged<R>>())
+    }
+
+    fn register(&self, commands: &mut Commands, handle: &ReactorHandle)
+    {
+        commands.syscall(handle.clone(), register_resource_value_changed_reactor::<R>);
+    }
+}
+
+/// Returns a [`ResourceValueChangedTrigger`] reaction trigger.
+pub fn resource_value_changed<R: ReactResource + PartialEq + Clone>() -> ResourceValueChangedTrigger<R>
+{
+    ResourceValueChangedTrigger::default()
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Reaction trigger for changes to plain Bevy [`Resource`]s.
+/// - Changes are detected with Bevy change detection (i.e. `Res<R>::is_changed()`), including when the resource is
+///   inserted. Changes made before the first reactor for `R` was registered are ignored.
+/// - Reactions occur at most once per frame, in `Last` before removal and despawn reactions.
+///
+/// Registering the first reactor for a resource type adds a change watcher for that type to the [`ReactPlugin`]'s
+/// resource system. The watcher's change ticks persist between frames, so a change made at any point in a frame is
+/// detected at the next check.
+pub struct BevyResourceChangedTrigger<R: Resource>(PhantomData<R>);
+impl<R: Resource> Default for BevyResourceChangedTrigger<R> { fn default() -> Self { Self(PhantomData) } }
+impl<R: Resource> Clone for BevyResourceChangedTrigger<R> { fn clone(&self) -> Self { *self } }
+impl<R: Resource> Copy for BevyResourceChangedTrigger<R> {}
+
+impl<R: Resource> ReactionTrigger for BevyResourceChangedTrigger<R>
+{
+    fn reactor_type(&self) -> ReactorType
+    {
+        ReactorType::BevyResourceChanged(TypeId::of::<R>())
+    }
+
+    fn register(&self, commands: &mut Commands, handle: &ReactorHandle)
+    {
+        commands.syscall(handle.clone(), register_bevy_resource_reactor::<R>);
+    }
+}
+
+/// Returns a [`BevyResourceChangedTrigger`] reaction trigger.
+pub fn bevy_resource_changed<R: Resource>() -> BevyResourceChangedTrigger<R> { BevyResourceChangedTrigger::default() }
+
+/// Reaction trigger for additions of plain Bevy [`Component`]s (e.g. third-party components that aren't
+/// [`ReactComponent`]s).
+/// - Additions are detected with Bevy change detection (i.e. `Added<C>`). Additions made before the first reactor for
+///   `C` was registered are ignored.
+/// - Reactions occur at most once per entity per frame, in `Last` before removal and despawn reactions.
+/// - Read the entity with [`BevyComponentEvent`].
+///
+/// Registering the first reactor adds a watcher for `C` to the [`ReactPlugin`]'s resource change system, which
+/// broadcasts an internal event for each entity where `C` was added.
+pub struct BevyComponentAddedTrigger<C: Component>(PhantomData<C>);
+impl<C: Component> Default for BevyComponentAddedTrigger<C> { fn default() -> Self { Self(PhantomData) } }
+impl<C: Component> Clone for BevyComponentAddedTrigger<C> { fn clone(&self) -> Self { *self } }
+impl<C: Component> Copy for BevyComponentAddedTrigger<C> {}
+
+impl<C: Component> ReactionTrigger for BevyComponentAddedTrigger<C>
+{
+    fn reactor_type(&self) -> ReactorType
+    {
+        ReactorType::Broadcast(TypeId::of::<BevyComponentAdded<C>>())
+    }
+
+    fn register(&self, commands: &mut Commands, handle: &ReactorHandle)
+    {
+        commands.syscall((),
+            |world: &mut World|
+            {
+                world.resource_scope(|world, mut cache: Mut<ReactCache>| cache.watch_component_additions::<C>(world));
+            }
+        );
+        commands.syscall((ChannelId::DEFAULT, handle.clone()), register_broadcast_reactor::<BevyComponentAdded<C>>);
+    }
+}
+
+/// Returns a [`BevyComponentAddedTrigger`] reaction trigger.
+pub fn bevy_component_added<C: Component>() -> BevyComponentAddedTrigger<C> { BevyComponentAddedTrigger::default() }
+
+/// Reaction trigger for changes to plain Bevy [`Component`]s (e.g. third-party components that aren't
+/// [`ReactComponent`]s).
+/// - Changes are detected with Bevy change detection (i.e. `Changed<C>`), including when the component is added.
+///   Changes made before the first reactor for `C` was registered are ignored.
+/// - Reactions occur at most once per entity per frame, in `Last` before removal and despawn reactions.
+/// - Read the entity with [`BevyComponentEvent`].
+///
+/// Registering the first reactor adds a watcher for `C` to the [`ReactPlugin`]'s resource change system, which
+/// broadcasts an internal event for each entity where `C` changed.
+pub struct BevyComponentChangedTrigger<C: Component>(PhantomData<C>);
+impl<C: Component> Default for BevyComponentChangedTrigger<C> { fn default() -> Self { Self(PhantomData) } }
+impl<C: Component> Clone for BevyComponentChangedTrigger<C> { fn clone(&self) -> Self { *self } }
+impl<C: Component> Copy for BevyComponentChangedTrigger<C> {}
+
+impl<C: Component> ReactionTrigger for BevyComponentChangedTrigger<C>
+{
+    fn reactor_type(&self) -> ReactorType
+    {
+        ReactorType::Broadcast(TypeId::of::<BevyComponentChanged<C>>())
+    }
+
+    fn register(&self, commands: &mut Commands, handle: &ReactorHandle)
+    {
+        commands.syscall((),
+            |world: &mut World|
+            {
+                world.resource_scope(|world, mut cache: Mut<ReactCache>| cache.watch_component_changes::<C>(world));
+            }
+        );
+        commands.syscall((ChannelId::DEFAULT, handle.clone()), register_broadcast_reactor::<BevyComponentChanged<C>>);
+    }
+}
+
+/// Returns a [`BevyComponentChangedTrigger`] reaction trigger.
+pub fn bevy_component_changed<C: Component>() -> BevyComponentChangedTrigger<C>
+{
+    BevyComponentChangedTrigger::default()
+}
+
+/// Reaction trigger for batched [`ReactComponent`] removals with the removed values.
+/// - Reactions occur at most once per removal pass (e.g. in `Last`), after other removal and despawn reactions, for all
+///   entities that lost `React<C>` since the previous pass.
+/// - Read the removals with [`BatchedRemovalEvent`].
+pub struct RemovalBatchedWithValuesTrigger<C: ReactComponent + Clone>(PhantomData<C>);
+impl<C: ReactComponent + Clone> Default for RemovalBatchedWithValuesTrigger<C>
+{
+    fn default() -> Self { Self(PhantomData) }
+}
+impl<C: ReactComponent + Clone> Clone for RemovalBatchedWithValuesTrigger<C> { fn clone(&self) -> Self { *self } }
+impl<C: ReactComponent + Clone> Copy for RemovalBatchedWithValuesTrigger<C> {}
+
+impl<C: ReactComponent + Clone> ReactionTrigger for RemovalBatchedWithValuesTrigger<C>
+{
+    fn reactor_type(&self) -> ReactorType
+    {
+        ReactorType::Broadcast(TypeId::of::<BatchedRemovals<C>>())
+    }
+
+    fn register(&self, commands: &mut Commands, handle: &ReactorHandle)
+    {
+        commands.syscall((), |mut captures: ResMut<RemovalValueCaptures>| captures.track::<C>());
+        commands.syscall((ChannelId::DEFAULT, handle.clone()), register_broadcast_reactor::<BatchedRemovals<C>>);
+    }
+}
+
+/// Returns a [`RemovalBatchedWithValuesTrigger`] reaction trigger.
+///
+/// Removed values are cloned when `React<C>` is removed (including on despawn), so `C` must implement `Clone`.
+pub fn removal_batched_with_values<C: ReactComponent + Clone>() -> RemovalBatchedWithValuesTrigger<C>
+{
+    RemovalBatchedWithValuesTrigger::default()
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// Reaction trigger for broadcast events.
 /// - Reactions only occur for events sent via [`ReactCommands::<E>::broadcast()`].
 pub struct BroadcastTrigger<E: Send + Sync + 'static>(PhantomData<E>);
@@ -454,7 +878,7 @@ impl<E: Send + Sync + 'static> ReactionTrigger for BroadcastTrigger<E>
 
     fn register(&self, commands: &mut Commands, handle: &ReactorHandle)
     {
-        commands.syscall(handle.clone(), register_broadcast_reactor::<E>);
+        commands.syscall((ChannelId::DEFAULT, handle.clone()), register_broadcast_reactor::<E>);
     }
 }
 
@@ -463,6 +887,61 @@ pub fn broadcast<E: Send + Sync + 'static>() -> BroadcastTrigger<E> { BroadcastT
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Reaction trigger for broadcast events on a specific channel.
+/// - Reactions only occur for events sent via [`ReactCommands::<E>::broadcast_on_channel()`] with the same channel.
+pub struct BroadcastChannelTrigger<E: Send + Sync + 'static>(ChannelId, PhantomData<E>);
+impl<E: Send + Sync + 'static> Clone for BroadcastChannelTrigger<E> { fn clone(&self) -> Self { *self } }
+impl<E: Send + Sync + 'static> Copy for BroadcastChannelTrigger<E> {}
+
+impl<E: Send + Sync + 'static> ReactionTrigger for BroadcastChannelTrigger<E>
+{
+    fn reactor_type(&self) -> ReactorType
+    {
+        ReactorType::BroadcastChannel(TypeId::of::<E>(), self.0)
+    }
+
+    fn register(&self, commands: &mut Commands, handle: &ReactorHandle)
+    {
+        commands.syscall((self.0, handle.clone()), register_broadcast_reactor::<E>);
+    }
+}
+
+/// Returns a [`BroadcastChannelTrigger`] reaction trigger.
+pub fn broadcast_channel<E: Send + Sync + 'static>(channel: ChannelId) -> BroadcastChannelTrigger<E>
+{
+    BroadcastChannelTrigger(channel, PhantomData)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Reaction trigger for broadcast events of topic `T`.
+/// - Reactions occur for events of any type registered as a member of `T` with
+///   [`ReactCommands::add_topic_member()`], when sent via [`ReactCommands::broadcast()`] or
+///   [`ReactCommands::broadcast_collect()`].
+/// - Reactors can read the event with the [`TopicEvent`] system parameter.
+pub struct BroadcastTopicTrigger<T: BroadcastTopic>(PhantomData<T>);
+impl<T: BroadcastTopic> Default for BroadcastTopicTrigger<T> { fn default() -> Self { Self(PhantomData::default()) } }
+impl<T: BroadcastTopic> Clone for BroadcastTopicTrigger<T> { fn clone(&self) -> Self { *self } }
+impl<T: BroadcastTopic> Copy for BroadcastTopicTrigger<T> {}
+
+impl<T: BroadcastTopic> ReactionTrigger for BroadcastTopicTrigger<T>
+{
+    fn reactor_type(&self) -> ReactorType
+    {
+        ReactorType::BroadcastTopic(TypeId::of::<T>())
+    }
+
+    fn register(&self, commands: &mut Commands, handle: &ReactorHandle)
+    {
+        commands.syscall(handle.clone(), register_topic_reactor::<T>);
+    }
+}
+
+/// Returns a [`BroadcastTopicTrigger`] reaction trigger.
+pub fn broadcast_topic<T: BroadcastTopic>() -> BroadcastTopicTrigger<T> { BroadcastTopicTrigger::default() }
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// Reaction trigger for despawns.
 /// - Registration does nothing if the entity does not exist.
 ///
@@ -492,3 +971,43 @@ impl ReactionTrigger for DespawnTrigger
 pub fn despawn(entity: Entity) -> DespawnTrigger { DespawnTrigger(entity) }
 
 //-------------------------------------------------------------------------------------------------------------------
+
+/// Reaction trigger for asset loads.
+/// - Reactions occur when the asset finishes loading with all its dependencies (i.e. on
+///   [`AssetEvent::LoadedWithDependencies`]), including after hot-reloads.
+/// - If the asset is already loaded when the reactor is registered, then the reactor will run immediately.
+/// - Reactors can read the [`AssetId`] with the [`BroadcastEvent`] system parameter (e.g.
+///   `BroadcastEvent<AssetId<A>>`).
+///
+/// Registering the first reactor for an asset type adds a load watcher for that type to the [`ReactPlugin`]'s asset
+/// system (it runs in `Last` after [`AssetEvents`](bevy::asset::AssetEvents)). The asset type must be initialized
+/// with `app.init_asset()`.
+#[cfg(feature = "bevy_asset")]
+pub struct AssetLoadedTrigger<A: Asset>(AssetId<A>);
+#[cfg(feature = "bevy_asset")]
+impl<A: Asset> Clone for AssetLoadedTrigger<A> { fn clone(&self) -> Self { *self } }
+#[cfg(feature = "bevy_asset")]
+impl<A: Asset> Copy for AssetLoadedTrigger<A> {}
+
+#[cfg(feature = "bevy_asset")]
+impl<A: Asset> ReactionTrigger for AssetLoadedTrigger<A>
+{
+    fn reactor_type(&self) -> ReactorType
+    {
+        ReactorType::AssetLoaded(self.0.untyped())
+    }
+
+    fn register(&self, commands: &mut Commands, handle: &ReactorHandle)
+    {
+        commands.syscall((self.0, handle.clone()), register_asset_loaded_reactor::<A>);
+    }
+}
+
+/// Returns an [`AssetLoadedTrigger`] reaction trigger.
+#[cfg(feature = "bevy_asset")]
+pub fn asset_loaded<A: Asset>(handle: impl Into<AssetId<A>>) -> AssetLoadedTrigger<A>
+{
+    AssetLoadedTrigger(handle.into())
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,228 @@
+//local shortcuts
+use crate::prelude::*;
+
+//third-party shortcuts
+
+//standard shortcuts
+use core::any::TypeId;
+use std::marker::PhantomData;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// The kind of reaction data read by a reader system parameter.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ReaderKind
+{
+    /// [`InsertionEvent`]
+    Insertion(TypeId),
+    /// [`MutationEvent`]
+    Mutation(TypeId),
+    /// [`ChangeEvent`]
+    Change(TypeId),
+    /// [`RemovalEvent`]
+    Removal(TypeId),
+    /// [`EntityEvent`]
+    EntityEvent(TypeId),
+    /// [`BroadcastEvent`] and [`ArcBroadcastEvent`]
+    Broadcast(TypeId),
+    /// [`TopicEvent`]
+    Topic(TypeId),
+    /// [`DespawnEvent`]
+    Despawn,
+}
+
+impl ReaderKind
+{
+    /// Returns `true` if reactions scheduled by a trigger of type `registered` have data for this reader.
+    pub fn reads(&self, registered: ReactorType) -> bool
+    {
+        match (*self, registered)
+        {
+            (Self::Insertion(a), ReactorType::EntityInsertion(_, b) | ReactorType::ComponentInsertion(b)) |
+            (Self::Mutation(a), ReactorType::EntityMutation(_, b) | ReactorType::ComponentMutation(b)) |
+            (
+                Self::Change(a),
+                ReactorType::EntityInsertion(_, b) | ReactorType::ComponentInsertion(b) |
+                ReactorType::EntityMutation(_, b) | ReactorType::ComponentMutation(b)
+            ) |
+            (Self::Removal(a), ReactorType::EntityRemoval(_, b) | ReactorType::ComponentRemoval(b)) |
+            (Self::EntityEvent(a), ReactorType::EntityEvent(_, b) | ReactorType::AnyEntityEvent(b)) |
+            (Self::Broadcast(a), ReactorType::Broadcast(b) | ReactorType::BroadcastChannel(b, _)) |
+            (Self::Topic(a), ReactorType::BroadcastTopic(b)) => a == b,
+            (Self::Despawn, ReactorType::Despawn(_)) => true,
+            _ => false,
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Used by [`react_on`](crate::react_on) to look up the [`ReaderKind`] of a system parameter type.
+#[doc(hidden)]
+pub struct ReaderProbe<T>(PhantomData<T>);
+
+impl<T> ReaderProbe<T>
+{
+    pub fn new() -> Self
+    {
+        Self(PhantomData)
+    }
+}
+
+impl<T> Default for ReaderProbe<T>
+{
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+/// Used by [`react_on`](crate::react_on) to look up the [`ReaderKind`] of a system parameter type.
+///
+/// Reader parameters implement this on `ReaderProbe<Reader>`, and all other types fall back to the implementation on
+/// `&ReaderProbe<T>` (autoref specialization).
+#[doc(hidden)]
+pub trait ProbeReader
+{
+    fn reader_kind(&self) -> Option<ReaderKind>;
+}
+
+impl<T> ProbeReader for &ReaderProbe<T>
+{
+    fn reader_kind(&self) -> Option<ReaderKind> { None }
+}
+
+impl<T: ReactComponent> ProbeReader for ReaderProbe<InsertionEvent<'_, '_, T>>
+{
+    fn reader_kind(&self) -> Option<ReaderKind> { Some(ReaderKind::Insertion(TypeId::of::<T>())) }
+}
+
+impl<T: ReactComponent> ProbeReader for ReaderProbe<MutationEvent<'_, '_, T>>
+{
+    fn reader_kind(&self) -> Option<ReaderKind> { Some(ReaderKind::Mutation(TypeId::of::<T>())) }
+}
+
+impl<T: ReactComponent> ProbeReader for ReaderProbe<ChangeEvent<'_, '_, T>>
+{
+    fn reader_kind(&self) -> Option<ReaderKind> { Some(ReaderKind::Change(TypeId::of::<T>())) }
+}
+
+impl<T: ReactComponent> ProbeReader for ReaderProbe<RemovalEvent<'_, '_, T>>
+{
+    fn reader_kind(&self) -> Option<ReaderKind> { Some(ReaderKind::Removal(TypeId::of::<T>())) }
+}
+
+impl<T: Send + Sync + 'static> ProbeReader for ReaderProbe<EntityEvent<'_, '_, T>>
+{
+    fn reader_kind(&self) -> Option<ReaderKind> { Some(ReaderKind::EntityEvent(TypeId::of::<T>())) }
+}
+
+impl<T: Send + Sync + 'static> ProbeReader for ReaderProbe<BroadcastEvent<'_, '_, T>>
+{
+    fn reader_kind(&self) -> Option<ReaderKind> { Some(ReaderKind::Broadcast(TypeId::of::<T>())) }
+}
+
+impl<T: Send + Sync + 'static> ProbeReader for ReaderProbe<ArcBroadcastEvent<'_, '_, T>>
+{
+    fn reader_kind(&self) -> Option<ReaderKind> { Some(ReaderKind::Broadcast(TypeId::of::<std::sync::Arc<T>>())) }
+}
+
+impl<T: BroadcastTopic> ProbeReader for ReaderProbe<TopicEvent<'_, '_, T>>
+{
+    fn reader_kind(&self) -> Option<ReaderKind> { Some(ReaderKind::Topic(TypeId::of::<T>())) }
+}
+
+impl ProbeReader for ReaderProbe<DespawnEvent<'_>>
+{
+    fn reader_kind(&self) -> Option<ReaderKind> { Some(ReaderKind::Despawn) }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Panics if `reader` can never read data from reactions scheduled by `triggers`.
+///
+/// Does nothing if `reader` is `None` (i.e. the parameter is not a reader).
+#[doc(hidden)]
+pub fn check_reader(triggers: impl ReactionTriggerBundle, reader: Option<ReaderKind>, reader_name: &str)
+{
+    let Some(reader) = reader else { return; };
+    let registered = get_reactor_types(triggers);
+    if registered.iter().any(|registered| reader.reads(*registered)) { return; }
+
+    panic!("reader {reader_name} ({reader:?}) can never read data from the reactor's triggers {registered:?}");
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Registers a reactor with [`ReactCommands::on`], checking in debug builds that each reader parameter of the reactor
+/// can read data from at least one of its triggers.
+///
+/// Reading with the wrong type (e.g. a [`MutationEvent<B>`] in a reactor triggered by `mutation::<A>()`) is not a
+/// compile error, and the reader will silently never have data. This macro catches those mistakes when the reactor is
+/// registered.
+///
+/// A full static check isn't possible, so the macro reads the parameter types of closure reactors syntactically and
+/// checks them when the reactor is registered:
+/// - Parameters are checked in order until one isn't written as `name: Type` or `mut name: Type`. Reactors that
+///   aren't closures are not checked.
+/// - Readers are matched by type only (e.g. a [`MutationEvent<A>`] is accepted for any
+///   `entity_mutation::<A>(entity)` trigger).
+/// - Type aliases of readers and [`BatchedRemovalEvent`] are not recognized as readers.
+///
+/// Panics in debug builds if a reader can never read data. In release builds this is equivalent to
+/// [`ReactCommands::on`].
+///
+/*
+```rust
+react_on!(rc, mutation::<Health>(),
+    |event: MutationEvent<Health>, mut log: ResMut<Log>|
+    {
+        log.push(event.entity());
+    }
+);
+```
+*/
+#[macro_export]
+macro_rules! react_on
+{
+    // Finds the parameters of a closure reactor.
+    (@scan $triggers:ident, move | $($rest:tt)*) => { $crate::react_on!(@params $triggers, $($rest)*); };
+    (@scan $triggers:ident, | $($rest:tt)*) => { $crate::react_on!(@params $triggers, $($rest)*); };
+    (@scan $triggers:ident, $($rest:tt)*) => {};
+
+    // Checks typed parameters until the end of the parameter list or an unsupported parameter.
+    (@params $triggers:ident, mut $arg:ident : $ty:ty , $($rest:tt)*) =>
+    {
+        $crate::react_on!(@check $triggers, $ty);
+        $crate::react_on!(@params $triggers, $($rest)*);
+    };
+    (@params $triggers:ident, mut $arg:ident : $ty:ty | $($rest:tt)*) => { $crate::react_on!(@check $triggers, $ty); };
+    (@params $triggers:ident, $arg:ident : $ty:ty , $($rest:tt)*) =>
+    {
+        $crate::react_on!(@check $triggers, $ty);
+        $crate::react_on!(@params $triggers, $($rest)*);
+    };
+    (@params $triggers:ident, $arg:ident : $ty:ty | $($rest:tt)*) => { $crate::react_on!(@check $triggers, $ty); };
+    (@params $triggers:ident, $($rest:tt)*) => {};
+
+    (@check $triggers:ident, $ty:ty) =>
+    {{
+        #[allow(unused_imports)]
+        use $crate::prelude::ProbeReader;
+        $crate::prelude::check_reader(
+            $triggers,
+            (&$crate::prelude::ReaderProbe::<$ty>::new()).reader_kind(),
+            stringify!($ty)
+        );
+    }};
+
+    ($rc:expr, $triggers:expr, $($reactor:tt)+) =>
+    {{
+        let triggers = $triggers;
+        #[cfg(debug_assertions)]
+        $crate::react_on!(@scan triggers, $($reactor)+);
+        $rc.on(triggers, $($reactor)+)
+    }};
+}
+
+//-------------------------------------------------------------------------------------------------------------------
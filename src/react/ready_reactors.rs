@@ -0,0 +1,73 @@
+//local shortcuts
+use crate::prelude::*;
+
+//third-party shortcuts
+use bevy::ecs::world::Command;
+use bevy::prelude::*;
+
+//standard shortcuts
+use std::marker::PhantomData;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// What happens to triggers that fire before the resource of a reactor registered with
+/// [`ReactCommands::on_when_ready_with`] exists.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub enum EarlyTriggers
+{
+    /// Each early trigger is replayed when the resource is inserted.
+    #[default]
+    Replay,
+    /// Early triggers collapse into one run when the resource is inserted.
+    ReplayOnce,
+    /// Early triggers are dropped.
+    Drop,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Buffers runs of reactors that are waiting for `R` to be inserted.
+#[derive(Resource)]
+struct ReadyReactorBuffer<R: ReactResource>
+{
+    /// Reactors to run when `R` is inserted, in the order their triggers fired.
+    pending: Vec<SystemCommand>,
+    p: PhantomData<R>,
+}
+
+impl<R: ReactResource> Default for ReadyReactorBuffer<R>
+{
+    fn default() -> Self
+    {
+        Self{ pending: Vec::default(), p: PhantomData }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Records a trigger for a reactor that is waiting for `R`.
+pub(crate) fn buffer_early_trigger<R: ReactResource>(world: &mut World, command: SystemCommand, early: EarlyTriggers)
+{
+    if early == EarlyTriggers::Drop { return; }
+    let mut buffer = world.get_resource_or_insert_with(ReadyReactorBuffer::<R>::default);
+    if early == EarlyTriggers::ReplayOnce && buffer.pending.contains(&command) { return; }
+    buffer.pending.push(command);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Runs reactors that were waiting for `R`, after `R` is inserted.
+///
+/// Replayed runs are not reactions, so reaction readers like [`BroadcastEvent`] will be empty.
+pub(crate) fn replay_ready_reactors<R: ReactResource>(world: &mut World)
+{
+    let Some(buffer) = world.remove_resource::<ReadyReactorBuffer<R>>() else { return; };
+    for command in buffer.pending
+    {
+        // Reactors revoked in the meantime are skipped.
+        if world.get_entity(*command).is_err() { continue; }
+        command.apply(world);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,271 @@
+//local shortcuts
+use crate::prelude::*;
+
+//third-party shortcuts
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+//standard shortcuts
+use core::any::TypeId;
+use core::marker::PhantomData;
+use core::ops::{BitOr, BitOrAssign};
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Set of fields of a [`ReactResource`] that implements [`TrackFields`].
+///
+/// Bit `i` is the `i`th field in declaration order. Use the constants generated by
+/// `#[derive(ReactResource)]` to name fields (see [`TrackFields`]).
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct FieldMask(u64);
+
+impl FieldMask
+{
+    /// The empty mask.
+    pub const NONE: Self = Self(0);
+
+    /// Makes a mask from raw bits.
+    pub const fn from_bits(bits: u64) -> Self
+    {
+        Self(bits)
+    }
+
+    /// Makes a mask with the first `count` fields set.
+    pub const fn first(count: u32) -> Self
+    {
+        if count >= u64::BITS { return Self(u64::MAX); }
+        Self((1u64 << count) - 1)
+    }
+
+    /// Gets the raw bits.
+    pub const fn bits(&self) -> u64
+    {
+        self.0
+    }
+
+    /// Returns `true` if no fields are set.
+    pub const fn is_empty(&self) -> bool
+    {
+        self.0 == 0
+    }
+
+    /// Returns `true` if all fields in `other` are set.
+    pub const fn contains(&self, other: Self) -> bool
+    {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns `true` if any field in `other` is set.
+    pub const fn intersects(&self, other: Self) -> bool
+    {
+        self.0 & other.0 != 0
+    }
+}
+
+impl BitOr for FieldMask
+{
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self
+    {
+        Self(self.0 | other.0)
+    }
+}
+
+impl BitOrAssign for FieldMask
+{
+    fn bitor_assign(&mut self, other: Self)
+    {
+        self.0 |= other.0;
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Trait for [`ReactResources`](ReactResource) that report which fields changed in mutation reactions.
+///
+/// Implemented by `#[derive(ReactResource)]` with `#[react(track_fields)]`, which also generates a `{Name}Fields`
+/// type with one [`FieldMask`] constant per field (the uppercased field name, or `_0`, `_1`, ... for tuple
+/// structs) and an `ALL` constant. Resources can have at most 64 fields.
+///
+/// Change fields with [`ReactResMut::get_field_mut`] and read them with [`ResourceMutationEvent`].
+/*
+```rust
+#[derive(ReactResource)]
+#[react(track_fields)]
+struct Settings
+{
+    volume: f32,
+    theme: Theme,
+}
+
+fn set_volume(mut c: Commands, mut settings: ReactResMut<Settings>)
+{
+    settings.get_field_mut(&mut c, SettingsFields::VOLUME).volume = 0.5;
+}
+```
+*/
+pub trait TrackFields: ReactResource
+{
+    /// Number of fields in the resource.
+    const FIELD_COUNT: u32;
+
+    /// Gets a mask with all of the resource's fields set.
+    fn all_fields() -> FieldMask
+    {
+        FieldMask::first(Self::FIELD_COUNT)
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Identifies the source of a resource mutation made with [`ReactResMut::get_mut_tagged`].
+///
+/// Read the tag in reactors with [`ResourceMutationSource`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct ChangeTag(pub &'static str);
+
+impl From<&'static str> for ChangeTag
+{
+    fn from(tag: &'static str) -> Self
+    {
+        Self(tag)
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Metadata of a resource mutation that its reactors can read.
+#[derive(Debug, Default, Copy, Clone)]
+pub(crate) struct ResourceMutationInfo
+{
+    /// Fields changed by the mutation, if they are known.
+    pub(crate) fields: Option<FieldMask>,
+    /// Source of the mutation, if it was tagged.
+    pub(crate) tag: Option<ChangeTag>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Tracks metadata of the resource mutations whose reactions are currently running.
+///
+/// Mutations with metadata push it before their reactions run and pop it after. Plain mutations of a resource with
+/// pushed metadata push empty metadata so their reactions don't read the outer mutation's metadata.
+#[derive(Resource, Default)]
+pub(crate) struct ResourceMutationTracker
+{
+    /// Metadata stacks, innermost last.
+    running: HashMap<TypeId, Vec<ResourceMutationInfo>>,
+}
+
+impl ResourceMutationTracker
+{
+    pub(crate) fn push(&mut self, resource: TypeId, info: ResourceMutationInfo)
+    {
+        self.running.entry(resource).or_default().push(info);
+    }
+
+    pub(crate) fn pop(&mut self, resource: TypeId)
+    {
+        let Some(running) = self.running.get_mut(&resource) else { return; };
+        let _ = running.pop();
+        if running.is_empty() { self.running.remove(&resource); }
+    }
+
+    /// Returns `true` if metadata is pushed for the resource.
+    pub(crate) fn is_tracking(&self, resource: TypeId) -> bool
+    {
+        self.running.contains_key(&resource)
+    }
+
+    fn current(&self, resource: TypeId) -> ResourceMutationInfo
+    {
+        self.running.get(&resource).and_then(|running| running.last().copied()).unwrap_or_default()
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// System parameter for reading which fields changed in a [`resource_mutation`] reaction.
+///
+/// Mutations made with [`ReactResMut::get_field_mut`] report the fields passed in. If the resource was mutated with
+/// `get_field_mut` several times before its reactions were scheduled, the mutations are coalesced into one reaction
+/// and their masks are OR'd together.
+///
+/// All other mutations (e.g. [`ReactResMut::get_mut`]) report all fields. All fields are also reported when not
+/// reacting to a mutation of `R`.
+///
+/*
+```rust
+rc.on(resource_mutation::<Settings>(),
+    |event: ResourceMutationEvent<Settings>|
+    {
+        if event.changed(SettingsFields::THEME) { rebuild_theme(); }
+    }
+);
+```
+*/
+#[derive(SystemParam)]
+pub struct ResourceMutationEvent<'w, R: TrackFields>
+{
+    tracker: Res<'w, ResourceMutationTracker>,
+    _p: PhantomData<R>,
+}
+
+impl<'w, R: TrackFields> ResourceMutationEvent<'w, R>
+{
+    /// Gets the fields that changed.
+    pub fn fields(&self) -> FieldMask
+    {
+        self.tracker.current(TypeId::of::<R>()).fields.unwrap_or_else(R::all_fields)
+    }
+
+    /// Returns `true` if any of `fields` changed.
+    pub fn changed(&self, fields: FieldMask) -> bool
+    {
+        self.fields().intersects(fields)
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// System parameter for reading the [`ChangeTag`] of a [`resource_mutation`] reaction.
+///
+/// Mutations made with [`ReactResMut::get_mut_tagged`] report their tag. All other mutations report `None`, and
+/// `None` is also reported when not reacting to a mutation of `R`.
+///
+/*
+```rust
+rc.on(resource_mutation::<Score>(),
+    |source: ResourceMutationSource<Score>|
+    {
+        if source.is(ChangeTag("network")) { return; }
+        sync_score();
+    }
+);
+```
+*/
+#[derive(SystemParam)]
+pub struct ResourceMutationSource<'w, R: ReactResource>
+{
+    tracker: Res<'w, ResourceMutationTracker>,
+    _p: PhantomData<R>,
+}
+
+impl<'w, R: ReactResource> ResourceMutationSource<'w, R>
+{
+    /// Gets the tag of the mutation.
+    pub fn tag(&self) -> Option<ChangeTag>
+    {
+        self.tracker.current(TypeId::of::<R>()).tag
+    }
+
+    /// Returns `true` if the mutation was tagged with `tag`.
+    pub fn is(&self, tag: ChangeTag) -> bool
+    {
+        self.tag() == Some(tag)
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
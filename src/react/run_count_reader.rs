@@ -0,0 +1,60 @@
+//local shortcuts
+use crate::prelude::*;
+
+//third-party shortcuts
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+//standard shortcuts
+
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// System parameter for reading how many times the current system command has run (including the current run).
+///
+/// Counts are stored alongside each system command's callback and incremented each time the system command starts
+/// running. They are never reset, so they are monotonic for the lifetime of the system command.
+///
+/// Returns `0` when not used within [`SystemCommands`](super::SystemCommand).
+///
+/*
+```rust
+rc.on(broadcast::<()>(),
+    |run_count: ReactionRunCount|
+    {
+        if run_count.is_first() { println!("first run"); }
+    }
+);
+```
+*/
+#[derive(SystemParam)]
+pub struct ReactionRunCount<'w, 's>
+{
+    tracker  : Res<'w, SyscommandTracker>,
+    commands : Query<'w, 's, &'static SystemCommandStorage>,
+}
+
+impl<'w, 's> ReactionRunCount<'w, 's>
+{
+    /// Returns the number of times the current system command has run, including the current run.
+    pub fn get(&self) -> u64
+    {
+        self.tracker.current_run_count()
+    }
+
+    /// Returns the number of times `command` has run (including any current run).
+    ///
+    /// Returns `0` if `command` doesn't exist.
+    pub fn get_for(&self, command: SystemCommand) -> u64
+    {
+        self.commands.get(*command).map(|storage| storage.run_count()).unwrap_or_default()
+    }
+
+    /// Returns `true` if this is the first time the current system command has run.
+    pub fn is_first(&self) -> bool
+    {
+        self.get() == 1
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,108 @@
+//local shortcuts
+use crate::prelude::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+
+//standard shortcuts
+
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Information about a reaction run by [`SteppedTree::step`].
+#[derive(Debug, Clone)]
+pub struct SteppedReactorInfo
+{
+    /// The reactor that ran, or `None` for callbacks queued with [`ReactCommands::queue_in_tree`].
+    pub reactor: Option<SystemCommand>,
+    /// The trigger that scheduled the reaction, or `None` for queued callbacks.
+    pub trigger: Option<ReactorType>,
+    /// Human-readable description of the trigger.
+    pub description: String,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A tree of system commands that is driven manually one reaction at a time, for debugging reaction cascades.
+///
+/// Made with [`ReactWorldExt::begin_stepped_tree`]. While the tree is active, reactions scheduled anywhere in the world
+/// are queued instead of running, and [`Self::step`] runs exactly one of them. Reactions scheduled by a step are
+/// queued in front of the remaining reactions, so steps run in the same order reactions would run without stepping.
+///
+/// The whole stepped tree counts as one tree of system commands: tree-end behavior (e.g.
+/// [`ReactCommands::on_tree_complete`] readers, batched reactors, and retained entity events) only happens when the
+/// tree is completed with [`Self::finish`] or discarded with [`Self::abort`]. One of them must be called, otherwise
+/// reactions will never run again.
+///
+/// System commands and system events are not stepped. They run within the step that scheduled them.
+#[must_use = "stepped trees must be completed with `SteppedTree::finish` or discarded with `SteppedTree::abort`"]
+pub struct SteppedTree
+{
+    _private: (),
+}
+
+impl SteppedTree
+{
+    pub(crate) fn begin(world: &mut World) -> Self
+    {
+        if **world.resource::<SyscommandCounter>() != 0
+        { panic!("failed beginning stepped tree, a tree of system commands is running"); }
+        let mut stepped = world.resource_mut::<SteppedTreeQueue>();
+        if stepped.is_active()
+        { panic!("failed beginning stepped tree, a stepped tree is already active"); }
+
+        // The stepped tree acts as the root of a tree of system commands.
+        stepped.set_active(true);
+        **world.resource_mut::<SyscommandCounter>() = 1;
+
+        Self{ _private: () }
+    }
+
+    /// Runs the next queued reaction.
+    ///
+    /// Returns `None` if no reactions are queued.
+    pub fn step(&mut self, world: &mut World) -> Option<SteppedReactorInfo>
+    {
+        let reaction = world.resource_mut::<SteppedTreeQueue>().next()?;
+        let (reactor, trigger) = reaction.info();
+        reaction.prepare(world).run(world);
+        world.resource_mut::<SteppedTreeQueue>().end_step();
+
+        Some(SteppedReactorInfo{ reactor, trigger: trigger.reactor_type(), description: trigger.to_string() })
+    }
+
+    /// Returns `true` if no reactions are queued.
+    pub fn is_done(&self, world: &World) -> bool
+    {
+        world.resource::<SteppedTreeQueue>().is_empty()
+    }
+
+    /// Runs all remaining reactions then ends the tree.
+    pub fn finish(mut self, world: &mut World)
+    {
+        while self.step(world).is_some() {}
+        Self::end(world);
+    }
+
+    /// Discards all remaining reactions (including reactions scheduled while discarding) then ends the tree.
+    ///
+    /// Reaction data is cleaned up as if the discarded reactors were missing.
+    pub fn abort(self, world: &mut World)
+    {
+        while let Some(reaction) = world.resource_mut::<SteppedTreeQueue>().next()
+        {
+            let (reactor, trigger) = reaction.info();
+            tracing::debug!(?reactor, %trigger, "discarding reaction of aborted stepped tree");
+            reaction.prepare(world).abort(world);
+        }
+        Self::end(world);
+    }
+
+    fn end(world: &mut World)
+    {
+        world.resource_mut::<SteppedTreeQueue>().set_active(false);
+        end_syscommand_tree(world);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -3,14 +3,18 @@ use crate::prelude::*;
 
 //third-party shortcuts
 use bevy::prelude::*;
+use bevy::utils::{HashMap, HashSet};
 
 //standard shortcuts
+use core::any::{Any, TypeId};
+use std::collections::VecDeque;
+use std::sync::Arc;
 
 
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
 
-fn cleanup_on_abort(world: &mut World, setup: SystemCommandSetup, cleanup: SystemCommandCleanup)
+pub(crate) fn cleanup_on_abort(world: &mut World, setup: SystemCommandSetup, cleanup: SystemCommandCleanup)
 {
     // We run setup even on abort in case there was a 'prepare' step that needs to be cleared.
     setup.run(world);
@@ -19,6 +23,302 @@ fn cleanup_on_abort(world: &mut World, setup: SystemCommandSetup, cleanup: Syste
     schedule_removal_and_despawn_reactors(world);
 }
 
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Releases entity events retained for the tree of system commands that just ended.
+fn release_retained_entity_events(world: &mut World)
+{
+    let Some(mut cache) = world.get_resource_mut::<ReactCache>() else { return; };
+    let data_entities = cache.take_retained_entity_events();
+
+    for data_entity in data_entities
+    {
+        try_cleanup_data_entity(world, data_entity);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Runs tree completion readers with the results accumulated during the tree of system commands that just ended.
+///
+/// Readers run in LIFO order. Results are reset before the readers run, so reactions triggered by readers
+/// accumulate into their own trees.
+fn complete_tree_results(world: &mut World)
+{
+    let Some(mut results) = world.get_resource_mut::<TreeResults>() else { return; };
+    let (mut values, readers) = results.take_tree();
+
+    for reader in readers.into_iter().rev()
+    {
+        (reader)(&mut values, world);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Runs batched reactors with the entities collected during the tree of system commands that just ended.
+///
+/// Each batch runs in its own tree, so entities collected while a batch runs will be sent in a follow-up batch.
+fn flush_batched_reactions(world: &mut World)
+{
+    let Some(mut cache) = world.get_resource_mut::<ReactCache>() else { return; };
+    let batches = cache.take_batched_reactions();
+
+    for (batch_reactor, entities) in batches
+    {
+        world.send_system_event(batch_reactor, entities);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Runs reactors rescheduled with [`ReactCommands::reschedule_current_next_tree`] in the tree that is starting.
+///
+/// Reactors rescheduled while these run are kept for the tree after this one.
+fn run_pending_next_tree(world: &mut World)
+{
+    let Some(mut tracker) = world.get_resource_mut::<SyscommandTracker>() else { return; };
+    let pending = tracker.take_pending();
+
+    for command in pending
+    {
+        syscommand_runner(world, command, SystemCommandSetup::default(), SystemCommandCleanup::default());
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Starts a tree for reactors rescheduled with [`ReactCommands::reschedule_current_next_tree`] if no other tree
+/// picked them up since they were rescheduled.
+pub(crate) fn run_next_tree_reactors(world: &mut World)
+{
+    if world.resource::<SyscommandTracker>().pending.is_empty() { return; }
+    if **world.resource::<SyscommandCounter>() != 0 { return; }
+
+    // Act as the root of a tree of system commands.
+    **world.resource_mut::<SyscommandCounter>() = 1;
+    run_pending_next_tree(world);
+    end_syscommand_tree(world);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A system command that is currently running.
+#[derive(Debug, Copy, Clone)]
+struct RunningSyscommand
+{
+    command: SystemCommand,
+    /// The system command's run count, including the current run.
+    run_count: u64,
+    /// The trigger that scheduled the run.
+    trigger: Option<ReactorType>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Tracks running system commands, the system commands that ran in the current tree of system commands, and the
+/// reactors rescheduled for the next tree.
+///
+/// All per-run bookkeeping lives here so the runner only touches one resource when a system command starts and ends.
+#[derive(Resource, Default)]
+pub(crate) struct SyscommandTracker
+{
+    /// System commands that are currently running, innermost last.
+    running: Vec<RunningSyscommand>,
+    /// System commands that started running in the current tree.
+    ran: HashSet<SystemCommand>,
+    /// System commands that will run at the start of the next tree.
+    pending: Vec<SystemCommand>,
+}
+
+impl SyscommandTracker
+{
+    fn start(&mut self, command: SystemCommand, run_count: u64, trigger: Option<ReactorType>)
+    {
+        self.running.push(RunningSyscommand{ command, run_count, trigger });
+        self.ran.insert(command);
+    }
+
+    fn end(&mut self)
+    {
+        let _ = self.running.pop();
+    }
+
+    fn end_tree(&mut self)
+    {
+        self.ran.clear();
+    }
+
+    fn take_pending(&mut self) -> Vec<SystemCommand>
+    {
+        std::mem::take(&mut self.pending)
+    }
+
+    /// Returns `true` if no system commands are running.
+    pub(crate) fn is_idle(&self) -> bool
+    {
+        self.running.is_empty()
+    }
+
+    /// Returns the innermost running system command.
+    pub(crate) fn current(&self) -> Option<SystemCommand>
+    {
+        self.running.last().map(|running| running.command)
+    }
+
+    /// Returns the run count of the innermost running system command, or `0` if none is running.
+    pub(crate) fn current_run_count(&self) -> u64
+    {
+        self.running.last().map(|running| running.run_count).unwrap_or_default()
+    }
+
+    /// Returns the trigger of the innermost running system command.
+    pub(crate) fn current_trigger(&self) -> Option<ReactorType>
+    {
+        self.running.last().and_then(|running| running.trigger)
+    }
+
+    /// Returns `true` if `command` started running in the current tree.
+    pub(crate) fn has_run(&self, command: SystemCommand) -> bool
+    {
+        self.ran.contains(&command)
+    }
+
+    /// Reschedules the current system command. Returns `false` if no system command is running.
+    pub(crate) fn reschedule_current(&mut self) -> bool
+    {
+        let Some(current) = self.current() else { return false; };
+        if !self.pending.contains(&current)
+        {
+            self.pending.push(current);
+        }
+        true
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) fn reschedule_current_reactor(mut tracker: ResMut<SyscommandTracker>)
+{
+    if tracker.reschedule_current() { return; }
+    tracing::warn!("ignoring ReactCommands::reschedule_current_next_tree, no system command is running");
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Folds a new value into the accumulated result for a tree of system commands.
+pub(crate) type TreeResultCombiner<T> = Arc<dyn Fn(&mut T, T) + Send + Sync + 'static>;
+
+/// Reads the accumulated result for a tree of system commands.
+pub(crate) type TreeResultCallback<T> = Box<dyn FnOnce(T, &mut World) + Send + Sync + 'static>;
+
+/// Accumulated results keyed by result type.
+type TreeResultValues = HashMap<TypeId, Box<dyn Any + Send + Sync>>;
+
+/// Tree completion reader, type-erased over the result type it reads.
+type TreeResultReader = Box<dyn FnOnce(&mut TreeResultValues, &mut World) + Send + Sync>;
+
+/// Per-tree storage for [`ReactCommands::accumulate`] and [`ReactCommands::on_tree_complete`].
+///
+/// Values are keyed by the [`TypeId`] of the result type and reset at the end of each tree.
+#[derive(Resource, Default)]
+pub(crate) struct TreeResults
+{
+    /// Combiners for each result type (`TreeResultCombiner<T>`).
+    combiners: HashMap<TypeId, Box<dyn Any + Send + Sync>>,
+    /// Accumulated values in the current tree (`T`).
+    values: TreeResultValues,
+    /// Completion readers for the current tree.
+    readers: Vec<TreeResultReader>,
+}
+
+impl TreeResults
+{
+    pub(crate) fn register_combiner<T: Send + Sync + 'static>(&mut self, combiner: TreeResultCombiner<T>)
+    {
+        self.combiners.insert(TypeId::of::<T>(), Box::new(combiner));
+    }
+
+    pub(crate) fn accumulate<T: Default + Send + Sync + 'static>(&mut self, value: T)
+    {
+        let Some(combiner) = self.combiners.get(&TypeId::of::<T>())
+        else
+        {
+            tracing::warn!("dropping tree result {}, no combiner was registered for it; use \
+                ReactCommands::register_tree_result", std::any::type_name::<T>());
+            return;
+        };
+        let combiner = combiner.downcast_ref::<TreeResultCombiner<T>>().unwrap();
+
+        let accumulated = self.values
+            .entry(TypeId::of::<T>())
+            .or_insert_with(|| Box::new(T::default()))
+            .downcast_mut::<T>()
+            .unwrap();
+        (combiner)(accumulated, value);
+    }
+
+    pub(crate) fn push_reader(&mut self, reader: TreeResultReader)
+    {
+        self.readers.push(reader);
+    }
+
+    pub(crate) fn take_tree(&mut self) -> (TreeResultValues, Vec<TreeResultReader>)
+    {
+        (std::mem::take(&mut self.values), std::mem::take(&mut self.readers))
+    }
+
+    pub(crate) fn is_empty(&self) -> bool
+    {
+        self.values.is_empty() && self.readers.is_empty()
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) fn register_tree_result_combiner<T: Send + Sync + 'static>(
+    In(combiner) : In<TreeResultCombiner<T>>,
+    mut results  : ResMut<TreeResults>,
+){
+    results.register_combiner(combiner);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) fn accumulate_tree_result<T: Default + Send + Sync + 'static>(
+    In(value)   : In<T>,
+    mut results : ResMut<TreeResults>,
+){
+    results.accumulate(value);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Adds a tree completion reader for result type `T`.
+///
+/// If no tree is running then the reader runs immediately with the results accumulated so far.
+pub(crate) fn add_tree_result_reader<T: Default + Send + Sync + 'static>(
+    In(reader) : In<TreeResultCallback<T>>,
+    world      : &mut World,
+){
+    let reader: TreeResultReader = Box::new(
+        move |values, world|
+        {
+            let value = values
+                .remove(&TypeId::of::<T>())
+                .map(|value| *value.downcast::<T>().unwrap())
+                .unwrap_or_default();
+            (reader)(value, world);
+        }
+    );
+    world.resource_mut::<TreeResults>().push_reader(reader);
+
+    if **world.resource::<SyscommandCounter>() != 0 { return; }
+    complete_tree_results(world);
+}
+
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
 
@@ -26,19 +326,33 @@ fn cleanup_on_abort(world: &mut World, setup: SystemCommandSetup, cleanup: Syste
 pub(crate) struct SystemCommandSetup
 {
     reactor: SystemCommand,
-    setup: fn(&mut World, SystemCommand),
+    /// Identifies the reaction metadata prepared for this run of the reactor.
+    version: ReactionVersion,
+    setup: fn(&mut World, SystemCommand, ReactionVersion),
+    /// The trigger that scheduled this system command, for diagnostics.
+    trigger: ReactionTriggerInfo,
 }
 
 impl SystemCommandSetup
 {
-    pub(crate) fn new(reactor: SystemCommand, setup: fn(&mut World, SystemCommand)) -> Self
+    pub(crate) fn new(
+        reactor : SystemCommand,
+        version : ReactionVersion,
+        setup   : fn(&mut World, SystemCommand, ReactionVersion)
+    ) -> Self
+    {
+        Self { reactor, version, setup, trigger: ReactionTriggerInfo::default() }
+    }
+
+    pub(crate) fn with_trigger(mut self, trigger: ReactionTriggerInfo) -> Self
     {
-        Self { reactor, setup }
+        self.trigger = trigger;
+        self
     }
 
     fn run(self, world: &mut World)
     {
-        (self.setup)(world, self.reactor);
+        (self.setup)(world, self.reactor, self.version);
     }
 }
 
@@ -48,7 +362,9 @@ impl Default for SystemCommandSetup
     {
         Self{
             reactor: SystemCommand(Entity::PLACEHOLDER),
-            setup: |_, _| {}
+            version: ReactionVersion::default(),
+            setup: |_, _, _| {},
+            trigger: ReactionTriggerInfo::default(),
         }
     }
 }
@@ -85,16 +401,18 @@ pub(crate) fn syscommand_runner(
 
     // extract the callback
     // - On abort we perform garbage collection in case the cleanup auto-despawns entities.
+    // - If the command was despawned without revoking its triggers, then we drop the triggers here.
     let Ok(mut entity_mut) = world.get_entity_mut(*command)
     else
     {
+        world.syscall(command, revoke_despawned_reactor);
         cleanup_on_abort(world, setup, cleanup);
         return
     };
     let Some(mut system_command) = entity_mut.get_mut::<SystemCommandStorage>()
     else
     {
-        tracing::error!(?command, "system command component is missing on extract");
+        tracing::error!(?command, trigger = %setup.trigger, "system command component is missing on extract");
         cleanup_on_abort(world, setup, cleanup);
         return
     };
@@ -103,10 +421,10 @@ pub(crate) fn syscommand_runner(
     {
         // Cache the callback unless at the bottom of the pile.
         if idx == 0 {
-            tracing::warn!(?command, "system command missing");
+            tracing::warn!(?command, trigger = %setup.trigger, "system command missing");
             cleanup_on_abort(world, setup, cleanup);
         } else {
-            tracing::debug!(?command, "deferring suspected recursive system command");
+            tracing::debug!(?command, trigger = %setup.trigger, "deferring suspected recursive system command");
             world.resource_mut::<CobwebCommandQueue<BufferedSyscommand>>().push(
                 BufferedSyscommand{ command, setup, cleanup }
             );
@@ -114,11 +432,20 @@ pub(crate) fn syscommand_runner(
 
         return
     };
+    let run_count = system_command.increment_run_count();
 
     // run the system command
     **world.resource_mut::<SyscommandCounter>() += 1;
+    record_reaction(world, command, idx, setup.trigger);
+    if idx == 0
+    {
+        // If this command was rescheduled then it will be deferred until the callback is reinserted.
+        run_pending_next_tree(world);
+    }
+    world.resource_mut::<SyscommandTracker>().start(command, run_count, setup.trigger.reactor_type());
     setup.run(world);
     callback.run(world, cleanup);
+    world.resource_mut::<SyscommandTracker>().end();
 
     // cleanup
     // - We do this before reinserting the callback in case the callback garbage collected itself.
@@ -135,7 +462,7 @@ pub(crate) fn syscommand_runner(
         {
             std::mem::drop(callback);
             entity_mut.despawn_recursive();
-            tracing::error!(?command, "system command component is missing on insert");
+            tracing::error!(?command, trigger = %setup.trigger, "system command component is missing on insert");
 
             // In case dropping the callback caused entities to be garbage collected.
             garbage_collect_entities(world);
@@ -175,14 +502,96 @@ pub(crate) fn syscommand_runner(
     // final cleanup
     if idx == 0
     {
-        while let Some(to_discard) = world.resource_mut::<CobwebCommandQueue<BufferedSyscommand>>().pop_front() {
-            tracing::warn!(?to_discard.command, "failed to run missing system command");
-            cleanup_on_abort(world, to_discard.setup, to_discard.cleanup);
-        }
+        end_syscommand_tree(world);
+    }
+}
 
-        // Reset the counter since we are exiting the system command tree.
-        **world.resource_mut::<SyscommandCounter>() = 0;
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Exits a tree of system commands.
+///
+/// Discards system commands that were deferred but never ran, then runs the tree-end hooks.
+pub(crate) fn end_syscommand_tree(world: &mut World)
+{
+    while let Some(to_discard) = world.resource_mut::<CobwebCommandQueue<BufferedSyscommand>>().pop_front() {
+        tracing::warn!(?to_discard.command, trigger = %to_discard.setup.trigger,
+            "failed to run missing system command");
+        cleanup_on_abort(world, to_discard.setup, to_discard.cleanup);
     }
+
+    // Reset the counter since we are exiting the system command tree.
+    **world.resource_mut::<SyscommandCounter>() = 0;
+    debug_assert!(world.resource::<CobwebCommandQueue<BufferedSyscommand>>().is_empty());
+
+    // Tree-end hooks.
+    world.resource_mut::<SyscommandTracker>().end_tree();
+    record_tree_end(world);
+    release_retained_entity_events(world);
+    complete_tree_results(world);
+    flush_batched_reactions(world);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Reactions of the active [`SteppedTree`].
+///
+/// While a stepped tree is active, [`ReactionCommand`]s are captured instead of running. Reactions captured while a
+/// step runs are moved to the front of the queue when the step ends, so the queue follows the depth-first order that
+/// reactions would run in without stepping.
+#[derive(Resource, Default)]
+pub(crate) struct SteppedTreeQueue
+{
+    active: bool,
+    /// Reactions waiting to run.
+    queue: VecDeque<ReactionCommand>,
+    /// Reactions captured since the start or end of the last step.
+    captured: Vec<ReactionCommand>,
+}
+
+impl SteppedTreeQueue
+{
+    pub(crate) fn is_active(&self) -> bool
+    {
+        self.active
+    }
+
+    pub(crate) fn set_active(&mut self, active: bool)
+    {
+        self.active = active;
+    }
+
+    /// Returns `true` if no reactions are waiting.
+    pub(crate) fn is_empty(&self) -> bool
+    {
+        self.queue.is_empty() && self.captured.is_empty()
+    }
+
+    /// Gets the next reaction to run.
+    ///
+    /// Reactions captured outside of steps run after all reactions already in the queue.
+    pub(crate) fn next(&mut self) -> Option<ReactionCommand>
+    {
+        self.queue.extend(self.captured.drain(..));
+        self.queue.pop_front()
+    }
+
+    /// Moves reactions captured while a step ran to the front of the queue.
+    pub(crate) fn end_step(&mut self)
+    {
+        for reaction in self.captured.drain(..).rev()
+        {
+            self.queue.push_front(reaction);
+        }
+    }
+}
+
+/// Captures a reaction if a [`SteppedTree`] is active, otherwise returns the reaction so it can run.
+pub(crate) fn capture_stepped_reaction(world: &mut World, reaction: ReactionCommand) -> Option<ReactionCommand>
+{
+    let Some(mut stepped) = world.get_resource_mut::<SteppedTreeQueue>() else { return Some(reaction); };
+    if !stepped.active { return Some(reaction); }
+    stepped.captured.push(reaction);
+    None
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -2,10 +2,58 @@
 use crate::prelude::*;
 
 //third-party shortcuts
+use bevy::ecs::system::BoxedSystem;
 use bevy::prelude::*;
 
 //standard shortcuts
+use std::any::TypeId;
+use std::borrow::Cow;
 
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Label that can be attached to reactors for group operations.
+///
+/// Tagged reactors are registered with [`ReactCommands::on_tagged`] and revoked in bulk with
+/// [`ReactCommands::revoke_tagged`]. The tag is stored as a component on the reactor's [`SystemCommand`] entity.
+#[derive(Component, Debug, Clone, Eq, PartialEq, Hash)]
+pub enum ReactorTag
+{
+    /// A string label.
+    Name(Cow<'static, str>),
+    /// A type label.
+    Type(TypeId),
+}
+
+impl ReactorTag
+{
+    /// Makes a tag from a string label.
+    pub fn name(name: impl Into<Cow<'static, str>>) -> Self
+    {
+        Self::Name(name.into())
+    }
+
+    /// Makes a tag from a type label.
+    pub fn of<T: 'static>() -> Self
+    {
+        Self::Type(TypeId::of::<T>())
+    }
+}
+
+impl From<&'static str> for ReactorTag
+{
+    fn from(name: &'static str) -> Self
+    {
+        Self::name(name)
+    }
+}
+
+impl From<String> for ReactorTag
+{
+    fn from(name: String) -> Self
+    {
+        Self::name(name)
+    }
+}
 
 //-------------------------------------------------------------------------------------------------------------------
 
@@ -30,7 +78,7 @@ impl SystemCommandCleanup
     /// Runs the system cleanup on the world.
     ///
     /// Does nothing if no callback is stored.
-    pub(crate) fn run(self, world: &mut World)
+    pub fn run(self, world: &mut World)
     {
         let Some(cleanup) = self.cleanup else { return; };
         (cleanup)(world);
@@ -64,12 +112,87 @@ impl SystemCommandCallback
         Self::with(command)
     }
 
+    /// Makes a new system command callback from a system, and initializes the system immediately.
+    ///
+    /// The system's state is set up without running the system, so the first run won't pay the initialization cost.
+    /// Callbacks made with [`Self::new`] are initialized the first time they run.
+    pub fn new_initialized<S, R: CobwebResult, M>(world: &mut World, system: S) -> Self
+    where
+        S: IntoSystem<(), R, M> + Send + Sync + 'static
+    {
+        let mut callback = RawCallbackSystem::new(system);
+        callback.initialize(world);
+        let command = move |world: &mut World, cleanup: SystemCommandCleanup|
+        {
+            let result = callback.run_with_cleanup(world, (), move |world: &mut World| cleanup.run(world));
+            result.handle(world);
+        };
+        Self::with(command)
+    }
+
+    /// Makes a new system command callback from a boxed system.
+    ///
+    /// The system is initialized the first time it runs. Initializing an already-initialized system keeps its state
+    /// (e.g. `Local`s) but resets its change detection ticks, so it must have been initialized with the same world.
+    pub fn from_boxed(system: BoxedSystem<(), ()>) -> Self
+    {
+        let mut callback = CallbackSystem::New(system);
+        let command = move |world: &mut World, cleanup: SystemCommandCleanup|
+        {
+            let _ = callback.run_with_cleanup(world, (), move |world: &mut World| cleanup.run(world));
+        };
+        Self::with(command)
+    }
+
+    /// Makes a new system command callback from a closure with exclusive world access.
+    ///
+    /// The closure runs as an exclusive system. The [`SystemCommandCleanup`] is queued as a world command before the
+    /// closure runs, so commands added by the closure with `world.commands()` are applied after cleanup when the
+    /// closure returns.
+    pub fn from_exclusive(mut callback: impl FnMut(&mut World) + Send + Sync + 'static) -> Self
+    {
+        Self::new(move |world: &mut World| (callback)(world))
+    }
+
+    /// Makes a new system command callback from a system that reads the [`SystemEvent<I>`] sent to the command as its
+    /// input, then passes the system's output to `on_output`.
+    ///
+    /// The system does not run if the command runs without an event.
+    pub(crate) fn with_event<I, O, M>(
+        system        : impl IntoSystem<In<I>, O, M> + Send + Sync + 'static,
+        mut on_output : impl FnMut(&mut World, O) + Send + Sync + 'static
+    ) -> Self
+    where
+        I: Send + Sync + 'static,
+        O: Send + Sync + 'static,
+    {
+        let mut take_input = RawCallbackSystem::new(|mut event: SystemEvent<I>| event.take().ok());
+        let mut callback = RawCallbackSystem::new(system);
+        let command = move |world: &mut World, cleanup: SystemCommandCleanup|
+        {
+            let Some(input) = take_input.run(world, ()) else { cleanup.run(world); return; };
+            let output = callback.run_with_cleanup(world, input, move |world: &mut World| cleanup.run(world));
+            (on_output)(world, output);
+        };
+        Self::with(command)
+    }
+
     /// Makes a new system command callback from a pre-defined callback.
     pub fn with(callback: impl FnMut(&mut World, SystemCommandCleanup) + Send + Sync + 'static) -> Self
     {
         Self{ inner: Box::new(callback) }
     }
 
+    /// Makes a new system command callback from a pre-defined callback that receives the id of its own
+    /// [`SystemCommand`].
+    pub(crate) fn with_self(
+        command      : SystemCommand,
+        mut callback : impl FnMut(SystemCommand, &mut World, SystemCommandCleanup) + Send + Sync + 'static
+    ) -> Self
+    {
+        Self::with(move |world: &mut World, cleanup: SystemCommandCleanup| (callback)(command, world, cleanup))
+    }
+
     /// Runs the system command callback.
     ///
     /// The `cleanup` should be invoked between running the callback's inner system and
@@ -90,13 +213,27 @@ impl SystemCommandCallback
 pub(crate) struct SystemCommandStorage
 {
     callback: Option<SystemCommandCallback>,
+    /// Number of times the callback has been run. See [`ReactionRunCount`].
+    run_count: u64,
 }
 
 impl SystemCommandStorage
 {
     pub(crate) fn new(callback: SystemCommandCallback) -> Self
     {
-        Self{ callback: Some(callback) }
+        Self{ callback: Some(callback), run_count: 0 }
+    }
+
+    /// Increments the run count, returning the new count.
+    pub(crate) fn increment_run_count(&mut self) -> u64
+    {
+        self.run_count += 1;
+        self.run_count
+    }
+
+    pub(crate) fn run_count(&self) -> u64
+    {
+        self.run_count
     }
 
     pub(crate) fn insert(&mut self, callback: SystemCommandCallback)
@@ -132,6 +269,24 @@ pub fn spawn_system_command_from(world: &mut World, callback: SystemCommandCallb
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Spawns a [`SystemCommand`] from a callback that receives the command's own id.
+///
+/// The id is the entity the command is stored on, so it is known before the callback is stored. This is useful for
+/// commands that need to reschedule or revoke themselves.
+///
+/// See [`SystemCommandCallback`] for how the [`SystemCommandCleanup`] should be used.
+pub fn spawn_system_command_self(
+    world    : &mut World,
+    callback : impl FnMut(SystemCommand, &mut World, SystemCommandCleanup) + Send + Sync + 'static
+) -> SystemCommand
+{
+    let command = SystemCommand(world.spawn_empty().id());
+    world.entity_mut(*command).insert(SystemCommandStorage::new(SystemCommandCallback::with_self(command, callback)));
+    command
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 //todo: allow overwriting an existing command's callback
 
 //-------------------------------------------------------------------------------------------------------------------
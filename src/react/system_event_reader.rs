@@ -21,26 +21,26 @@ pub(crate) struct SystemEventAccessTracker
     data_entity: Entity,
 
     /// Information cached for when the system actually runs.
-    prepared: Vec<(SystemCommand, Entity)>,
+    prepared: Vec<(SystemCommand, ReactionVersion, Entity)>,
 }
 
 impl SystemEventAccessTracker
 {
     /// Caches metadata for a system event.
-    pub(crate) fn prepare(&mut self, system: SystemCommand, data_entity: Entity)
+    pub(crate) fn prepare(&mut self, system: SystemCommand, version: ReactionVersion, data_entity: Entity)
     {
-        self.prepared.push((system, data_entity));
+        self.prepared.push((system, version, data_entity));
     }
 
     /// Sets metadata for the current entity reaction.
-    pub(crate) fn start(&mut self, reactor: SystemCommand)
+    pub(crate) fn start(&mut self, reactor: SystemCommand, version: ReactionVersion)
     {
-        let Some(pos) = self.prepared.iter().position(|(s, _)| *s == reactor) else {
+        let Some(pos) = self.prepared.iter().position(|(s, v, _)| *s == reactor && *v == version) else {
             tracing::error!("prepared system event is missing {:?}", reactor);
             debug_assert!(false);
             return;
         };
-        let (_, data_entity) = self.prepared.swap_remove(pos);
+        let (_, _, data_entity) = self.prepared.swap_remove(pos);
 
         debug_assert!(!self.currently_reacting);
         self.currently_reacting = true;
@@ -56,6 +56,12 @@ impl SystemEventAccessTracker
         self.data_entity
     }
 
+    /// Returns `true` if no reactions are running or prepared.
+    pub(crate) fn is_idle(&self) -> bool
+    {
+        !self.currently_reacting && self.prepared.is_empty()
+    }
+
     /// Returns `true` if a system event is currently being processed.
     fn is_reacting(&self) -> bool
     {
@@ -0,0 +1,130 @@
+//local shortcuts
+use crate::prelude::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+
+//standard shortcuts
+use std::time::Duration;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// When a reactor registered with [`ReactCommands::on_throttled`] runs.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub enum ThrottleMode
+{
+    /// A trigger runs the reactor immediately if the interval has elapsed since the last run. Triggers that arrive
+    /// before then are dropped.
+    #[default]
+    Leading,
+    /// Triggers never run the reactor immediately. Instead, they collapse into one pending run that executes in
+    /// [`First`] once the interval has elapsed since the last run.
+    ///
+    /// Trailing runs are not reactions, so reaction readers like [`BroadcastEvent`] will be empty.
+    Trailing,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Rate limit for reactors registered with [`ReactCommands::on_throttled`].
+///
+/// Time is measured with the [`Time`] resource.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Throttle
+{
+    /// Minimum time between runs of the reactor.
+    pub min_interval: Duration,
+    /// When the reactor runs.
+    pub mode: ThrottleMode,
+}
+
+impl Throttle
+{
+    /// Makes a [`ThrottleMode::Leading`] throttle.
+    pub fn leading(min_interval: Duration) -> Self
+    {
+        Self{ min_interval, mode: ThrottleMode::Leading }
+    }
+
+    /// Makes a [`ThrottleMode::Trailing`] throttle.
+    pub fn trailing(min_interval: Duration) -> Self
+    {
+        Self{ min_interval, mode: ThrottleMode::Trailing }
+    }
+}
+
+impl From<Duration> for Throttle
+{
+    fn from(min_interval: Duration) -> Self
+    {
+        Self::leading(min_interval)
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Throttle state stored on the [`SystemCommand`] entities of throttled reactors.
+#[derive(Component, Debug)]
+pub(crate) struct ReactorThrottle
+{
+    throttle: Throttle,
+    /// Time of the last run.
+    last_run: Option<Duration>,
+    /// A trailing run is waiting for the interval to elapse.
+    pending: bool,
+    /// A trailing run was scheduled and the next run should not be throttled.
+    flushing: bool,
+}
+
+impl ReactorThrottle
+{
+    pub(crate) fn new(throttle: Throttle) -> Self
+    {
+        Self{ throttle, last_run: None, pending: false, flushing: false }
+    }
+
+    /// Returns `true` if the interval has elapsed since the last run.
+    fn is_ready(&self, now: Duration) -> bool
+    {
+        self.last_run.is_none_or(|last_run| now.saturating_sub(last_run) >= self.throttle.min_interval)
+    }
+
+    /// Returns `true` if the reactor should run now, and records the run.
+    pub(crate) fn try_run(&mut self, now: Duration) -> bool
+    {
+        let run = self.flushing || match self.throttle.mode
+        {
+            ThrottleMode::Leading => self.is_ready(now),
+            ThrottleMode::Trailing =>
+            {
+                self.pending = true;
+                false
+            }
+        };
+        if !run { return false; }
+
+        self.flushing = false;
+        self.last_run = Some(now);
+        true
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Runs pending trailing runs of throttled reactors whose interval has elapsed.
+pub(crate) fn run_trailing_throttled_reactors(
+    mut c     : Commands,
+    time      : Res<Time>,
+    mut query : Query<(Entity, &mut ReactorThrottle)>
+){
+    let now = time.elapsed();
+    for (entity, mut throttle) in query.iter_mut()
+    {
+        if !throttle.pending || !throttle.is_ready(now) { continue; }
+        throttle.pending = false;
+        throttle.flushing = true;
+        c.queue(SystemCommand(entity));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,125 @@
+//local shortcuts
+use crate::prelude::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+//standard shortcuts
+use core::any::{type_name, Any, TypeId};
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A snapshot of a [`ReactResource`] that was accessed mutably in a transaction.
+struct ResourceSnapshot<R: ReactResource + Clone>(R);
+
+/// Type-erased transaction snapshot.
+trait TransactionSnapshot: Send + Sync + 'static
+{
+    fn as_any(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+    /// Writes the snapshot to the world without triggering reactions.
+    ///
+    /// Returns a callback that schedules mutation reactions for the snapshot's resource.
+    fn apply(self: Box<Self>, world: &mut World) -> fn(&mut ReactCommands);
+}
+
+impl<R: ReactResource + Clone> TransactionSnapshot for ResourceSnapshot<R>
+{
+    fn as_any(&self) -> &dyn Any
+    {
+        &self.0
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any
+    {
+        &mut self.0
+    }
+
+    fn apply(self: Box<Self>, world: &mut World) -> fn(&mut ReactCommands)
+    {
+        *world.react_resource_mut_noreact::<R>() = self.0;
+        |rc| rc.trigger_resource_mutation::<R>()
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Runs a transaction. See [`ReactWorldExt::transaction`].
+pub(crate) fn run_transaction<E>(
+    world : &mut World,
+    f     : impl FnOnce(&mut TransactionCtx) -> Result<(), E>
+) -> Result<(), E>
+{
+    // collect snapshots
+    let mut ctx = TransactionCtx{ world, snapshots: Vec::default(), indices: HashMap::default() };
+    (f)(&mut ctx)?;
+    let snapshots = ctx.snapshots;
+
+    // apply all snapshots before any reactions run, so reactors see the whole transaction
+    let triggers: Vec<fn(&mut ReactCommands)> = snapshots
+        .into_iter()
+        .map(|snapshot| snapshot.apply(world))
+        .collect();
+    world.react(|rc| triggers.into_iter().for_each(|trigger| (trigger)(rc)));
+
+    Ok(())
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Provides access to [`ReactResources`](ReactResource) in a transaction started with
+/// [`ReactWorldExt::transaction`].
+///
+/// Resources are cloned into a snapshot the first time they are accessed with [`Self::get_mut`], and all later
+/// accesses in the transaction use the snapshot. The world is not changed until the transaction commits.
+pub struct TransactionCtx<'w>
+{
+    world: &'w World,
+    /// Snapshots in order of first access.
+    snapshots: Vec<Box<dyn TransactionSnapshot>>,
+    /// Snapshot indices (resource type : index)
+    indices: HashMap<TypeId, usize>,
+}
+
+impl<'w> TransactionCtx<'w>
+{
+    /// Reads `R`, including changes made earlier in the transaction.
+    ///
+    /// Returns `None` if the resource doesn't exist.
+    pub fn get<R: ReactResource + Clone>(&self) -> Option<&R>
+    {
+        match self.indices.get(&TypeId::of::<R>())
+        {
+            Some(idx) => self.snapshots[*idx].as_any().downcast_ref::<R>(),
+            None      => self.world.get_react_resource::<R>(),
+        }
+    }
+
+    /// Mutably accesses a snapshot of `R`.
+    ///
+    /// The resource is cloned on first access. If the transaction commits, the snapshot replaces the resource and
+    /// resource mutation reactions are triggered for `R`.
+    ///
+    /// Returns `None` if the resource doesn't exist.
+    pub fn get_mut<R: ReactResource + Clone>(&mut self) -> Option<&mut R>
+    {
+        let idx = match self.indices.get(&TypeId::of::<R>())
+        {
+            Some(idx) => *idx,
+            None =>
+            {
+                let resource = self.world.get_react_resource::<R>()?.clone();
+                self.snapshots.push(Box::new(ResourceSnapshot(resource)));
+                self.indices.insert(TypeId::of::<R>(), self.snapshots.len() - 1);
+                self.snapshots.len() - 1
+            }
+        };
+
+        let snapshot = self.snapshots[idx].as_any_mut().downcast_mut::<R>();
+        debug_assert!(snapshot.is_some(), "transaction snapshot type mismatch for {}", type_name::<R>());
+        snapshot
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
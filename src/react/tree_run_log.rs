@@ -0,0 +1,50 @@
+//local shortcuts
+use crate::prelude::*;
+
+//third-party shortcuts
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+//standard shortcuts
+
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// System parameter for checking which [`SystemCommands`](SystemCommand) (e.g. reactors) ran in the current tree of
+/// system commands.
+///
+/// This is useful for coordinating reactors that respond to the same trigger. The log is cleared when the tree ends,
+/// so it only reflects the current tree. Outside a tree the log is empty.
+///
+/// Note that reactions to changes made outside system commands (e.g. in normal systems) each run in their own tree,
+/// so sibling reactors only see each other in the log if the trigger was fired from within a tree.
+///
+/*
+```rust
+let first = rc.on_revokable(broadcast::<Hit>(), play_hit_sound);
+rc.on(broadcast::<Hit>(),
+    move |log: TreeRunLog|
+    {
+        if !log.has_run(first.clone().into()) { play_fallback_sound(); }
+    }
+);
+```
+*/
+#[derive(SystemParam)]
+pub struct TreeRunLog<'w>
+{
+    tracker: Res<'w, SyscommandTracker>,
+}
+
+impl TreeRunLog<'_>
+{
+    /// Returns `true` if `command` started running in the current tree.
+    ///
+    /// System commands that are still running (including the current one) count as having run.
+    pub fn has_run(&self, command: SystemCommand) -> bool
+    {
+        self.tracker.has_run(command)
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,131 @@
+//local shortcuts
+use crate::prelude::*;
+
+//third-party shortcuts
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+//standard shortcuts
+
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Returns `true` if a reaction that fired for `fired` was scheduled by a trigger of type `registered`.
+///
+/// Reactions to component-wide triggers (e.g. [`mutation`]) report the entity that was changed, and broadcasts on a
+/// channel report the plain broadcast.
+fn trigger_matches(registered: ReactorType, fired: ReactorType) -> bool
+{
+    match (registered, fired)
+    {
+        (ReactorType::ComponentInsertion(a), ReactorType::EntityInsertion(_, b)) |
+        (ReactorType::ComponentMutation(a), ReactorType::EntityMutation(_, b)) |
+        (ReactorType::ComponentRemoval(a), ReactorType::EntityRemoval(_, b)) |
+        (ReactorType::AnyEntityEvent(a), ReactorType::EntityEvent(_, b)) |
+        (ReactorType::BroadcastChannel(a, _), ReactorType::Broadcast(b)) => a == b,
+        _ => registered == fired,
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// System parameter for reading which trigger scheduled the current reaction.
+///
+/// This is mainly useful for reactors registered with several triggers, such as with
+/// [`ReactCommands::once_race`].
+///
+/// Returns `None` when not used within a reactor run by a reaction.
+///
+/*
+```rust
+rc.once_race((broadcast::<Cancel>(), resource_mutation::<Timer>()),
+    |winner: WinningTrigger|
+    {
+        if winner.is(broadcast::<Cancel>()) { println!("cancelled"); }
+    }
+);
+```
+*/
+#[derive(SystemParam)]
+pub struct WinningTrigger<'w>
+{
+    tracker: Res<'w, SyscommandTracker>,
+}
+
+impl<'w> WinningTrigger<'w>
+{
+    /// Returns the trigger of the current reaction.
+    ///
+    /// Reactions to component-wide triggers report the entity-specific [`ReactorType`].
+    pub fn get(&self) -> Option<ReactorType>
+    {
+        self.tracker.current_trigger()
+    }
+
+    /// Returns `true` if the current reaction was scheduled by `trigger`.
+    pub fn is(&self, trigger: impl ReactionTrigger) -> bool
+    {
+        self.get().is_some_and(|fired| trigger_matches(trigger.reactor_type(), fired))
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// System parameter for reading all triggers registered for the current reactor.
+///
+/// The full set of triggers is returned regardless of which trigger scheduled the current run. Use
+/// [`WinningTrigger`] to find out which one fired.
+///
+/// Entity-specific triggers are reported with their entity (e.g. [`ReactorType::EntityMutation`]). Triggers are not
+/// in any particular order.
+///
+/*
+```rust
+rc.on((broadcast::<A>(), resource_mutation::<B>()),
+    |triggers: ReactorTriggers, winner: WinningTrigger|
+    {
+        println!("fired {:?}, registered for {:?}", winner.get(), triggers.get());
+    }
+);
+```
+*/
+#[derive(SystemParam)]
+pub struct ReactorTriggers<'w, 's>
+{
+    cache    : Res<'w, ReactCache>,
+    running  : Res<'w, SyscommandTracker>,
+    reactors : Query<'w, 's, &'static EntityReactors>,
+}
+
+impl<'w, 's> ReactorTriggers<'w, 's>
+{
+    /// Returns the triggers registered for the current reactor.
+    ///
+    /// Returns an empty list when not used within a system command, or if the reactor has no triggers (e.g. it was
+    /// revoked while running).
+    ///
+    /// This scans all registered triggers, so it is not intended for hot paths.
+    pub fn get(&self) -> Vec<ReactorType>
+    {
+        let Some(reactor) = self.running.current() else { return Vec::default(); };
+        let mut triggers = Vec::default();
+        let mut entities = Vec::default();
+        self.cache.collect_reactor_triggers(reactor, &mut triggers, &mut entities);
+
+        for entity in entities
+        {
+            let Ok(entity_reactors) = self.reactors.get(entity) else { continue; };
+            triggers.extend(entity_reactors.iter_reactor_rtypes(reactor).map(|rtype| rtype.reactor_type(entity)));
+        }
+
+        triggers
+    }
+
+    /// Returns `true` if `trigger` is registered for the current reactor.
+    pub fn contains(&self, trigger: impl ReactionTrigger) -> bool
+    {
+        self.get().contains(&trigger.reactor_type())
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
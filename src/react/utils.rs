@@ -2,12 +2,14 @@
 use crate::prelude::*;
 
 //third-party shortcuts
+use bevy::ecs::component::ComponentId;
+use bevy::ecs::world::DeferredWorld;
 use bevy::prelude::*;
 use smallvec::SmallVec;
 
 //standard shortcuts
 use core::any::TypeId;
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
@@ -28,15 +30,44 @@ pub fn schedule_removal_and_despawn_reactors(world: &mut World)
         cache.schedule_removal_reactions(world);
         cache.schedule_despawn_reactions(world);
     });
+    RemovalValueCaptures::flush(world);
     world.flush();
 }
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Asserts that all reactions have finished and no reaction state was leaked.
+///
+/// This is intended for tests. Call it after all reactions have been applied (e.g. after a `syscall` that triggers
+/// reactions returns) to catch regressions in how nested reactions are scheduled and cleaned up.
+///
+/// Panics if a tree of system commands is still running, if any reactions are prepared but haven't run, if event
+/// data entities are orphaned, or if batched reactions are pending.
+pub fn assert_reaction_tree_drained(world: &mut World)
+{
+    ReactCache::assert_tree_drained(world);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Gets the reactors with entity-specific triggers ([`entity_insertion`], [`entity_mutation`], [`entity_removal`],
+/// [`entity_event`]) that target `entity`.
+///
+/// Reads the reverse index maintained by the [`ReactPlugin`]. The order of the returned reactors is unspecified.
+pub fn reactors_targeting(world: &World, entity: Entity) -> Vec<SystemCommand>
+{
+    let Some(cache) = world.get_resource::<ReactCache>() else { return Vec::default(); };
+    cache.iter_entity_reactor_index(entity).collect()
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// The type of an entity reaction.
+///
+/// Read the type of the current entity reaction with [`EntityReactionSource`].
 //todo: switch to ComponentId when observers are integrated
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
-pub(crate) enum EntityReactionType
+pub enum EntityReactionType
 {
     /// A component was inserted.
     Insertion(TypeId),
@@ -48,10 +79,51 @@ pub(crate) enum EntityReactionType
     Event(TypeId),
 }
 
+impl EntityReactionType
+{
+    /// Converts to a [`ReactorType`] for an entity-specific reaction.
+    pub(crate) fn reactor_type(self, source: Entity) -> ReactorType
+    {
+        match self
+        {
+            Self::Insertion(id) => ReactorType::EntityInsertion(source, id),
+            Self::Mutation(id)  => ReactorType::EntityMutation(source, id),
+            Self::Removal(id)   => ReactorType::EntityRemoval(source, id),
+            Self::Event(id)     => ReactorType::EntityEvent(source, id),
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Mirrors a new [`EntityReactors`] component into the [`ReactCache`] reverse index.
+fn index_entity_reactors(mut world: DeferredWorld, entity: Entity, _: ComponentId)
+{
+    let Some(entity_reactors) = world.get::<EntityReactors>(entity) else { return; };
+    let reactors: Vec<SystemCommand> = entity_reactors.iter_reactors().collect();
+    let Some(mut cache) = world.get_resource_mut::<ReactCache>() else { return; };
+    for reactor in reactors
+    {
+        cache.index_entity_reactor(entity, reactor);
+    }
+}
+
+/// Removes an entity from the [`ReactCache`] reverse index when its [`EntityReactors`] component is replaced or
+/// removed (including on despawn).
+fn unindex_entity_reactors(mut world: DeferredWorld, entity: Entity, _: ComponentId)
+{
+    let Some(mut cache) = world.get_resource_mut::<ReactCache>() else { return; };
+    cache.unindex_entity(entity);
+}
+
 //-------------------------------------------------------------------------------------------------------------------
 
 /// Component that stores reactor handles that target a specific entity.
+///
+/// The reactors are mirrored in the [`ReactCache`] reverse index, which is updated by component hooks when the
+/// component is inserted/replaced/removed, and by the revocation paths when individual handles are removed.
 #[derive(Component)]
+#[component(on_insert = index_entity_reactors, on_replace = unindex_entity_reactors)]
 pub(crate) struct EntityReactors
 {
     reactors: SmallVec<[(EntityReactionType, ReactorHandle); ENTITY_REACTORS_STATIC_SIZE]>,
@@ -82,11 +154,47 @@ impl EntityReactors
             );
     }
 
+    /// Removes and returns the reactor handles for a reactor with the given reaction type.
+    pub(crate) fn take(&mut self, rtype: EntityReactionType, reactor_id: SystemCommand) -> Vec<ReactorHandle>
+    {
+        self.reactors
+            .drain_filter(|(reaction_type, handle)| *reaction_type == rtype && handle.sys_command() == reactor_id)
+            .map(|(_, handle)| handle)
+            .collect()
+    }
+
+    /// Removes all reactor handles for a reactor regardless of reaction type.
+    pub(crate) fn remove_all(&mut self, reactor_id: SystemCommand)
+    {
+        self.reactors.drain_filter(|(_, handle)| handle.sys_command() == reactor_id);
+    }
+
+    /// Removes all reactor handles.
+    pub(crate) fn clear(&mut self)
+    {
+        self.reactors.clear();
+    }
+
+    /// Returns `true` if any reactor handle for the reactor is stored.
+    pub(crate) fn contains(&self, reactor_id: SystemCommand) -> bool
+    {
+        self.iter_reactors().any(|reactor| reactor == reactor_id)
+    }
+
     pub(crate) fn count(&self, rtype: EntityReactionType) -> usize
     {
         self.iter_rtype(rtype).count()
     }
 
+    /// Iterates the reaction types registered for a reactor.
+    pub(crate) fn iter_reactor_rtypes(&self, reactor_id: SystemCommand) -> impl Iterator<Item = EntityReactionType> + '_
+    {
+        self.reactors
+            .iter()
+            .filter(move |(_, handle)| handle.sys_command() == reactor_id)
+            .map(|(rtype, _)| *rtype)
+    }
+
     pub(crate) fn iter_reactors(&self) -> impl Iterator<Item = SystemCommand> + '_
     {
         self.reactors
@@ -120,7 +228,39 @@ impl Default for EntityReactors
 
 //-------------------------------------------------------------------------------------------------------------------
 
-#[derive(Clone, Eq, PartialEq, Debug)]
+/// Identifies a broadcast channel.
+///
+/// Broadcasts sent with [`ReactCommands::broadcast_on_channel`] only reach reactors registered with a
+/// [`broadcast_channel`] trigger for the same event type and channel. Normal [`ReactCommands::broadcast`] events and
+/// [`broadcast`] triggers use [`ChannelId::DEFAULT`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+pub struct ChannelId(pub u64);
+
+impl ChannelId
+{
+    /// The channel used by un-scoped broadcasts.
+    pub const DEFAULT: Self = Self(0);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Controls how long a retained event stays readable by newly-registered reactors.
+///
+/// See [`ReactCommands::entity_event_retained`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum RetentionScope
+{
+    /// The event is retained until the end of the current tree of system commands.
+    Tree,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// The type of a reaction trigger.
+///
+/// New trigger types may be added (some only with crate features enabled), so this enum is non-exhaustive.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[non_exhaustive]
 pub enum ReactorType
 {
     EntityInsertion(Entity, TypeId),
@@ -132,8 +272,13 @@ pub enum ReactorType
     ComponentMutation(TypeId),
     ComponentRemoval(TypeId),
     ResourceMutation(TypeId),
+    BevyResourceChanged(TypeId),
     Broadcast(TypeId),
+    BroadcastChannel(TypeId, ChannelId),
+    BroadcastTopic(TypeId),
     Despawn(Entity),
+    #[cfg(feature = "bevy_asset")]
+    AssetLoaded(bevy::asset::UntypedAssetId),
 }
 
 impl ReactorType
@@ -153,7 +298,92 @@ impl ReactorType
             Self::ComponentMutation(_) |
             Self::ComponentRemoval(_) |
             Self::ResourceMutation(_) |
-            Self::Broadcast(_) => None,
+            Self::BevyResourceChanged(_) |
+            Self::Broadcast(_) |
+            Self::BroadcastChannel(_, _) |
+            Self::BroadcastTopic(_) => None,
+            #[cfg(feature = "bevy_asset")]
+            Self::AssetLoaded(_) => None,
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Identifies one scheduled run of a reactor.
+///
+/// Reaction metadata is prepared before the reactor runs, and reactors that are already running are deferred until
+/// they finish. If the same reactor is scheduled multiple times while running (possibly at different levels of
+/// recursion), then each prepared entry is tagged with a unique version so the deferred run reads its own data
+/// instead of data prepared for a different run.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub(crate) struct ReactionVersion(u64);
+
+impl ReactionVersion
+{
+    /// Returns the next version.
+    pub(crate) fn next(self) -> Self
+    {
+        Self(self.0.wrapping_add(1))
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Describes the trigger that scheduled a reaction, for diagnostics.
+///
+/// Type names are captured where the trigger's type is known (at registration or when scheduling the reaction) so
+/// error messages don't need to print raw [`TypeId`]s.
+#[derive(Debug, Copy, Clone, Default)]
+pub(crate) struct ReactionTriggerInfo
+{
+    reactor_type: Option<ReactorType>,
+    type_name: Option<&'static str>,
+}
+
+impl ReactionTriggerInfo
+{
+    pub(crate) fn new(reactor_type: ReactorType, type_name: &'static str) -> Self
+    {
+        Self{ reactor_type: Some(reactor_type), type_name: Some(type_name) }
+    }
+
+    pub(crate) fn despawn(entity: Entity) -> Self
+    {
+        Self{ reactor_type: Some(ReactorType::Despawn(entity)), type_name: None }
+    }
+
+    pub(crate) fn reactor_type(&self) -> Option<ReactorType>
+    {
+        self.reactor_type
+    }
+}
+
+impl std::fmt::Display for ReactionTriggerInfo
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        let Some(reactor_type) = self.reactor_type else { return f.write_str("none"); };
+        let name = self.type_name.unwrap_or("<unknown>");
+
+        match reactor_type
+        {
+            ReactorType::EntityInsertion(entity, _)  => write!(f, "insertion of {name} on {entity}"),
+            ReactorType::EntityMutation(entity, _)   => write!(f, "mutation of {name} on {entity}"),
+            ReactorType::EntityRemoval(entity, _)    => write!(f, "removal of {name} from {entity}"),
+            ReactorType::EntityEvent(entity, _)      => write!(f, "entity event {name} targeting {entity}"),
+            ReactorType::AnyEntityEvent(_)           => write!(f, "entity event {name}"),
+            ReactorType::ComponentInsertion(_)       => write!(f, "insertion of {name}"),
+            ReactorType::ComponentMutation(_)        => write!(f, "mutation of {name}"),
+            ReactorType::ComponentRemoval(_)         => write!(f, "removal of {name}"),
+            ReactorType::ResourceMutation(_)         => write!(f, "mutation of resource {name}"),
+            ReactorType::BevyResourceChanged(_)      => write!(f, "change of resource {name}"),
+            ReactorType::Broadcast(_)                => write!(f, "broadcast {name}"),
+            ReactorType::BroadcastChannel(_, channel) => write!(f, "broadcast {name} on channel {}", channel.0),
+            ReactorType::BroadcastTopic(_)           => write!(f, "broadcast {name} to a topic"),
+            ReactorType::Despawn(entity)             => write!(f, "despawn of {entity}"),
+            #[cfg(feature = "bevy_asset")]
+            ReactorType::AssetLoaded(id)             => write!(f, "load of asset {id} ({name})"),
         }
     }
 }
@@ -205,6 +435,37 @@ impl RevokeToken
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// RAII guard for a reactor registered with [`ReactCommands::on_scoped_handle`].
+///
+/// Dropping the guard revokes the reactor. Since the guard can't access the world, it holds an [`AutoDespawnSignal`]
+/// for the reactor entity. The reactor is despawned and its triggers are revoked the next time entities are garbage
+/// collected (in the `Last` schedule or the next time a reaction tree runs), so it may still react until then.
+pub struct ReactorGuard
+{
+    pub(crate) id     : SystemCommand,
+    /// Filled with the reactor's despawn signal when the reactor is registered.
+    pub(crate) signal : Arc<OnceLock<AutoDespawnSignal>>,
+}
+
+impl ReactorGuard
+{
+    /// Returns the guarded reactor.
+    pub fn id(&self) -> SystemCommand
+    {
+        self.id
+    }
+}
+
+impl std::fmt::Debug for ReactorGuard
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result
+    {
+        f.debug_tuple("ReactorGuard").field(&self.id).finish()
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// Handle for managing a reactor within the react backend.
 #[derive(Clone)]
 pub enum ReactorHandle
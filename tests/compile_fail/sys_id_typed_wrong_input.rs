@@ -0,0 +1,11 @@
+use bevy::prelude::*;
+use bevy_cobweb::prelude::*;
+
+fn main()
+{
+    let mut world = World::new();
+    let sys_id = spawn_system(&mut world, |In(input): In<u16>| input);
+
+    // wrong input type
+    let _ = spawned_syscall(&mut world, sys_id, "hello");
+}
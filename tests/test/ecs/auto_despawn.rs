@@ -124,3 +124,114 @@ fn auto_despawn_multiple()
 }
 
 //-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Resource, Default)]
+struct CallbackCount(usize);
+
+#[test]
+fn auto_despawn_with_callback()
+{
+    let mut app = App::new();
+    app.setup_auto_despawn()
+        .init_resource::<CallbackCount>();
+
+    // add entity
+    let entity = app.world_mut().spawn(TestComponent).id();
+    let handle = app.world().resource::<AutoDespawner>().prepare_with_callback(entity,
+        move |world: &mut World|
+        {
+            assert!(world.get_entity(entity).is_err());
+            world.resource_mut::<CallbackCount>().0 += 1;
+        }
+    );
+    let handle_clone = handle.clone();
+
+    // drop one handle (no despawn)
+    std::mem::drop(handle);
+    app.update();
+    assert_eq!(syscall(app.world_mut(), (), count_entities), 1);
+    assert_eq!(app.world().resource::<CallbackCount>().0, 0);
+
+    // drop last handle (despawn and callback)
+    std::mem::drop(handle_clone);
+    app.update();
+    assert_eq!(syscall(app.world_mut(), (), count_entities), 0);
+    assert_eq!(app.world().resource::<CallbackCount>().0, 1);
+
+    // callback only runs once
+    app.update();
+    assert_eq!(app.world().resource::<CallbackCount>().0, 1);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn auto_despawn_outstanding_report()
+{
+    let mut app = App::new();
+    app.setup_auto_despawn();
+    let world = app.world_mut();
+    let a = world.spawn_empty().id();
+    let b = world.spawn_empty().id();
+    let c = world.spawn_empty().id();
+
+    // prepare signals
+    let despawner = world.resource::<AutoDespawner>().clone();
+    let signal_a = despawner.prepare(a);
+    let signal_a_copy = signal_a.clone();
+    let signal_b = despawner.prepare(b);
+    let signal_b2 = despawner.prepare(b);
+    let signal_c = despawner.prepare(c);
+    assert_eq!(despawner.outstanding(), vec![(a, 2), (b, 2), (c, 1)]);
+
+    // drop some signals
+    drop(signal_a);
+    drop(signal_b2);
+    drop(signal_c);
+    assert_eq!(despawner.outstanding(), vec![(a, 1), (b, 1)]);
+
+    // the report is shared between despawner clones
+    drop(signal_a_copy);
+    assert_eq!(world.resource::<AutoDespawner>().outstanding(), vec![(b, 1)]);
+    drop(signal_b);
+    assert!(despawner.outstanding().is_empty());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn auto_despawn_manual_processing()
+{
+    // the app is never updated, so despawns are only processed manually
+    let mut app = App::new();
+    app.setup_auto_despawn();
+    let world = app.world_mut();
+    let despawner = world.resource::<AutoDespawner>().clone();
+    let a = world.spawn(TestComponent).id();
+    let b = world.spawn(TestComponent).id();
+    let signal_a = despawner.prepare(a);
+    let signal_b = despawner.prepare(b);
+
+    // nothing to process while signals are alive
+    assert_eq!(despawner.process_despawns(world), 0);
+    assert_eq!(syscall(world, (), count_entities), 2);
+
+    // dropping signals doesn't despawn until processed
+    drop(signal_a);
+    drop(signal_b);
+    assert_eq!(syscall(world, (), count_entities), 2);
+    assert_eq!(despawner.process_despawns(world), 2);
+    assert_eq!(syscall(world, (), count_entities), 0);
+
+    // processing again is a no-op
+    assert_eq!(despawner.process_despawns(world), 0);
+
+    // already-despawned entities are not counted
+    let c = world.spawn(TestComponent).id();
+    let signal_c = despawner.prepare(c);
+    world.despawn(c);
+    drop(signal_c);
+    assert_eq!(despawner.process_despawns(world), 0);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -1,2 +1,4 @@
 //test modules
 mod auto_despawn;
+mod spawned_syscall;
+mod syscall;
@@ -0,0 +1,84 @@
+//local shortcuts
+use bevy_cobweb::prelude::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+
+//standard shortcuts
+
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn counter(In(input): In<u16>, mut local: Local<u16>) -> u16
+{
+    *local += input;
+    *local
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn despawn_spawned_system()
+{
+    let mut world = World::new();
+
+    let sys_id = spawn_system(&mut world, counter);
+    assert_eq!(spawned_syscall::<In<u16>, u16>(&mut world, sys_id, 1), Ok(1));
+
+    // despawn
+    assert!(world.despawn_system(sys_id));
+    assert_eq!(spawned_syscall::<In<u16>, u16>(&mut world, sys_id, 1), Err(()));
+
+    // despawning again does nothing
+    assert!(!world.despawn_system(sys_id));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn despawn_spawned_system_with_commands()
+{
+    let mut world = World::new();
+
+    let sys_id = world.syscall((), |mut c: Commands| c.spawn_system(counter));
+    assert_eq!(spawned_syscall::<In<u16>, u16>(&mut world, sys_id, 1), Ok(1));
+
+    // despawn
+    world.syscall(sys_id.untyped(), |In(sys_id): In<SysId>, mut c: Commands| c.despawn_system(sys_id));
+    assert_eq!(spawned_syscall::<In<u16>, u16>(&mut world, sys_id, 1), Err(()));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn typed_spawned_syscall()
+{
+    let mut world = World::new();
+
+    // input and output types are inferred from the typed id
+    let sys_id = spawn_system(&mut world, counter);
+    assert_eq!(spawned_syscall(&mut world, sys_id, 1), Ok(1));
+    assert_eq!(spawned_syscall(&mut world, sys_id, 2), Ok(3));
+
+    // the untyped id references the same system
+    let untyped: SysId = sys_id.into();
+    assert_eq!(untyped, sys_id.untyped());
+    assert_eq!(spawned_syscall::<In<u16>, u16>(&mut world, untyped, 1), Ok(4));
+
+    assert!(world.despawn_system(sys_id));
+    assert_eq!(spawned_syscall(&mut world, sys_id, 1), Err(()));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// Passing the wrong input type with a typed id is a compile error.
+#[test]
+fn typed_spawned_syscall_rejects_wrong_input()
+{
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/compile_fail/sys_id_typed_wrong_input.rs");
+}
+
+//-------------------------------------------------------------------------------------------------------------------
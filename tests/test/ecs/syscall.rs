@@ -0,0 +1,149 @@
+//local shortcuts
+use bevy_cobweb::prelude::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+
+//standard shortcuts
+use std::time::Duration;
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Resource, Debug, PartialEq)]
+struct TestSetting(u16);
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn read_setting(In(offset): In<u16>, mut setting: ResMut<TestSetting>) -> u16
+{
+    setting.0 += offset;
+    setting.0
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn counter(In(input): In<u16>, mut local: Local<u16>) -> u16
+{
+    *local += input;
+    *local
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn sleepy_counter(In(input): In<u16>, mut local: Local<u16>) -> u16
+{
+    std::thread::sleep(Duration::from_millis(1));
+    *local += input;
+    *local
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn syscall_timed_returns_output_and_duration()
+{
+    let mut world = World::new();
+
+    let (output, elapsed) = world.syscall_timed(1u16, sleepy_counter);
+    assert_eq!(output, 1);
+    assert!(elapsed >= Duration::from_millis(1));
+
+    // the cached system is shared with `syscall`
+    assert_eq!(world.syscall(1u16, sleepy_counter), 2);
+    let (output, elapsed) = world.syscall_timed(10u16, sleepy_counter);
+    assert_eq!(output, 12);
+    assert!(elapsed > Duration::ZERO);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn syscall_with_resource_restores_previous()
+{
+    let mut world = World::new();
+
+    // absent before: removed after
+    assert_eq!(world.syscall_with_resource(TestSetting(5), 1u16, read_setting), 6);
+    assert!(!world.contains_resource::<TestSetting>());
+
+    // present before: restored after (temporary mutations are dropped)
+    world.insert_resource(TestSetting(1));
+    assert_eq!(world.syscall_with_resource(TestSetting(10), 1u16, read_setting), 11);
+    assert_eq!(*world.resource::<TestSetting>(), TestSetting(1));
+    assert_eq!(world.syscall(1u16, read_setting), 2);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn cached_system_persists_state()
+{
+    const CALLS: u16 = 1000;
+    let mut world = World::new();
+
+    // state is owned by each cached system, not shared with `syscall`
+    let mut cached = CachedSystem::new(&mut world, counter);
+    let mut other = CachedSystem::new(&mut world, counter);
+    assert_eq!(cached.run(&mut world, 1), 1);
+    assert_eq!(other.run(&mut world, 10), 10);
+    assert_eq!(world.syscall(100u16, counter), 100);
+
+    // compare overheads (informational only, timings are not asserted)
+    let start = std::time::Instant::now();
+    for _ in 1..CALLS { cached.run(&mut world, 1); }
+    let cached_elapsed = start.elapsed();
+    let start = std::time::Instant::now();
+    for _ in 1..CALLS { world.syscall(1u16, counter); }
+    let syscall_elapsed = start.elapsed();
+    println!("{CALLS} calls: CachedSystem {cached_elapsed:?}, syscall {syscall_elapsed:?}");
+
+    assert_eq!(cached.run(&mut world, 0), CALLS);
+    assert_eq!(world.syscall(0u16, counter), 100 + CALLS - 1);
+    assert_eq!(other.run(&mut world, 0), 10);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn syscall_scoped_consumes_borrowed_output()
+{
+    let mut world = World::new();
+    world.spawn(Name::new("a"));
+    world.spawn(Name::new("bcd"));
+    world.insert_resource(TestSetting(1));
+
+    // borrowed query results are processed without cloning
+    let longest = world.syscall_scoped::<Query<&Name>, _, _>(0usize,
+        |min, names|
+        {
+            let longest: &str = names.iter()
+                .map(|n| n.as_str())
+                .filter(|n| n.len() > min)
+                .max_by_key(|n| n.len())
+                .unwrap();
+            longest.to_string()
+        });
+    assert_eq!(longest, "bcd");
+
+    // deferred commands are applied after the callback
+    let count = world.syscall_scoped::<(Commands, ResMut<TestSetting>), _, _>(5u16,
+        |val, (mut c, mut setting)|
+        {
+            setting.0 += val;
+            c.spawn(Name::new("e"));
+            setting.0
+        });
+    assert_eq!(count, 6);
+    assert_eq!(world.query::<&Name>().iter(&world).count(), 3);
+
+    // locals persist across calls with the same params
+    let counts: Vec<u16> = (0..3)
+        .map(|_| world.syscall_scoped::<Local<u16>, _, _>((), |(), mut local| { *local += 1; *local }))
+        .collect();
+    assert_eq!(counts, vec![1, 2, 3]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
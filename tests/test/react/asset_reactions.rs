@@ -0,0 +1,102 @@
+//local shortcuts
+use bevy_cobweb::prelude::*;
+use crate::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+
+//standard shortcuts
+
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Asset, TypePath)]
+struct TestAsset(usize);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn on_asset_loaded(In(id): In<AssetId<TestAsset>>, mut c: Commands) -> RevokeToken
+{
+    c.react().on_revokable(asset_loaded(id),
+        |event: BroadcastEvent<AssetId<TestAsset>>, assets: Res<Assets<TestAsset>>, mut recorder: ResMut<TestReactRecorder>|
+        {
+            let id = event.read();
+            recorder.0 += assets.get(*id).unwrap().0;
+        }
+    )
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn send_loaded(app: &mut App, id: AssetId<TestAsset>)
+{
+    app.world_mut().send_event(AssetEvent::LoadedWithDependencies{ id });
+    app.update();
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn asset_loaded_reaction()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TestReactRecorder>()
+        .init_resource::<Assets<TestAsset>>()
+        .add_event::<AssetEvent<TestAsset>>();
+
+    // add reactor
+    let handle = app.world_mut().resource_mut::<Assets<TestAsset>>().reserve_handle();
+    let other = app.world_mut().resource_mut::<Assets<TestAsset>>().reserve_handle();
+    let token = app.world_mut().syscall(handle.id(), on_asset_loaded);
+    assert_eq!(app.world().resource::<TestReactRecorder>().0, 0);
+
+    // load asset (reaction)
+    app.world_mut().resource_mut::<Assets<TestAsset>>().insert(&handle, TestAsset(1));
+    app.world_mut().resource_mut::<Assets<TestAsset>>().insert(&other, TestAsset(10));
+    send_loaded(&mut app, handle.id());
+    assert_eq!(app.world().resource::<TestReactRecorder>().0, 1);
+
+    // load other asset (no reaction)
+    send_loaded(&mut app, other.id());
+    assert_eq!(app.world().resource::<TestReactRecorder>().0, 1);
+
+    // reload asset (reaction)
+    send_loaded(&mut app, handle.id());
+    assert_eq!(app.world().resource::<TestReactRecorder>().0, 2);
+
+    // revoke
+    app.world_mut().syscall(token, revoke_reactor);
+    send_loaded(&mut app, handle.id());
+    assert_eq!(app.world().resource::<TestReactRecorder>().0, 2);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn asset_already_loaded_reacts_immediately()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TestReactRecorder>()
+        .init_resource::<Assets<TestAsset>>()
+        .add_event::<AssetEvent<TestAsset>>();
+
+    // load asset before registering
+    let handle = app.world_mut().resource_mut::<Assets<TestAsset>>().add(TestAsset(5));
+    app.world_mut().send_event(AssetEvent::LoadedWithDependencies{ id: handle.id() });
+
+    // add reactor (immediate reaction)
+    app.world_mut().syscall(handle.id(), on_asset_loaded);
+    assert_eq!(app.world().resource::<TestReactRecorder>().0, 5);
+
+    // stale load event is ignored (no reaction)
+    app.update();
+    assert_eq!(app.world().resource::<TestReactRecorder>().0, 5);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
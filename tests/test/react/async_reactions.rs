@@ -0,0 +1,99 @@
+//local shortcuts
+use bevy_cobweb::prelude::*;
+use crate::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+
+//standard shortcuts
+
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn register_sum_reactor(world: &mut World) -> RevokeToken
+{
+    world.react(|rc| rc.on_async(broadcast::<IntEvent>(),
+        |event: BroadcastEvent<IntEvent>| event.read().0,
+        |n: usize| (0..=n).sum::<usize>(),
+        |In(sum): In<usize>, mut c: Commands|
+        {
+            c.react().broadcast(sum);
+        }
+    ))
+}
+
+/// Updates the app until `done` or the update budget runs out.
+fn update_until(app: &mut App, done: impl Fn(&World) -> bool)
+{
+    for _ in 0..1000
+    {
+        app.update();
+        if done(app.world()) { return; }
+        std::thread::sleep(std::time::Duration::from_millis(1));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// Async reactors compute off-thread and apply their results in a new reaction tree.
+#[test]
+fn async_reaction_applies_result()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TelescopeHistory>();
+    let world = app.world_mut();
+
+    // add reactors
+    register_sum_reactor(world);
+    world.react(|rc| rc.on(broadcast::<usize>(),
+        |event: BroadcastEvent<usize>, mut history: ResMut<TelescopeHistory>| history.push(*event.read())
+    ));
+
+    // trigger: nothing is applied until the task finishes and is polled
+    world.broadcast(IntEvent(4));
+    assert!(world.resource::<TelescopeHistory>().is_empty());
+
+    // the result is applied, and reactions to it run
+    update_until(&mut app, |world| !world.resource::<TelescopeHistory>().is_empty());
+    assert_eq!(**app.world().resource::<TelescopeHistory>(), vec![10]);
+    assert_reaction_tree_drained(app.world_mut());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// Revoking an async reactor cancels its in-flight tasks.
+#[test]
+fn async_reaction_revoked()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TelescopeHistory>();
+    let world = app.world_mut();
+
+    // add reactors
+    let token = register_sum_reactor(world);
+    let reactor = SystemCommand::from(token.clone());
+    world.react(|rc| rc.on(broadcast::<usize>(),
+        |event: BroadcastEvent<usize>, mut history: ResMut<TelescopeHistory>| history.push(*event.read())
+    ));
+
+    // trigger then revoke before the result is applied
+    world.broadcast(IntEvent(4));
+    world.react(|rc| rc.revoke(token));
+
+    // the result is never applied
+    for _ in 0..10
+    {
+        app.update();
+        std::thread::sleep(std::time::Duration::from_millis(1));
+    }
+    assert!(app.world().get_entity(*reactor).is_err());
+    assert!(app.world().resource::<TelescopeHistory>().is_empty());
+    assert_reaction_tree_drained(app.world_mut());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -899,3 +899,1295 @@ fn revoke_component_mutation_reactor()
 }
 
 //-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+#[should_panic(expected = "there is no event (current trigger: mutation of tests::common::react::TestComponent on")]
+fn reader_panic_reports_current_trigger()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin);
+    let world = app.world_mut();
+
+    // entity
+    let test_entity = world.spawn_empty().id();
+    world.syscall((test_entity, TestComponent(0)), insert_on_test_entity);
+
+    // add reactor that reads the wrong event type
+    world.syscall((),
+        |mut c: Commands|
+        {
+            c.react().on(mutation::<TestComponent>(),
+                |insertion: InsertionEvent<TestComponent>|
+                {
+                    let _ = insertion.entity();
+                }
+            );
+        }
+    );
+
+    // update (reaction panics)
+    world.syscall((test_entity, TestComponent(1)), update_test_entity);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// Readers are checked against the reactor's triggers when registering with react_on!.
+#[test]
+fn react_on_accepts_matching_readers()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin);
+    let world = app.world_mut();
+    let test_entity = world.spawn_empty().id();
+
+    // add reactors whose readers match at least one trigger
+    world.react(|rc| react_on!(rc, (mutation::<TestComponent>(), broadcast::<IntEvent>()),
+        |mutation: MutationEvent<TestComponent>, event: BroadcastEvent<IntEvent>, mut c: Commands|
+        {
+            let _ = (mutation.get(), event.try_read());
+            c.react().broadcast(());
+        }
+    ));
+    world.react(|rc| react_on!(rc, entity_insertion::<TestComponent>(test_entity),
+        move |insertion: InsertionEvent<TestComponent>|
+        {
+            assert_eq!(insertion.entity(), test_entity);
+        }
+    ));
+    world.react(|rc| react_on!(rc, broadcast::<()>(), || {}));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "reader MutationEvent<TestComponent> (Mutation(")]
+fn react_on_catches_mismatched_reader()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin);
+    let world = app.world_mut();
+
+    // add reactor that reads the wrong event type (panics)
+    world.react(|rc| react_on!(rc, insertion::<TestComponent>(),
+        |mut c: Commands, mutation: MutationEvent<TestComponent>|
+        {
+            let _ = mutation.get();
+            c.react().broadcast(());
+        }
+    ));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(ReactComponent, PartialEq)]
+struct ChangeTracked(usize);
+
+#[derive(Resource, Default)]
+struct ChangedCount(usize);
+
+fn count_changed(mut count: ResMut<ChangedCount>, changed: Query<(), Changed<React<ChangeTracked>>>)
+{
+    count.0 = changed.iter().count();
+}
+
+#[test]
+fn reactive_mutation_marks_bevy_changed()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<ChangedCount>()
+        .add_systems(Update, count_changed);
+
+    // entity (changed on insert)
+    let test_entity = app.world_mut().spawn_empty().id();
+    app.world_mut().syscall(test_entity, |In(entity): In<Entity>, mut c: Commands| {
+        c.react().insert(entity, ChangeTracked(0));
+    });
+    app.update();
+    assert_eq!(app.world().resource::<ChangedCount>().0, 1);
+
+    // no mutation (not changed)
+    app.update();
+    assert_eq!(app.world().resource::<ChangedCount>().0, 0);
+
+    // reactive mutation (changed)
+    app.world_mut().syscall(test_entity, |In(entity): In<Entity>, mut c: Commands, mut q: ReactiveMut<ChangeTracked>| {
+        q.get_mut(&mut c, entity).unwrap().0 = 1;
+    });
+    app.update();
+    assert_eq!(app.world().resource::<ChangedCount>().0, 1);
+
+    // reactive set to the same value (not changed)
+    app.world_mut().syscall(test_entity, |In(entity): In<Entity>, mut c: Commands, mut q: ReactiveMut<ChangeTracked>| {
+        assert!(q.set_if_neq(&mut c, entity, ChangeTracked(1)).is_none());
+        assert!(q.set_single_if_not_eq(&mut c, ChangeTracked(1)).1.is_none());
+    });
+    app.update();
+    assert_eq!(app.world().resource::<ChangedCount>().0, 0);
+
+    // reactive set to a new value (changed)
+    app.world_mut().syscall(test_entity, |In(entity): In<Entity>, mut c: Commands, mut q: ReactiveMut<ChangeTracked>| {
+        assert!(q.set_if_neq(&mut c, entity, ChangeTracked(2)).is_some());
+    });
+    app.update();
+    assert_eq!(app.world().resource::<ChangedCount>().0, 1);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn mutate_test_entities(In(entities): In<Vec<Entity>>, mut c: Commands)
+{
+    let command = c.spawn_system_command(
+        move |mut c: Commands, mut components: ReactiveMut<TestComponent>|
+        {
+            for entity in entities.iter()
+            {
+                components.get_mut(&mut c, *entity).unwrap().0 += 1;
+            }
+        }
+    );
+    c.queue(command);
+}
+
+#[test]
+fn batched_mutation_reactor()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TelescopeHistory>();
+    let world = app.world_mut();
+
+    // entities
+    let e1 = world.spawn_empty().id();
+    let e2 = world.spawn_empty().id();
+    let e3 = world.spawn_empty().id();
+    world.syscall((e1, TestComponent(0)), insert_on_test_entity);
+    world.syscall((e2, TestComponent(0)), insert_on_test_entity);
+    world.syscall((e3, TestComponent(0)), insert_on_test_entity);
+
+    // add batched reactor
+    // - On the first batch, mutate an entity again (follow-up batch).
+    let token = world.syscall((),
+        move |mut c: Commands|
+        {
+            c.react().on_batched::<TestComponent, _>(
+                move
+                |
+                    In(entities): In<Vec<Entity>>,
+                    mut c: Commands,
+                    mut history: ResMut<TelescopeHistory>,
+                    mut components: ReactiveMut<TestComponent>
+                |
+                {
+                    history.push(entities.len());
+                    if history.len() == 1 { components.get_mut(&mut c, e1).unwrap().0 += 1; }
+                }
+            )
+        }
+    );
+
+    // mutate in one tree (one batch with deduplicated entities, then a follow-up batch)
+    world.syscall(vec![e1, e2, e1, e3], mutate_test_entities);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![3, 1]);
+
+    // mutate in user-land (one batch per mutation)
+    world.syscall((e2, TestComponent(5)), update_test_entity);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![3, 1, 1]);
+
+    // revoke
+    world.syscall(token, revoke_reactor);
+    world.syscall(vec![e1, e2], mutate_test_entities);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![3, 1, 1]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn entity_component_lifecycle()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TelescopeHistory>();
+    let world = app.world_mut();
+    let test_entity = world.spawn_empty().id();
+
+    // add reactor
+    world.syscall(test_entity,
+        |In(entity): In<Entity>, mut c: Commands|
+        {
+            c.react().on(entity_component::<TestComponent>(entity),
+                |
+                    insertion     : InsertionEvent<TestComponent>,
+                    mutation      : MutationEvent<TestComponent>,
+                    removal       : RemovalEvent<TestComponent>,
+                    despawn       : DespawnEvent,
+                    mut history   : ResMut<TelescopeHistory>
+                |
+                {
+                    if !insertion.is_empty() { history.push(1); }
+                    if !mutation.is_empty() { history.push(2); }
+                    if !removal.is_empty() { history.push(3); }
+                    if !despawn.is_empty() { history.push(4); }
+                }
+            );
+        }
+    );
+
+    // insert, mutate, remove
+    world.syscall((test_entity, TestComponent(1)), insert_on_test_entity);
+    world.syscall((test_entity, TestComponent(2)), update_test_entity);
+    world.syscall(test_entity, remove_from_test_entity);
+    schedule_removal_and_despawn_reactors(world);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1, 2, 3]);
+
+    // despawn
+    world.despawn(test_entity);
+    schedule_removal_and_despawn_reactors(world);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1, 2, 3, 4]);
+
+    // one token revokes all triggers
+    let test_entity = world.spawn_empty().id();
+    let token = world.syscall(test_entity,
+        |In(entity): In<Entity>, mut c: Commands| -> RevokeToken
+        {
+            c.react().on_revokable(entity_component::<TestComponent>(entity),
+                |mut history: ResMut<TelescopeHistory>| history.push(5)
+            )
+        }
+    );
+    world.syscall(token, revoke_reactor);
+    world.syscall((test_entity, TestComponent(1)), insert_on_test_entity);
+    world.syscall((test_entity, TestComponent(2)), update_test_entity);
+    world.syscall(test_entity, remove_from_test_entity);
+    world.despawn(test_entity);
+    schedule_removal_and_despawn_reactors(world);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1, 2, 3, 4]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn prune_entity_reactors_then_despawn()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TelescopeHistory>();
+    let world = app.world_mut();
+    let test_entity = world.spawn_empty().id();
+    world.syscall((test_entity, TestComponent(0)), insert_on_test_entity);
+
+    // add reactors
+    // - one with a non-entity trigger, one with only an entity trigger, and a despawn reactor
+    let (mixed, entity_only) = world.syscall(test_entity,
+        |In(entity): In<Entity>, mut c: Commands| -> (RevokeToken, RevokeToken)
+        {
+            let mixed = c.react().on_revokable((entity_mutation::<TestComponent>(entity), broadcast::<IntEvent>()),
+                |mut history: ResMut<TelescopeHistory>| history.push(1)
+            );
+            let entity_only = c.react().on_revokable(entity_mutation::<TestComponent>(entity),
+                |mut history: ResMut<TelescopeHistory>| history.push(2)
+            );
+            c.react().on(despawn(entity), |mut history: ResMut<TelescopeHistory>| history.push(3));
+            (mixed, entity_only)
+        }
+    );
+    world.syscall((test_entity, TestComponent(1)), update_test_entity);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1, 2]);
+
+    // prune (no entity reactions)
+    world.react(|rc| rc.prune_entity_reactors(test_entity));
+    world.syscall((test_entity, TestComponent(2)), update_test_entity);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1, 2]);
+
+    // the mixed reactor keeps its other trigger, the entity-only reactor is cleaned up
+    world.syscall(0, send_broadcast);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1, 2, 1]);
+    garbage_collect_entities(world);
+    assert!(world.get_entity(*SystemCommand::from(mixed)).is_ok());
+    assert!(world.get_entity(*SystemCommand::from(entity_only)).is_err());
+
+    // despawn (no despawn reaction)
+    world.despawn(test_entity);
+    schedule_removal_and_despawn_reactors(world);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1, 2, 1]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(ReactComponent, PartialEq, Eq, PartialOrd, Ord)]
+struct Priority(usize);
+
+fn set_priorities(In(priorities): In<Vec<(Entity, usize)>>, mut c: Commands)
+{
+    let command = c.spawn_system_command(
+        move |mut c: Commands, mut components: ReactiveMut<Priority>|
+        {
+            for (entity, priority) in priorities.iter()
+            {
+                components.get_mut(&mut c, *entity).unwrap().0 = *priority;
+            }
+        }
+    );
+    c.queue(command);
+}
+
+#[test]
+fn sorted_mutation_reactor()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TelescopeHistory>();
+    let world = app.world_mut();
+
+    // entities
+    let entities: Vec<Entity> = (0..4).map(|_| world.spawn_empty().id()).collect();
+    for entity in entities.iter()
+    {
+        world.syscall((*entity, Priority(0)),
+            |In((entity, priority)): In<(Entity, Priority)>, mut c: Commands| c.react().insert(entity, priority)
+        );
+    }
+
+    // add sorted reactor
+    let token = world.react(|rc| rc.on_mutation_sorted::<Priority, _>(
+        |In(entity): In<Entity>, priorities: Reactive<Priority>, mut history: ResMut<TelescopeHistory>|
+        {
+            history.push(priorities.get(entity).unwrap().0);
+        }
+    ));
+
+    // mutate in one tree (reactor runs per entity, highest priority first)
+    let priorities: Vec<(Entity, usize)> = entities.iter().copied().zip([2, 7, 1, 5]).collect();
+    world.syscall(priorities.clone(), set_priorities);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![7, 5, 2, 1]);
+
+    // revoke
+    world.syscall(token, revoke_reactor);
+    world.syscall(priorities, set_priorities);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![7, 5, 2, 1]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn sorted_reactors(world: &World, entity: Entity) -> Vec<SystemCommand>
+{
+    let mut reactors = reactors_targeting(world, entity);
+    reactors.sort_by_key(|reactor| reactor.0);
+    reactors
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn entity_reactor_index()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin);
+    let world = app.world_mut();
+    let entity_a = world.spawn_empty().id();
+    let entity_b = world.spawn_empty().id();
+    assert!(reactors_targeting(world, entity_a).is_empty());
+
+    // add reactors
+    // - one with two triggers on entity a and one on entity b, one only on entity a
+    let (multi, single) = world.syscall((entity_a, entity_b),
+        |In((a, b)): In<(Entity, Entity)>, mut c: Commands| -> (RevokeToken, RevokeToken)
+        {
+            let multi = c.react().on_revokable(
+                (entity_mutation::<TestComponent>(a), entity_event::<()>(a), entity_event::<()>(b)),
+                || {}
+            );
+            let single = c.react().on_revokable(entity_event::<()>(a), || {});
+            (multi, single)
+        }
+    );
+    let multi_id = SystemCommand::from(multi);
+    let single_id = SystemCommand::from(single.clone());
+    assert_eq!(sorted_reactors(world, entity_a), vec![multi_id, single_id]);
+    assert_eq!(sorted_reactors(world, entity_b), vec![multi_id]);
+    assert_reaction_tree_drained(world);
+
+    // revoke one reactor
+    world.react(|rc| rc.revoke(single));
+    assert_eq!(sorted_reactors(world, entity_a), vec![multi_id]);
+    assert_eq!(sorted_reactors(world, entity_b), vec![multi_id]);
+    assert_reaction_tree_drained(world);
+
+    // prune one entity
+    world.react(|rc| rc.prune_entity_reactors(entity_a));
+    assert!(reactors_targeting(world, entity_a).is_empty());
+    assert_eq!(sorted_reactors(world, entity_b), vec![multi_id]);
+    assert_reaction_tree_drained(world);
+
+    // despawn the other entity
+    world.despawn(entity_b);
+    assert!(reactors_targeting(world, entity_b).is_empty());
+    assert_reaction_tree_drained(world);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(ReactComponent, Default)]
+struct RequiredValue(usize);
+
+#[derive(Component)]
+#[require(React<RequiredValue>)]
+struct NeedsValue;
+
+// Insertion reactions fire when `React<C>` is inserted as a required component.
+#[test]
+fn required_react_component_insertion()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TelescopeHistory>();
+    let world = app.world_mut();
+
+    // add reactors
+    world.react(|rc| rc.on(insertion::<RequiredValue>(),
+        |event: InsertionEvent<RequiredValue>, values: Reactive<RequiredValue>, mut history: ResMut<TelescopeHistory>|
+        {
+            history.push(values.get(event.entity()).unwrap().0 + 1);
+        }
+    ));
+
+    // spawn with the required component
+    let entity = world.syscall((), |mut c: Commands| c.spawn(NeedsValue).id());
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1]);
+
+    // mutation reactions target the entity
+    world.react(|rc| rc.on(entity_mutation::<RequiredValue>(entity),
+        |mut history: ResMut<TelescopeHistory>| history.push(10)
+    ));
+    world.syscall(entity,
+        |In(entity): In<Entity>, mut c: Commands, mut values: ReactiveMut<RequiredValue>|
+        {
+            values.get_mut(&mut c, entity).unwrap().0 = 5;
+        }
+    );
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1, 10]);
+
+    // spawning directly in the world also reacts
+    world.spawn(NeedsValue);
+    world.flush();
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1, 10, 1]);
+    assert_reaction_tree_drained(world);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Resource, Default)]
+struct HierarchyLog
+{
+    parent   : Vec<ParentChanged>,
+    children : Vec<(Entity, ChildrenChanged)>,
+}
+
+fn watch_children(In(entity): In<Entity>, mut c: Commands)
+{
+    c.react().on(entity_children_changed(entity),
+        |event: EntityEvent<ChildrenChanged>, mut log: ResMut<HierarchyLog>|
+        {
+            let (target, change) = event.read();
+            log.children.push((target, *change));
+        }
+    );
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// Hierarchy changes are reported in `Last`.
+#[test]
+fn hierarchy_change_reactions()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<HierarchyLog>();
+    let world = app.world_mut();
+    let parent_a = world.spawn_empty().id();
+    let parent_b = world.spawn_empty().id();
+    let child = world.spawn_empty().id();
+
+    // add reactors
+    world.react(|rc| rc.on(entity_parent_changed(child),
+        |event: EntityEvent<ParentChanged>, mut log: ResMut<HierarchyLog>| log.parent.push(*event.read().1)
+    ));
+    world.syscall(parent_a, watch_children);
+    world.syscall(parent_b, watch_children);
+
+    // gain a parent
+    app.world_mut().entity_mut(child).set_parent(parent_a);
+    assert!(app.world().resource::<HierarchyLog>().parent.is_empty());
+    app.update();
+    let log = app.world().resource::<HierarchyLog>();
+    assert_eq!(log.parent, vec![ParentChanged{ old: None, new: Some(parent_a) }]);
+    assert_eq!(log.children, vec![(parent_a, ChildrenChanged::Added(child))]);
+
+    // reparent
+    app.world_mut().entity_mut(child).set_parent(parent_b);
+    app.update();
+    let log = app.world().resource::<HierarchyLog>();
+    assert_eq!(log.parent[1..], [ParentChanged{ old: Some(parent_a), new: Some(parent_b) }]);
+    assert_eq!(log.children[1..], [
+        (parent_a, ChildrenChanged::Removed(child)),
+        (parent_b, ChildrenChanged::Added(child)),
+    ]);
+
+    // lose the parent
+    app.world_mut().entity_mut(child).remove_parent();
+    app.update();
+    let log = app.world().resource::<HierarchyLog>();
+    assert_eq!(log.parent[2..], [ParentChanged{ old: Some(parent_b), new: None }]);
+    assert_eq!(log.children[3..], [(parent_b, ChildrenChanged::Removed(child))]);
+
+    // nothing more
+    app.update();
+    assert_eq!(app.world().resource::<HierarchyLog>().parent.len(), 3);
+    assert_eq!(app.world().resource::<HierarchyLog>().children.len(), 4);
+    assert_reaction_tree_drained(app.world_mut());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// The reactor registered with a component runs for the initial insertion and each mutation, and is cleaned up
+// when the entity despawns.
+#[test]
+fn insert_and_react_lifecycle()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TelescopeHistory>();
+    let world = app.world_mut();
+    let entity = world.spawn_empty().id();
+
+    // insert and register
+    let token = world.react(|rc| rc.insert_and_react(entity, TestComponent(1),
+        |components: Reactive<TestComponent>, mut history: ResMut<TelescopeHistory>|
+        {
+            history.push(components.single().1.0);
+        }
+    ));
+    let reactor = SystemCommand::from(token);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1]);
+    assert_eq!(reactors_targeting(world, entity), vec![reactor]);
+
+    // mutate
+    world.syscall((entity, TestComponent(2)), update_test_entity);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1, 2]);
+
+    // despawn: the reactor is cleaned up (after garbage collection)
+    world.despawn(entity);
+    assert!(reactors_targeting(world, entity).is_empty());
+    app.update();
+    assert!(app.world().get_entity(*reactor).is_err());
+    assert_eq!(**app.world().resource::<TelescopeHistory>(), vec![1, 2]);
+    assert_reaction_tree_drained(app.world_mut());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(ReactComponent, Clone, Debug, PartialEq)]
+struct RemovedValue(usize);
+
+#[derive(Resource, Default)]
+struct BatchedRemovalLog(Vec<Vec<(Entity, RemovedValue)>>);
+
+// Batched removal reactors run once per removal pass with all removed entities and their values.
+#[test]
+fn batched_removal_with_values()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<BatchedRemovalLog>();
+    let world = app.world_mut();
+    let entities: Vec<Entity> = (0..3).map(|_| world.spawn_empty().id()).collect();
+    for (i, entity) in entities.iter().enumerate()
+    {
+        world.react(|rc| rc.insert(*entity, RemovedValue(i)));
+    }
+
+    // add reactor
+    world.react(|rc| rc.on(removal_batched_with_values::<RemovedValue>(),
+        |event: BatchedRemovalEvent<RemovedValue>, mut log: ResMut<BatchedRemovalLog>|
+        {
+            log.0.push(event.iter().map(|(entity, value)| (entity, value.clone())).collect());
+        }
+    ));
+
+    // remove from two entities and despawn the third
+    world.entity_mut(entities[0]).remove::<React<RemovedValue>>();
+    world.entity_mut(entities[2]).remove::<React<RemovedValue>>();
+    world.despawn(entities[1]);
+    assert!(world.resource::<BatchedRemovalLog>().0.is_empty());
+
+    // one reaction with all values
+    schedule_removal_and_despawn_reactors(world);
+    assert_eq!(world.resource::<BatchedRemovalLog>().0, vec![vec![
+        (entities[0], RemovedValue(0)),
+        (entities[2], RemovedValue(2)),
+        (entities[1], RemovedValue(1)),
+    ]]);
+
+    // no more removals
+    schedule_removal_and_despawn_reactors(world);
+    assert_eq!(world.resource::<BatchedRemovalLog>().0.len(), 1);
+    assert_reaction_tree_drained(world);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn retarget_mutation_reactor()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TelescopeHistory>();
+    let world = app.world_mut();
+    let a = world.spawn_empty().id();
+    let b = world.spawn_empty().id();
+    world.syscall((a, TestComponent(0)), insert_on_test_entity);
+    world.syscall((b, TestComponent(0)), insert_on_test_entity);
+
+    // add reactor (records its run count, so we can see its state is retained)
+    let reactor = world.react(|rc| rc.on_persistent(entity_mutation::<TestComponent>(a),
+        |mut count: Local<usize>, mut history: ResMut<TelescopeHistory>|
+        {
+            *count += 1;
+            history.push(*count);
+        }
+    ));
+    world.syscall((a, TestComponent(1)), update_test_entity);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1]);
+
+    // retarget: only B triggers the reactor
+    world.react(|rc| rc.retarget::<TestComponent>(reactor, a, b));
+    world.syscall((a, TestComponent(2)), update_test_entity);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1]);
+    world.syscall((b, TestComponent(2)), update_test_entity);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1, 2]);
+
+    // retarget from an entity without the trigger (no change)
+    world.react(|rc| rc.retarget::<TestComponent>(reactor, a, b));
+    world.syscall((b, TestComponent(3)), update_test_entity);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1, 2, 3]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+macro_rules! ordered_components {
+    ($($name:ident = $label:literal),*) => {
+        $(
+            #[derive(ReactComponent, Clone)]
+            struct $name;
+
+            impl $name
+            {
+                fn register(world: &mut World)
+                {
+                    world.react(|rc| rc.on(removal::<$name>(),
+                        |mut history: ResMut<TelescopeHistory>| history.push($label * 10)
+                    ));
+                    world.react(|rc| rc.on(removal_batched_with_values::<$name>(),
+                        |mut history: ResMut<TelescopeHistory>| history.push($label * 10 + 1)
+                    ));
+                }
+            }
+        )*
+    };
+}
+
+ordered_components!(Ordered0 = 0, Ordered1 = 1, Ordered2 = 2, Ordered3 = 3, Ordered4 = 4, Ordered5 = 5);
+
+fn run_ordered_removals() -> Vec<usize>
+{
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TelescopeHistory>();
+    let world = app.world_mut();
+
+    // register trigger types out of type order
+    Ordered3::register(world);
+    Ordered5::register(world);
+    Ordered1::register(world);
+    Ordered0::register(world);
+    Ordered4::register(world);
+    Ordered2::register(world);
+
+    // remove all components in one pass
+    let entity = world.spawn_empty().id();
+    world.react(|rc|
+    {
+        rc.insert(entity, Ordered0);
+        rc.insert(entity, Ordered1);
+        rc.insert(entity, Ordered2);
+        rc.insert(entity, Ordered3);
+        rc.insert(entity, Ordered4);
+        rc.insert(entity, Ordered5);
+    });
+    world.despawn(entity);
+    schedule_removal_and_despawn_reactors(world);
+    assert_reaction_tree_drained(world);
+
+    world.remove_resource::<TelescopeHistory>().unwrap().to_vec()
+}
+
+// Reactions for different trigger types run in the order the trigger types were registered.
+#[test]
+fn deterministic_reaction_order()
+{
+    let expected = vec![30, 50, 10, 0, 40, 20, 31, 51, 11, 1, 41, 21];
+    for _ in 0..4
+    {
+        assert_eq!(run_ordered_removals(), expected);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Component)]
+struct PlainComponent(usize);
+
+// Plain Bevy components can be watched without converting them to React components.
+#[test]
+fn bevy_component_added_and_changed()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TelescopeHistory>();
+    let world = app.world_mut();
+
+    // add reactors
+    world.react(|rc| rc.on(bevy_component_added::<PlainComponent>(),
+        |
+            event       : BevyComponentEvent<PlainComponent>,
+            plain       : Query<&PlainComponent>,
+            mut history : ResMut<TelescopeHistory>
+        |
+        {
+            assert!(event.is_added());
+            history.push(plain.get(event.entity()).unwrap().0);
+        }
+    ));
+    world.react(|rc| rc.on(bevy_component_changed::<PlainComponent>(),
+        |
+            event       : BevyComponentEvent<PlainComponent>,
+            plain       : Query<&PlainComponent>,
+            mut history : ResMut<TelescopeHistory>
+        |
+        {
+            assert!(!event.is_added());
+            history.push(plain.get(event.entity()).unwrap().0 + 100);
+        }
+    ));
+
+    // add component (addition counts as a change)
+    let entity = app.world_mut().spawn(PlainComponent(1)).id();
+    app.update();
+    assert_eq!(**app.world().resource::<TelescopeHistory>(), vec![1, 101]);
+
+    // change component
+    app.world_mut().get_mut::<PlainComponent>(entity).unwrap().0 = 2;
+    app.update();
+    assert_eq!(**app.world().resource::<TelescopeHistory>(), vec![1, 101, 102]);
+
+    // no change
+    app.update();
+    assert_eq!(**app.world().resource::<TelescopeHistory>(), vec![1, 101, 102]);
+    assert_reaction_tree_drained(app.world_mut());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(ReactComponent)]
+struct TestHealth(f32);
+
+#[test]
+fn threshold_crossings()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TelescopeHistory>();
+    let world = app.world_mut();
+
+    let entity = world.spawn_empty().id();
+    world.react(|rc| rc.insert(entity, TestHealth(10.)));
+    let num_entities = world.entities().len();
+
+    // add reactors
+    world.react(|rc| rc.on_threshold(entity, 0., |h: &TestHealth| h.0, CrossDirection::Down,
+        |mut history: ResMut<TelescopeHistory>| history.push(1)
+    ));
+    world.react(|rc| rc.on_threshold(entity, 0., |h: &TestHealth| h.0, CrossDirection::Up,
+        |mut history: ResMut<TelescopeHistory>| history.push(2)
+    ));
+    world.react(|rc| rc.on_threshold(entity, 0., |h: &TestHealth| h.0, CrossDirection::Either,
+        |mut history: ResMut<TelescopeHistory>| history.push(3)
+    ));
+    assert_eq!(**world.resource::<TelescopeHistory>(), Vec::<usize>::new());
+
+    let set_health = |world: &mut World, health: f32|
+    {
+        world.syscall(health, move |In(health): In<f32>, mut c: Commands, mut q: Query<&mut React<TestHealth>>|
+        {
+            q.get_mut(entity).unwrap().get_mut(&mut c).0 = health;
+        });
+    };
+
+    // stay above (no crossing)
+    set_health(world, 5.);
+    assert_eq!(**world.resource::<TelescopeHistory>(), Vec::<usize>::new());
+
+    // cross downward
+    set_health(world, -1.);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1, 3]);
+
+    // stay below (no crossing)
+    set_health(world, -5.);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1, 3]);
+
+    // cross upward (reaching the threshold counts)
+    set_health(world, 0.);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1, 3, 2, 3]);
+
+    // despawn (reactors cleaned up)
+    world.despawn(entity);
+    schedule_removal_and_despawn_reactors(world);
+    garbage_collect_entities(world);
+    assert_eq!(world.entities().len(), num_entities - 1);
+    assert_reaction_tree_drained(world);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(ReactComponent)]
+struct TestLazy(usize);
+
+impl FromWorld for TestLazy
+{
+    fn from_world(world: &mut World) -> Self
+    {
+        Self(world.resource::<TestReactRecorder>().0)
+    }
+}
+
+#[test]
+fn ensure_component_inserts_once()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .insert_resource(TestReactRecorder(5))
+        .init_resource::<TelescopeHistory>();
+    let world = app.world_mut();
+    let entity = world.spawn_empty().id();
+
+    // add reactor
+    world.react(|rc| rc.on(insertion::<TestLazy>(),
+        |event: InsertionEvent<TestLazy>, lazy: Reactive<TestLazy>, mut history: ResMut<TelescopeHistory>|
+        {
+            history.push(lazy.get(event.entity()).unwrap().0);
+        }
+    ));
+
+    // first ensure inserts (default computed from the world)
+    assert!(world.ensure_component::<TestLazy>(entity));
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![5]);
+
+    // second ensure leaves the component untouched
+    world.resource_mut::<TestReactRecorder>().0 = 10;
+    assert!(!world.ensure_component::<TestLazy>(entity));
+    world.react(|rc| rc.ensure_component::<TestLazy>(entity));
+    assert_eq!(world.get::<React<TestLazy>>(entity).unwrap().0, 5);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![5]);
+
+    // deferred ensure on another entity
+    let other = world.spawn_empty().id();
+    world.react(|rc| rc.ensure_component::<TestLazy>(other));
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![5, 10]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Resource)]
+struct ChangeDetectionTarget(Entity);
+
+fn mutate_change_detection_target(
+    mut c          : Commands,
+    target         : Res<ChangeDetectionTarget>,
+    mut components : ReactiveMut<TestComponent>
+){
+    components.get_mut(&mut c, target.0).unwrap().0 += 1;
+}
+
+fn poll_changed_components(
+    changed     : Query<(), Changed<React<TestComponent>>>,
+    mut history : ResMut<TelescopeHistory>
+){
+    if changed.is_empty() { return; }
+    history.push(1);
+}
+
+// Reactors can wait until polling systems have observed Bevy's change detection.
+#[test]
+fn after_change_detection_runs_after_polling()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TelescopeHistory>()
+        .add_systems(Update, (mutate_change_detection_target, poll_changed_components).chain());
+    let world = app.world_mut();
+    let entity = world.spawn_empty().id();
+    world.syscall((entity, TestComponent(0)), insert_on_test_entity);
+    world.insert_resource(ChangeDetectionTarget(entity));
+
+    // add reactors
+    world.react(|rc| rc.on(entity_mutation::<TestComponent>(entity),
+        |mut history: ResMut<TelescopeHistory>| history.push(0)
+    ));
+    let token = world.react(|rc| rc.on_after_change_detection::<TestComponent, _>(
+        |In(entities): In<Vec<Entity>>, mut history: ResMut<TelescopeHistory>| history.push(entities.len() + 1)
+    ));
+
+    // immediate reaction, then polling, then the deferred reactor
+    app.update();
+    assert_eq!(**app.world().resource::<TelescopeHistory>(), vec![0, 1, 2]);
+
+    // mutations are collected until Last
+    app.world_mut().syscall((entity, TestComponent(5)), update_test_entity);
+    app.update();
+    assert_eq!(**app.world().resource::<TelescopeHistory>(), vec![0, 1, 2, 0, 0, 1, 2]);
+
+    // revoke
+    app.world_mut().react(|rc| rc.revoke(token));
+    app.update();
+    assert_eq!(**app.world().resource::<TelescopeHistory>(), vec![0, 1, 2, 0, 0, 1, 2, 0, 1]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// Per-entity reactor data persists across reactions and is separate for each entity.
+#[test]
+fn entity_reactor_data_across_triggers()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TelescopeHistory>();
+    let world = app.world_mut();
+    let entity_a = world.spawn_empty().id();
+    let entity_b = world.spawn_empty().id();
+    world.syscall((entity_a, TestComponent(0)), insert_on_test_entity);
+    world.syscall((entity_b, TestComponent(0)), insert_on_test_entity);
+
+    // add reactors that count mutations in their entity's data
+    let count_mutations = |mut data: EntityData<usize>, mut history: ResMut<TelescopeHistory>|
+    {
+        let (_, count) = data.get_mut();
+        *count += 1;
+        history.push(*count);
+    };
+    world.react(|rc| rc.on_entity_with_data::<TestComponent, _, _, _>(entity_a, 0usize, count_mutations));
+    world.react(|rc| rc.on_entity_with_data::<TestComponent, _, _, _>(entity_b, 10usize, count_mutations));
+
+    // mutate
+    world.syscall((entity_a, TestComponent(1)), update_test_entity);
+    world.syscall((entity_a, TestComponent(2)), update_test_entity);
+    world.syscall((entity_b, TestComponent(1)), update_test_entity);
+    world.syscall((entity_a, TestComponent(3)), update_test_entity);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1, 2, 11, 3]);
+
+    // despawning an entity does not affect the data of other entities
+    world.despawn(entity_a);
+    schedule_removal_and_despawn_reactors(world);
+    world.syscall((entity_b, TestComponent(2)), update_test_entity);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1, 2, 11, 3, 12]);
+    assert_reaction_tree_drained(world);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// Change triggers react to both insertions and mutations, and ChangeEvent reads either.
+#[test]
+fn change_triggers_react_to_insertion_and_mutation()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TelescopeHistory>();
+    let world = app.world_mut();
+    let entity_a = world.spawn_empty().id();
+    let entity_b = world.spawn_empty().id();
+
+    // add reactors
+    world.react(|rc| rc.on_persistent(change::<TestComponent>(),
+            |event: ChangeEvent<TestComponent>, mut history: ResMut<TelescopeHistory>|
+            {
+                let _ = event.get()?;
+                history.push(if event.is_insertion() { 1 } else { 2 });
+                OK
+            }
+        ));
+    world.react(|rc| rc.on_persistent(entity_change::<TestComponent>(entity_b),
+            |event: ChangeEvent<TestComponent>, mut history: ResMut<TelescopeHistory>|
+            {
+                assert!(!event.is_empty());
+                history.push(if event.is_insertion() { 10 } else { 20 });
+            }
+        ));
+
+    // insert and mutate
+    world.syscall((entity_a, TestComponent(0)), insert_on_test_entity);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1]);
+    world.syscall((entity_a, TestComponent(1)), update_test_entity);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1, 2]);
+    world.syscall((entity_b, TestComponent(0)), insert_on_test_entity);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1, 2, 10, 1]);
+    world.syscall((entity_b, TestComponent(1)), update_test_entity);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1, 2, 10, 1, 20, 2]);
+    assert_reaction_tree_drained(world);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(ReactComponent, Clone)]
+struct TrackedValue(usize);
+
+fn update_tracked_value(
+    In((entity, new_val, tracked)) : In<(Entity, usize, bool)>,
+    mut c                          : Commands,
+    mut query                      : Query<&mut React<TrackedValue>>,
+){
+    let mut react = query.get_mut(entity).unwrap();
+    let value = match tracked
+    {
+        true  => react.get_mut_tracked(&mut c),
+        false => react.get_mut(&mut c),
+    };
+    value.0 = new_val;
+}
+
+// Mutations made with get_mut_tracked expose the previous value to all mutation reactors.
+#[test]
+fn mutation_event_reads_previous_value()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TelescopeHistory>();
+    let world = app.world_mut();
+    let entity = world.spawn_empty().id();
+    world.react(|rc| rc.insert(entity, TrackedValue(1)));
+
+    // add reactors
+    let record_previous = |event: MutationEvent<TrackedValue>, mut history: ResMut<TelescopeHistory>|
+    {
+        history.push(event.previous().map(|p| p.0).unwrap_or(usize::MAX));
+    };
+    world.react(|rc| rc.on_persistent(entity_mutation::<TrackedValue>(entity), record_previous));
+    world.react(|rc| rc.on_persistent(mutation::<TrackedValue>(), record_previous));
+
+    // tracked mutation
+    world.syscall((entity, 2, true), update_tracked_value);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1, 1]);
+
+    // untracked mutation
+    world.syscall((entity, 3, false), update_tracked_value);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1, 1, usize::MAX, usize::MAX]);
+
+    // tracked mutation again
+    world.syscall((entity, 4, true), update_tracked_value);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1, 1, usize::MAX, usize::MAX, 3, 3]);
+
+    // the previous values were dropped after the last reactor ran
+    assert_reaction_tree_drained(world);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// `insert_if_new` schedules insertion reactions for new components and mutation reactions for existing ones.
+#[test]
+fn insert_if_new_schedules_insertion_or_mutation()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TelescopeHistory>();
+    let world = app.world_mut();
+    let entity = world.spawn_empty().id();
+
+    // record insertions as 1 and mutations as 2
+    world.react(|rc| rc.on_persistent(entity_insertion::<TestComponent>(entity),
+        |mut history: ResMut<TelescopeHistory>| history.push(1)));
+    world.react(|rc| rc.on_persistent(entity_mutation::<TestComponent>(entity),
+        |mut history: ResMut<TelescopeHistory>| history.push(2)));
+
+    // first insert: insertion reaction
+    world.react(|rc| rc.insert_if_new(entity, TestComponent(1)));
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1]);
+    assert_eq!(world.get::<React<TestComponent>>(entity).unwrap().get().0, 1);
+
+    // second insert: value is overwritten with a mutation reaction
+    world.react(|rc| rc.insert_if_new(entity, TestComponent(2)));
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1, 2]);
+    assert_eq!(world.get::<React<TestComponent>>(entity).unwrap().get().0, 2);
+
+    // plain insert still schedules insertion reactions
+    world.react(|rc| rc.insert(entity, TestComponent(3)));
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1, 2, 1]);
+    assert_reaction_tree_drained(world);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(ReactComponent, Clone, Debug, PartialEq)]
+struct Score(usize);
+
+fn set_score(In((entity, score)): In<(Entity, usize)>, mut c: Commands, mut scores: ReactiveMut<Score>)
+{
+    *scores.get_mut(&mut c, entity).unwrap() = Score(score);
+}
+
+// Diff tracking records coalesced (old, new) pairs for mutations since the last drain.
+#[test]
+fn diff_tracking_coalesces_mutations()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin);
+    let world = app.world_mut();
+    let entity_a = world.spawn_empty().id();
+    let entity_b = world.spawn_empty().id();
+    let entity_c = world.spawn_empty().id();
+
+    // mutations before tracking is enabled are not recorded
+    world.react(|rc| rc.insert(entity_a, Score(0)));
+    world.syscall((entity_a, 1), set_score);
+    world.react(|rc| rc.enable_diff_tracking::<Score>());
+    assert!(world.resource::<ReactDiffBuffer<Score>>().is_empty());
+
+    // insertions are not diffs, but the inserted values are used as old values
+    world.react(|rc| rc.insert(entity_b, Score(10)));
+    world.react(|rc| rc.insert(entity_c, Score(20)));
+    assert!(world.resource::<ReactDiffBuffer<Score>>().is_empty());
+
+    // mutations of the same entity are coalesced
+    world.syscall((entity_b, 11), set_score);
+    world.syscall((entity_a, 2), set_score);
+    world.syscall((entity_b, 12), set_score);
+    world.syscall((entity_c, 21), set_score);
+    world.syscall((entity_c, 20), set_score);  // back to the original value
+    let diffs: Vec<_> = world.resource_mut::<ReactDiffBuffer<Score>>().drain().collect();
+    assert_eq!(diffs, vec![
+        ReactDiff{ entity: entity_b, old: Score(10), new: Score(12) },
+        ReactDiff{ entity: entity_a, old: Score(1), new: Score(2) },
+    ]);
+    assert!(world.resource::<ReactDiffBuffer<Score>>().is_empty());
+
+    // the next drain starts from the latest values
+    world.syscall((entity_b, 13), set_score);
+    let diffs: Vec<_> = world.resource_mut::<ReactDiffBuffer<Score>>().drain().collect();
+    assert_eq!(diffs, vec![ReactDiff{ entity: entity_b, old: Score(12), new: Score(13) }]);
+
+    // removal discards pending diffs
+    world.syscall((entity_a, 3), set_score);
+    world.entity_mut(entity_a).remove::<React<Score>>();
+    schedule_removal_and_despawn_reactors(world);
+    assert_eq!(world.resource_mut::<ReactDiffBuffer<Score>>().drain().count(), 0);
+    assert_reaction_tree_drained(world);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// Changes made without reactions can be followed by one manual mutation trigger.
+#[test]
+fn noreact_changes_with_manual_trigger()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TelescopeHistory>();
+    let world = app.world_mut();
+    let entity = world.spawn_empty().id();
+    world.syscall((entity, TestComponent(0)), insert_on_test_entity);
+    world.react(|rc| rc.on_persistent(entity_mutation::<TestComponent>(entity),
+        |event: MutationEvent<TestComponent>, query: Reactive<TestComponent>, mut history: ResMut<TelescopeHistory>|
+        {
+            history.push(query.get(event.entity()).unwrap().0);
+        }
+    ));
+
+    // bulk changes don't react
+    world.syscall(entity,
+        |In(entity): In<Entity>, mut query: ReactiveMut<TestComponent>|
+        {
+            for _ in 0..3 { query.get_noreact(entity).unwrap().0 += 1; }
+        }
+    );
+    assert!(world.resource::<TelescopeHistory>().is_empty());
+
+    // the manual trigger runs reactors once with the final value
+    world.react(|rc| rc.trigger_mutation::<TestComponent>(entity));
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![3]);
+    assert_reaction_tree_drained(world);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Resource, Default, Deref, DerefMut)]
+struct ReactionSources(Vec<Option<(Entity, EntityReactionType)>>);
+
+// The source of entity reactions can be read without naming the component type.
+#[test]
+fn entity_reaction_source_reads_current_reaction()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<ReactionSources>();
+    let world = app.world_mut();
+    world.react(|rc| rc.on_persistent(
+        (insertion::<TestComponent>(), mutation::<TestComponent>(), removal::<TestComponent>()),
+        |source: EntityReactionSource, mut sources: ResMut<ReactionSources>|
+        {
+            sources.push(source.get());
+        }
+    ));
+    let component_id = std::any::TypeId::of::<TestComponent>();
+
+    // insertion
+    let entity = world.spawn_empty().id();
+    world.syscall((entity, TestComponent(0)), insert_on_test_entity);
+    assert_eq!(world.resource::<ReactionSources>()[0], Some((entity, EntityReactionType::Insertion(component_id))));
+
+    // mutation
+    world.syscall((entity, TestComponent(1)), update_test_entity);
+    assert_eq!(world.resource::<ReactionSources>()[1], Some((entity, EntityReactionType::Mutation(component_id))));
+
+    // removal
+    world.syscall(entity, remove_from_test_entity);
+    schedule_removal_and_despawn_reactors(world);
+    assert_eq!(world.resource::<ReactionSources>()[2], Some((entity, EntityReactionType::Removal(component_id))));
+
+    // outside a reaction
+    let source = world.syscall((), |source: EntityReactionSource| source.get());
+    assert_eq!(source, None);
+    assert_reaction_tree_drained(world);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -398,3 +398,54 @@ fn entity_world_reactor_mutable_data()
 }
 
 //-------------------------------------------------------------------------------------------------------------------
+
+// despawned entities are pruned from the reactor's tracking set
+#[test]
+fn entity_world_reactor_prunes_despawned_entities()
+{
+    // setup
+    let count = Arc::new(AtomicU32::new(0u32));
+    let count_inner = count.clone();
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .add_entity_reactor(FullDataReactorDetector(count_inner));
+    let world = app.world_mut();
+    let num_tracked = |world: &mut World| world.syscall((),
+        |reactor: EntityReactor<FullDataReactorDetector>| reactor.num_tracked()
+    );
+
+    // add entities
+    let entity1 = world.spawn_empty().id();
+    let entity2 = world.spawn_empty().id();
+    world.syscall((),
+        move |mut c: Commands, reactor: EntityReactor<FullDataReactorDetector>|
+        {
+            reactor.add(&mut c, entity1, 1usize);
+            reactor.add(&mut c, entity2, 3usize);
+        }
+    );
+    assert_eq!(num_tracked(world), 2);
+
+    // despawn an entity
+    world.despawn(entity1);
+    schedule_removal_and_despawn_reactors(world);
+    assert_eq!(num_tracked(world), 1);
+    assert!(!world.syscall((),
+        move |reactor: EntityReactor<FullDataReactorDetector>| reactor.is_tracking(entity1)
+    ));
+
+    // remaining entity still works
+    world.syscall((), move |mut c: Commands| { c.react().entity_event(entity2, ()); });
+    assert_eq!(count.load(Ordering::Relaxed), 3);
+
+    // removing triggers untracks the entity
+    world.syscall((),
+        move |mut c: Commands, reactor: EntityReactor<FullDataReactorDetector>|
+        {
+            reactor.remove(&mut c, entity_event::<()>(entity2));
+        }
+    );
+    assert_eq!(num_tracked(world), 0);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -6,7 +6,7 @@ use crate::*;
 use bevy::prelude::*;
 
 //standard shortcuts
-
+use std::sync::{Arc, Mutex};
 
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
@@ -320,6 +320,62 @@ fn broadcast_scoping()
 
 //-------------------------------------------------------------------------------------------------------------------
 
+// Channel-scoped broadcasts are only visible to reactors on the same channel.
+#[test]
+fn broadcast_channel_isolation()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TestReactRecorder>();
+    let world = app.world_mut();
+
+    // add reactors
+    world.syscall((),
+        |mut c: Commands|
+        {
+            c.react().on(broadcast_channel::<IntEvent>(ChannelId(1)),
+                |event: BroadcastEvent<IntEvent>, mut recorder: ResMut<TestReactRecorder>|
+                {
+                    recorder.0 += event.read().0;
+                }
+            );
+            c.react().on(broadcast_channel::<IntEvent>(ChannelId(2)),
+                |event: BroadcastEvent<IntEvent>, mut recorder: ResMut<TestReactRecorder>|
+                {
+                    recorder.0 += event.read().0 * 10;
+                }
+            );
+            c.react().on(broadcast::<IntEvent>(),
+                |event: BroadcastEvent<IntEvent>, mut recorder: ResMut<TestReactRecorder>|
+                {
+                    recorder.0 += event.read().0 * 100;
+                }
+            );
+        }
+    );
+
+    // channel 1
+    world.react(|rc| rc.broadcast_on_channel(ChannelId(1), IntEvent(1)));
+    assert_eq!(world.resource::<TestReactRecorder>().0, 1);
+
+    // channel 2
+    world.react(|rc| rc.broadcast_on_channel(ChannelId(2), IntEvent(1)));
+    assert_eq!(world.resource::<TestReactRecorder>().0, 11);
+
+    // global broadcasts use the default channel
+    world.react(|rc| rc.broadcast(IntEvent(1)));
+    assert_eq!(world.resource::<TestReactRecorder>().0, 111);
+    world.broadcast_on_channel(ChannelId::DEFAULT, IntEvent(1));
+    assert_eq!(world.resource::<TestReactRecorder>().0, 211);
+
+    // unused channel
+    world.broadcast_on_channel(ChannelId(3), IntEvent(1));
+    assert_eq!(world.resource::<TestReactRecorder>().0, 211);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 // Multiple broadcast events scheduled in a row do not interfere.
 #[test]
 fn multiple_broadcast_noninterference()
@@ -666,3 +722,497 @@ fn revoke_any_entity_event_reactor()
 }
 
 //-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Resource, Default)]
+struct SavedDataEntities(Vec<Entity>);
+
+#[test]
+fn event_data_entity_lifetime()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<SavedDataEntities>();
+    let world = app.world_mut();
+
+    // entity
+    let test_entity = world.spawn_empty().id();
+
+    // add reactors
+    world.syscall(test_entity,
+        |In(entity): In<Entity>, mut c: Commands|
+        {
+            c.react().on(broadcast::<IntEvent>(),
+                |event: BroadcastEvent<IntEvent>, mut saved: ResMut<SavedDataEntities>, all: Query<()>|
+                {
+                    let data_entity = event.data_entity().unwrap();
+                    assert!(all.contains(data_entity));
+                    saved.0.push(data_entity);
+                }
+            );
+            c.react().on(entity_event::<IntEvent>(entity),
+                |event: EntityEvent<IntEvent>, mut saved: ResMut<SavedDataEntities>, all: Query<()>|
+                {
+                    let data_entity = event.data_entity().unwrap();
+                    assert!(all.contains(data_entity));
+                    saved.0.push(data_entity);
+                }
+            );
+            c.react().on(broadcast::<()>(),
+                |event: EntityEvent<IntEvent>|
+                {
+                    assert!(event.data_entity().is_none());
+                }
+            );
+        }
+    );
+
+    // send events
+    world.syscall(1, send_broadcast);
+    world.syscall((test_entity, 2), send_entity_event);
+    world.broadcast(());
+
+    // data entities were despawned after the reactors ran
+    let saved = std::mem::take(&mut world.resource_mut::<SavedDataEntities>().0);
+    assert_eq!(saved.len(), 2);
+    for data_entity in saved
+    {
+        assert!(world.get_entity(data_entity).is_err());
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// Only the first registered reactor receives an entity event sent with `entity_event_first`.
+#[test]
+fn entity_event_first_reactor_only()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TestReactRecorder>()
+        .init_resource::<SavedDataEntities>();
+    let world = app.world_mut();
+    let test_entity = world.spawn_empty().id();
+
+    // add reactors
+    world.syscall(test_entity,
+        |In(entity): In<Entity>, mut c: Commands|
+        {
+            c.react().on(entity_event::<IntEvent>(entity),
+                |event: EntityEvent<IntEvent>, mut recorder: ResMut<TestReactRecorder>, mut saved: ResMut<SavedDataEntities>|
+                {
+                    recorder.0 += event.read().1.0;
+                    saved.0.push(event.data_entity().unwrap());
+                }
+            );
+            c.react().on(entity_event::<IntEvent>(entity),
+                |event: EntityEvent<IntEvent>, mut recorder: ResMut<TestReactRecorder>|
+                {
+                    recorder.0 += event.read().1.0 * 10;
+                }
+            );
+        }
+    );
+
+    // send to first reactor
+    world.react(|rc| rc.entity_event_first(test_entity, IntEvent(1)));
+    assert_eq!(world.resource::<TestReactRecorder>().0, 1);
+    let saved = std::mem::take(&mut world.resource_mut::<SavedDataEntities>().0);
+    assert!(world.get_entity(saved[0]).is_err());
+
+    // send to all reactors
+    world.react(|rc| rc.entity_event(test_entity, IntEvent(1)));
+    assert_eq!(world.resource::<TestReactRecorder>().0, 12);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// Same as `test_broadcast` and `test_entity_event` but with the synchronous world shortcuts.
+#[test]
+fn world_event_shortcuts()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TestReactRecorder>();
+    let world = app.world_mut();
+    let test_entity = world.spawn_empty().id();
+
+    // add reactors
+    world.syscall((), on_broadcast);
+    world.syscall(test_entity, on_entity_event);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 0);
+
+    // broadcast (reaction)
+    world.broadcast(IntEvent(222));
+    assert_eq!(world.resource::<TestReactRecorder>().0, 222);
+
+    // entity event (reaction)
+    world.entity_event(test_entity, IntEvent(1));
+    assert_eq!(world.resource::<TestReactRecorder>().0, 1);
+
+    // entity event for another entity (no reaction)
+    let other_entity = world.spawn_empty().id();
+    world.entity_event(other_entity, IntEvent(2));
+    assert_eq!(world.resource::<TestReactRecorder>().0, 1);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn entity_event_retained_for_tree()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TelescopeHistory>();
+    let world = app.world_mut();
+    let test_entity = world.spawn_empty().id();
+
+    // reactor registered before the event
+    world.react(|rc| rc.on(entity_event::<IntEvent>(test_entity),
+        |event: EntityEvent<IntEvent>, mut history: ResMut<TelescopeHistory>| history.push(event.read().1.0)
+    ));
+
+    // send events then register late reactors in the same tree
+    world.react(|rc| rc.on(broadcast::<bool>(),
+        move |mut c: Commands, event: BroadcastEvent<bool>|
+        {
+            if *event.read()
+            {
+                c.react().entity_event_retained(test_entity, IntEvent(1), RetentionScope::Tree);
+            }
+            else
+            {
+                c.react().entity_event(test_entity, IntEvent(2));
+            }
+            c.react().on(entity_event::<IntEvent>(test_entity),
+                |event: EntityEvent<IntEvent>, mut history: ResMut<TelescopeHistory>|
+                {
+                    history.push(event.read().1.0 * 10);
+                }
+            );
+            c.react().on(any_entity_event::<IntEvent>(),
+                |event: EntityEvent<IntEvent>, mut history: ResMut<TelescopeHistory>|
+                {
+                    history.push(event.read().1.0 * 100);
+                }
+            );
+        }
+    ));
+
+    // retained (late reactors receive the event)
+    world.broadcast(true);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1, 10, 100]);
+    assert_reaction_tree_drained(world);
+
+    // fire-and-forget (late reactors miss the event, earlier late reactors receive it)
+    world.resource_mut::<TelescopeHistory>().clear();
+    world.broadcast(false);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![2, 20, 200]);
+    assert_reaction_tree_drained(world);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// Spread broadcasts run a limited number of reactors per frame and drop the event data after the final reactor.
+#[test]
+fn broadcast_spread_across_frames()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TelescopeHistory>();
+
+    let proxy_entity = app.world_mut().spawn_empty().id();
+    let signal = app.world().resource::<AutoDespawner>().prepare(proxy_entity);
+
+    // add reactors
+    for i in 0..5
+    {
+        app.world_mut().react(|rc| rc.on(broadcast::<AutoDespawnSignal>(),
+            move |event: BroadcastEvent<AutoDespawnSignal>, mut history: ResMut<TelescopeHistory>|
+            {
+                assert_eq!(event.read().entity(), proxy_entity);
+                history.push(i);
+            }
+        ));
+    }
+
+    // send event (first batch runs immediately)
+    app.world_mut().syscall(signal,
+        |In(signal): In<AutoDespawnSignal>, mut c: Commands| c.react().broadcast_spread(signal, 2)
+    );
+    assert_eq!(**app.world().resource::<TelescopeHistory>(), vec![0, 1]);
+
+    // reactors registered after sending don't receive the event
+    app.world_mut().react(|rc| rc.on(broadcast::<AutoDespawnSignal>(),
+        |mut history: ResMut<TelescopeHistory>| history.push(100)
+    ));
+
+    // next frame
+    app.update();
+    assert_eq!(**app.world().resource::<TelescopeHistory>(), vec![0, 1, 2, 3]);
+    assert!(app.world().get_entity(proxy_entity).is_ok());
+
+    // final frame (event data is dropped after the final reactor)
+    app.update();
+    assert_eq!(**app.world().resource::<TelescopeHistory>(), vec![0, 1, 2, 3, 4]);
+    assert!(app.world().get_entity(proxy_entity).is_err());
+    assert_reaction_tree_drained(app.world_mut());
+
+    // nothing more runs
+    app.update();
+    assert_eq!(**app.world().resource::<TelescopeHistory>(), vec![0, 1, 2, 3, 4]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// Collected broadcast responses are stored in reactor run order and readable after the tree.
+#[test]
+fn broadcast_collect_responses()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin);
+    let world = app.world_mut();
+
+    // add reactors
+    for i in 0..3
+    {
+        world.react(|rc| rc.on(broadcast::<IntEvent>(),
+            move |event: BroadcastEvent<IntEvent>, responder: BroadcastResponder<usize>|
+            {
+                assert!(responder.can_respond());
+                responder.respond(event.read().0 + i);
+            }
+        ));
+    }
+
+    // collect responses
+    let handle = world.react(|rc| rc.broadcast_collect::<IntEvent, usize>(IntEvent(10)));
+    assert_eq!(handle.take(), vec![10, 11, 12]);
+    assert!(handle.is_empty());
+    assert_reaction_tree_drained(world);
+
+    // normal broadcasts can't be responded to
+    world.react(|rc| rc.on(broadcast::<()>(),
+        |responder: BroadcastResponder<usize>| assert!(responder.try_respond(0).is_err())
+    ));
+    world.broadcast(());
+
+    // no reactors: nothing is collected
+    let handle = world.react(|rc| rc.broadcast_collect::<usize, usize>(0));
+    assert!(handle.is_empty());
+    assert_reaction_tree_drained(world);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Resource, Default)]
+struct RetainedPayload(Vec<Arc<Vec<usize>>>);
+
+// Reactors can keep shared broadcast payloads after the event data is cleaned up.
+#[test]
+fn broadcast_arc_retained_past_tree()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<RetainedPayload>();
+    let world = app.world_mut();
+
+    // add reactors
+    for _ in 0..2
+    {
+        world.react(|rc| rc.on(broadcast::<Arc<Vec<usize>>>(),
+            |event: ArcBroadcastEvent<Vec<usize>>, mut retained: ResMut<RetainedPayload>|
+            {
+                retained.0.push(event.read());
+            }
+        ));
+    }
+
+    // broadcast
+    let payload = Arc::new(vec![1, 2, 3]);
+    world.react(|rc| rc.broadcast_arc(payload.clone()));
+    assert_reaction_tree_drained(world);
+
+    // the data entity was despawned, but the payload is shared with the reactors
+    let retained = &world.resource::<RetainedPayload>().0;
+    assert_eq!(retained.len(), 2);
+    assert!(retained.iter().all(|retained| Arc::ptr_eq(retained, &payload)));
+    assert_eq!(Arc::strong_count(&payload), 3);
+
+    // dropping the retained copies leaves only the sender's
+    world.remove_resource::<RetainedPayload>();
+    assert_eq!(Arc::strong_count(&payload), 1);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// Reactors can check whether sibling reactors ran in the current tree.
+#[test]
+fn tree_run_log_sibling_reactors()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TelescopeHistory>();
+    let world = app.world_mut();
+
+    // add reactors
+    let first = world.react(|rc| rc.on_revokable(broadcast::<usize>(), || {}));
+    let first = SystemCommand::from(first);
+    world.react(|rc| rc.on((broadcast::<usize>(), broadcast::<()>()),
+        move |log: TreeRunLog, mut history: ResMut<TelescopeHistory>|
+        {
+            history.push(log.has_run(first) as usize);
+        }
+    ));
+
+    // broadcasts made by a reactor are handled in the reactor's tree
+    world.react(|rc| rc.on(broadcast::<u8>(), |mut c: Commands| c.react().broadcast(0usize)));
+    world.react(|rc| rc.on(broadcast::<u16>(), |mut c: Commands| c.react().broadcast(())));
+
+    // the first reactor ran earlier in the tree
+    world.broadcast(0u8);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1]);
+
+    // the first reactor didn't run in this tree (the log was cleared)
+    world.broadcast(0u16);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1, 0]);
+    assert!(!world.syscall(first, |In(first): In<SystemCommand>, log: TreeRunLog| log.has_run(first)));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+struct InputTopic;
+impl BroadcastTopic for InputTopic {}
+
+struct ClickEvent(usize);
+impl TopicMember<InputTopic> for ClickEvent {}
+
+struct KeyEvent(usize);
+impl TopicMember<InputTopic> for KeyEvent {}
+
+// Topic reactors receive broadcasts of every member type, in addition to exact-type reactors.
+#[test]
+fn broadcast_topic_members()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TestReactRecorder>();
+    let world = app.world_mut();
+
+    // add reactors
+    world.react(|rc| {
+        rc.add_topic_member::<InputTopic, ClickEvent>();
+        rc.add_topic_member::<InputTopic, KeyEvent>();
+        rc.on(broadcast_topic::<InputTopic>(),
+            |event: TopicEvent<InputTopic>, mut recorder: ResMut<TestReactRecorder>|
+            {
+                if let Some(click) = event.downcast::<ClickEvent>() { recorder.0 += click.0; }
+                if let Some(key) = event.downcast::<KeyEvent>() { recorder.0 += key.0 * 100; }
+            }
+        );
+        rc.on(broadcast::<ClickEvent>(),
+            |event: BroadcastEvent<ClickEvent>, mut recorder: ResMut<TestReactRecorder>|
+            {
+                recorder.0 += event.read().0 * 10;
+            }
+        );
+    });
+
+    // click reaches the topic reactor and the exact-type reactor
+    world.broadcast(ClickEvent(1));
+    assert_eq!(world.resource::<TestReactRecorder>().0, 11);
+
+    // key press reaches the topic reactor
+    world.broadcast(KeyEvent(2));
+    assert_eq!(world.resource::<TestReactRecorder>().0, 211);
+
+    // other types and other channels don't reach the topic reactor
+    world.broadcast(IntEvent(1));
+    world.react(|rc| rc.broadcast_on_channel(ChannelId(1), KeyEvent(3)));
+    assert_eq!(world.resource::<TestReactRecorder>().0, 211);
+    assert_reaction_tree_drained(world);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// Each reactor runs once per batched event, and each event is sent to all reactors before the next event.
+#[test]
+fn broadcast_batch_runs_reactors_per_event()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TelescopeHistory>();
+    let world = app.world_mut();
+
+    // add reactors
+    world.react(|rc| rc.on_persistent(broadcast::<IntEvent>(),
+            |event: BroadcastEvent<IntEvent>, mut history: ResMut<TelescopeHistory>|
+            {
+                history.push(event.read().0);
+            }
+        ));
+    world.react(|rc| rc.on_persistent(broadcast::<IntEvent>(),
+            |event: BroadcastEvent<IntEvent>, mut history: ResMut<TelescopeHistory>|
+            {
+                history.push(event.read().0 * 10);
+            }
+        ));
+
+    // send a batch
+    world.react(|rc| rc.broadcast_batch((1..4).map(IntEvent)));
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1, 10, 2, 20, 3, 30]);
+
+    // empty batches do nothing
+    world.react(|rc| rc.broadcast_batch(Vec::<IntEvent>::new()));
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1, 10, 2, 20, 3, 30]);
+
+    // the batch data was dropped after the last reactor ran
+    assert_reaction_tree_drained(world);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// `ReactorsAttached` is broadcast when an entity gets its first entity-specific reactor.
+#[test]
+fn reactors_attached_on_first_entity_reactor()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin);
+    let world = app.world_mut();
+    let entity_a = world.spawn_empty().id();
+    let entity_b = world.spawn_empty().id();
+
+    // record attached entities
+    let attached = Arc::new(Mutex::new(Vec::new()));
+    let attached_clone = attached.clone();
+    world.react(|rc| rc.on_persistent(broadcast::<ReactorsAttached>(),
+        move |event: BroadcastEvent<ReactorsAttached>| attached_clone.lock().unwrap().push(event.read().0)
+    ));
+
+    // two reactors on the same entity only send one event
+    world.react(|rc| rc.on(entity_mutation::<TestComponent>(entity_a), || ()));
+    world.react(|rc| rc.on(entity_event::<()>(entity_a), || ()));
+    assert_eq!(*attached.lock().unwrap(), vec![entity_a]);
+
+    // a different entity gets its own event
+    world.react(|rc| rc.on(entity_insertion::<TestComponent>(entity_b), || ()));
+    assert_eq!(*attached.lock().unwrap(), vec![entity_a, entity_b]);
+
+    // despawn triggers don't make an entity reactive
+    let entity_c = world.spawn_empty().id();
+    world.react(|rc| rc.on(despawn(entity_c), || ()));
+    assert_eq!(*attached.lock().unwrap(), vec![entity_a, entity_b]);
+    assert_reaction_tree_drained(world);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -1,4 +1,7 @@
 //test modules
+#[cfg(feature = "bevy_asset")]
+mod asset_reactions;
+mod async_reactions;
 mod entity_reactions;
 mod entity_world_reactor;
 mod event_reactions;
@@ -6,7 +9,13 @@ mod plugin;
 mod reaction_tree;
 mod reactor_combination;
 mod reactor_mode;
+#[cfg(feature = "reflect")]
+mod reflect;
 mod resource_reactions;
+#[cfg(feature = "serde")]
+mod serde;
+mod stress;
 mod system_commands;
 mod system_events;
+mod throttled_reactions;
 mod world_reactor;
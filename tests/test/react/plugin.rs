@@ -3,10 +3,16 @@ use bevy_cobweb::prelude::*;
 use crate::*;
 
 //third-party shortcuts
+use bevy::app::FixedMain;
+use bevy::diagnostic::{DiagnosticPath, DiagnosticsStore};
+use bevy::input::ButtonState;
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::mouse::MouseButtonInput;
 use bevy::prelude::*;
 
 //standard shortcuts
-
+use std::any::TypeId;
+use std::sync::{Arc, Mutex};
 
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
@@ -18,6 +24,45 @@ fn on_entity_insertion(In(entity): In<Entity>, mut c: Commands) -> RevokeToken
         )
 }
 
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Entities to despawn in the next fixed step.
+#[derive(Resource, Default)]
+struct FixedDespawns(Vec<Entity>);
+
+fn despawn_in_fixed_step(mut c: Commands, mut despawns: ResMut<FixedDespawns>)
+{
+    for entity in despawns.0.drain(..)
+    {
+        c.entity(entity).despawn();
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Log writer that collects logs into a shared buffer.
+#[derive(Clone, Default)]
+struct LogBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl LogBuffer
+{
+    fn contents(&self) -> String
+    {
+        String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+    }
+}
+
+impl std::io::Write for LogBuffer
+{
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize>
+    {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> { Ok(()) }
+}
+
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
 
@@ -37,3 +82,342 @@ fn reactor_panic_without_plugin()
 }
 
 //-------------------------------------------------------------------------------------------------------------------
+
+#[cfg(debug_assertions)]
+#[test]
+fn leak_warning_on_monotonic_growth()
+{
+    // prepare tracing
+    let logs = LogBuffer::default();
+    let writer = logs.clone();
+    let subscriber = tracing_subscriber::FmtSubscriber::builder()
+        .with_max_level(tracing::Level::WARN)
+        .with_writer(move || writer.clone())
+        .finish();
+
+    tracing::subscriber::with_default(subscriber, ||
+    {
+        // setup
+        let mut app = App::new();
+        app.add_plugins(ReactPlugin.with_leak_warning(5))
+            .add_systems(Update, |mut c: Commands| { c.react().on(broadcast::<()>(), || {}); });
+
+        // below threshold (no warning)
+        for _ in 0..4 { app.update(); }
+        assert!(!logs.contents().contains("may be a leak"));
+
+        // reach threshold (warning)
+        app.update();
+        assert_eq!(logs.contents().matches("may be a leak").count(), 1);
+
+        // warn once per threshold
+        for _ in 0..4 { app.update(); }
+        assert_eq!(logs.contents().matches("may be a leak").count(), 1);
+        app.update();
+        assert_eq!(logs.contents().matches("may be a leak").count(), 2);
+    });
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn fixed_plugin_reacts_per_step()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin::fixed())
+        .init_resource::<FixedDespawns>()
+        .init_resource::<TelescopeHistory>()
+        .add_systems(FixedUpdate, despawn_in_fixed_step);
+    let world = app.world_mut();
+
+    // entities with despawn reactors
+    let entities: Vec<Entity> = (0..3).map(|_| world.spawn_empty().id()).collect();
+    for (idx, entity) in entities.iter().enumerate()
+    {
+        world.react(|rc| rc.on(despawn(*entity),
+            move |mut history: ResMut<TelescopeHistory>| history.push(idx)
+        ));
+    }
+
+    // each fixed step reacts to its own despawns
+    for (idx, entity) in entities.iter().enumerate()
+    {
+        world.resource_mut::<FixedDespawns>().0.push(*entity);
+        world.run_schedule(FixedMain);
+        assert_eq!(world.resource::<TelescopeHistory>().len(), idx + 1);
+    }
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![0, 1, 2]);
+
+    // removals are also processed per step
+    let test_entity = world.spawn_empty().id();
+    world.syscall((test_entity, TestComponent(0)), insert_on_test_entity);
+    world.react(|rc| rc.on(entity_removal::<TestComponent>(test_entity),
+        |mut history: ResMut<TelescopeHistory>| history.push(10)
+    ));
+    world.syscall(test_entity, remove_from_test_entity);
+    world.run_schedule(FixedMain);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![0, 1, 2, 10]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn broadcast_twice(mut c: Commands)
+{
+    c.react().broadcast(());
+    c.react().broadcast(());
+}
+
+// React diagnostics are registered and measured each frame.
+#[test]
+fn react_diagnostics_measurements()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins((ReactPlugin, ReactDiagnosticsPlugin))
+        .add_systems(Update, broadcast_twice);
+    let store = app.world().resource::<DiagnosticsStore>();
+    assert!(store.get(&ReactDiagnosticsPlugin::REACTIONS_PER_FRAME).is_some());
+    assert!(store.get(&ReactDiagnosticsPlugin::LIVE_REACTORS).is_some());
+
+    // add reactors
+    app.world_mut().react(|rc| rc.on(broadcast::<()>(), || {}));
+    app.world_mut().react(|rc| rc.on(broadcast::<()>(), || {}));
+
+    // two reactors ran twice this frame
+    app.update();
+    let store = app.world().resource::<DiagnosticsStore>();
+    let latest = |path: &DiagnosticPath| store.get(path).unwrap().measurement().unwrap().value;
+    assert_eq!(latest(&ReactDiagnosticsPlugin::REACTIONS_PER_FRAME), 4.0);
+    assert_eq!(latest(&ReactDiagnosticsPlugin::LIVE_REACTORS), 2.0);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn trigger_fire_counts(world: &mut World) -> Vec<(ReactorType, u64)>
+{
+    world.syscall((), |diagnostics: ReactDiagnostics| diagnostics.trigger_fire_counts())
+}
+
+// Trigger fires are counted per trigger type when metrics are enabled.
+#[test]
+fn trigger_fire_metrics()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin.with_metrics(true))
+        .insert_react_resource(TestReactRes::default());
+    let world = app.world_mut();
+    world.react(|rc| rc.on(broadcast::<()>(), || {}));
+
+    // fire triggers (counted with or without reactors)
+    world.broadcast(());
+    world.broadcast(1usize);
+    world.broadcast(());
+    world.syscall(1, update_react_res);
+    world.syscall(2, update_react_res);
+    world.broadcast(());
+
+    // counts are cumulative in order of first fire
+    assert_eq!(trigger_fire_counts(world), vec![
+        (ReactorType::Broadcast(TypeId::of::<()>()), 3),
+        (ReactorType::Broadcast(TypeId::of::<usize>()), 1),
+        (ReactorType::ResourceMutation(TypeId::of::<TestReactRes>()), 2),
+    ]);
+    app.update();
+    assert_eq!(trigger_fire_counts(app.world_mut()).len(), 3);
+}
+
+// Trigger fire counts can be reset each frame.
+#[test]
+fn trigger_fire_metrics_per_frame()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin.with_metrics(true).reset(TriggerMetricsReset::PerFrame))
+        .add_systems(Update, broadcast_twice);
+
+    // counts only include the current frame
+    app.update();
+    assert_eq!(trigger_fire_counts(app.world_mut()), vec![(ReactorType::Broadcast(TypeId::of::<()>()), 2)]);
+    app.update();
+    assert_eq!(trigger_fire_counts(app.world_mut()), vec![(ReactorType::Broadcast(TypeId::of::<()>()), 2)]);
+
+    // disabled metrics don't count anything
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin.with_metrics(false))
+        .add_systems(Update, broadcast_twice);
+    app.update();
+    assert!(trigger_fire_counts(app.world_mut()).is_empty());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Resource, Default)]
+struct InputLog(Vec<String>);
+
+// Input events are forwarded as broadcasts.
+#[test]
+fn input_event_broadcasts()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .add_input_broadcasts()
+        .add_input_broadcasts()
+        .init_resource::<InputLog>();
+    let window = app.world_mut().spawn_empty().id();
+
+    // add reactors
+    app.react(|rc| rc.on(broadcast::<KeyboardInput>(),
+        |event: BroadcastEvent<KeyboardInput>, mut log: ResMut<InputLog>|
+        {
+            log.0.push(format!("{:?} {:?}", event.read().key_code, event.read().state));
+        }
+    ));
+    app.react(|rc| rc.on(broadcast::<MouseButtonInput>(),
+        |event: BroadcastEvent<MouseButtonInput>, mut log: ResMut<InputLog>|
+        {
+            log.0.push(format!("{:?} {:?}", event.read().button, event.read().state));
+        }
+    ));
+
+    // send input events
+    app.world_mut().resource_mut::<Events<KeyboardInput>>().send(KeyboardInput{
+        key_code    : KeyCode::KeyA,
+        logical_key : Key::Character("a".into()),
+        state       : ButtonState::Pressed,
+        repeat      : false,
+        window,
+    });
+    app.world_mut().resource_mut::<Events<MouseButtonInput>>().send(MouseButtonInput{
+        button : MouseButton::Left,
+        state  : ButtonState::Released,
+        window,
+    });
+    assert!(app.world().resource::<InputLog>().0.is_empty());
+
+    // reactors receive each event once
+    app.update();
+    assert_eq!(app.world().resource::<InputLog>().0, vec!["KeyA Pressed", "Left Released"]);
+    app.update();
+    assert_eq!(app.world().resource::<InputLog>().0.len(), 2);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// The reaction log records each run with its tree and depth.
+#[test]
+fn reaction_log_records_cascade()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin.with_reaction_log(3));
+    let world = app.world_mut();
+
+    // add reactors
+    let outer = world.react(|rc| rc.on_persistent(broadcast::<u8>(), |mut c: Commands| c.react().broadcast(0u16)));
+    let inner = world.react(|rc| rc.on_persistent(broadcast::<u16>(), || {}));
+    world.entity_mut(*inner).insert(Name::new("inner"));
+
+    // run a cascade
+    world.broadcast(0u8);
+    let log = world.resource::<ReactionLog>();
+    let entries: Vec<_> = log.entries().map(|e| (e.tree, e.depth, e.reactor, e.trigger())).collect();
+    assert_eq!(entries, vec![
+        (1, 0, outer, Some(ReactorType::Broadcast(TypeId::of::<u8>()))),
+        (1, 1, inner, Some(ReactorType::Broadcast(TypeId::of::<u16>()))),
+    ]);
+    assert_eq!(log.dump(), format!(
+        "tree 1\n  [0.000s] {} (trigger: broadcast u8)\n    [0.000s] {} \"inner\" (trigger: broadcast u16)\n",
+        *outer, *inner
+    ));
+
+    // a new tree starts a new cascade, and old entries are dropped when the log is full
+    world.broadcast(0u16);
+    let log = world.resource::<ReactionLog>();
+    let entries: Vec<_> = log.entries().map(|e| (e.tree, e.depth, e.reactor)).collect();
+    assert_eq!(entries, vec![(1, 0, outer), (1, 1, inner), (2, 0, inner)]);
+    world.broadcast(0u16);
+    let log = world.resource::<ReactionLog>();
+    let entries: Vec<_> = log.entries().map(|e| (e.tree, e.depth, e.reactor)).collect();
+    assert_eq!(entries, vec![(1, 1, inner), (2, 0, inner), (3, 0, inner)]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn reactor_counts(world: &mut World) -> ReactorCounts
+{
+    world.syscall((), |diagnostics: ReactDiagnostics| diagnostics.reactor_counts())
+}
+
+#[test]
+fn reactor_counts_track_registrations()
+{
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin);
+    let world = app.world_mut();
+    let entity = world.spawn_empty().id();
+    assert_eq!(reactor_counts(world), ReactorCounts::default());
+
+    // register reactors
+    let token = world.react(|rc| rc.on_revokable((insertion::<TestComponent>(), mutation::<TestComponent>()), || ()));
+    world.react(|rc| rc.on_persistent(resource_mutation::<TestReactRes>(), || ()));
+    world.react(|rc| rc.on_persistent(broadcast::<()>(), || ()));
+    world.react(|rc| rc.on_persistent(despawn(entity), || ()));
+    world.react(|rc| rc.on_persistent(any_entity_event::<()>(), || ()));
+    world.react(|rc| rc.on_persistent(entity_mutation::<TestComponent>(entity), || ()));
+    let counts = reactor_counts(world);
+    assert_eq!(
+        counts,
+        ReactorCounts{ component: 2, resource: 1, broadcast: 1, despawn: 1, any_entity_event: 1 }
+    );
+    assert_eq!(counts.total(), 6);
+
+    // revoking removes all triggers of a reactor
+    world.react(|rc| rc.revoke(token));
+    assert_eq!(reactor_counts(world).component, 0);
+
+    // despawn reactors are cleaned up after the entity despawns
+    world.despawn(entity);
+    schedule_removal_and_despawn_reactors(world);
+    assert_eq!(reactor_counts(world).despawn, 0);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn reactor_count_for(world: &mut World, rtype: ReactorType) -> usize
+{
+    world.syscall(rtype, |In(rtype): In<ReactorType>, diagnostics: ReactDiagnostics| diagnostics.reactor_count_for(rtype))
+}
+
+#[test]
+fn reactor_count_for_trigger_types()
+{
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin);
+    let world = app.world_mut();
+    let entity = world.spawn_empty().id();
+    let broadcast_rtype = broadcast::<()>().reactor_type();
+    let entity_rtype = entity_mutation::<TestComponent>(entity).reactor_type();
+    assert_eq!(reactor_count_for(world, broadcast_rtype), 0);
+    assert_eq!(reactor_count_for(world, entity_rtype), 0);
+
+    // one reactor
+    world.react(|rc| rc.on_persistent(broadcast::<()>(), || ()));
+    world.react(|rc| rc.on_persistent(entity_mutation::<TestComponent>(entity), || ()));
+    assert_eq!(reactor_count_for(world, broadcast_rtype), 1);
+    assert_eq!(reactor_count_for(world, entity_rtype), 1);
+
+    // a duplicate
+    world.react(|rc| rc.on_persistent(broadcast::<()>(), || ()));
+    world.react(|rc| rc.on_persistent(entity_mutation::<TestComponent>(entity), || ()));
+    assert_eq!(reactor_count_for(world, broadcast_rtype), 2);
+    assert_eq!(reactor_count_for(world, entity_rtype), 2);
+
+    // other trigger types are unaffected
+    assert_eq!(reactor_count_for(world, broadcast::<usize>().reactor_type()), 0);
+    assert_eq!(reactor_count_for(world, mutation::<TestComponent>().reactor_type()), 0);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
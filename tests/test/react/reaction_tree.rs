@@ -160,6 +160,120 @@ fn cleanup_ordering()
 
 //-------------------------------------------------------------------------------------------------------------------
 
+// Reactive inserts inside a reactor should be handled by the active tree, exactly once.
+#[test]
+fn in_tree_insert_reacts_once()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TelescopeHistory>()
+        .add_reactor(insertion::<TestComponent>(), |mut history: ResMut<TelescopeHistory>| { history.push(2); })
+        .add_reactor(broadcast::<Entity>(),
+            |event: BroadcastEvent<Entity>, mut c: Commands, mut history: ResMut<TelescopeHistory>|
+            {
+                c.react().insert(*event.read(), TestComponent(0));
+                history.push(1);
+            }
+        )
+        .update();
+    let entity = app.world_mut().spawn_empty().id();
+
+    // broadcast (insertion reaction runs in the same tree)
+    app.react(|rc| rc.broadcast(entity));
+    assert_eq!(**app.world().resource::<TelescopeHistory>(), vec![1, 2]);
+
+    // no deferred reactions
+    app.update();
+    assert_eq!(**app.world().resource::<TelescopeHistory>(), vec![1, 2]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// All reaction state should be cleaned up after a complex nested reaction.
+#[test]
+fn tree_drained_after_nested_reactions()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TelescopeHistory>();
+    let world = app.world_mut();
+    let entity = world.spawn_empty().id();
+    world.syscall((entity, TestComponent(0)), insert_on_test_entity);
+
+    // reactor chain: broadcast -> entity event -> mutation -> system event -> despawn
+    let system_command = world.syscall((),
+        |mut c: Commands|
+        c.spawn_system_command(
+            |mut event: SystemEvent<Entity>, mut c: Commands, mut history: ResMut<TelescopeHistory>|
+            {
+                history.push(4);
+                c.entity(event.take().unwrap()).despawn();
+            }
+        )
+    );
+    world.syscall((entity, system_command),
+        |In((entity, system_command)): In<(Entity, SystemCommand)>, mut c: Commands|
+        {
+            c.react().on(broadcast::<IntEvent>(),
+                move |mut c: Commands, mut history: ResMut<TelescopeHistory>|
+                {
+                    history.push(1);
+                    c.react().entity_event(entity, ());
+                    c.react().entity_event(entity, ());
+                }
+            );
+            c.react().on(entity_event::<()>(entity),
+                |event: EntityEvent<()>, mut c: Commands, mut history: ResMut<TelescopeHistory>, mut q: ReactiveMut<TestComponent>|
+                {
+                    history.push(2);
+                    q.get_mut(&mut c, event.entity()).unwrap().0 += 1;
+                }
+            );
+            c.react().on(entity_mutation::<TestComponent>(entity),
+                move |event: MutationEvent<TestComponent>, mut c: Commands, mut history: ResMut<TelescopeHistory>, q: Reactive<TestComponent>|
+                {
+                    history.push(3);
+                    let entity = event.entity();
+                    if q.get(entity).unwrap().0 < 2 { return; }
+                    c.send_system_event(system_command, entity);
+                }
+            );
+            c.react().on(despawn(entity), |mut history: ResMut<TelescopeHistory>| history.push(5));
+        }
+    );
+
+    // run the chain
+    world.syscall(0usize, send_broadcast);
+    world.syscall((), schedule_removal_and_despawn_reactors);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1, 2, 3, 2, 3, 4, 5]);
+    assert_reaction_tree_drained(world);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+#[should_panic(expected = "a system command tree is running")]
+fn tree_not_drained_inside_reactor()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin);
+    let world = app.world_mut();
+
+    // check inside a reactor
+    world.syscall((),
+        |mut c: Commands|
+        {
+            c.react().on(broadcast::<()>(), |world: &mut World| assert_reaction_tree_drained(world));
+        }
+    );
+    world.broadcast(());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 // If reactions infinitely recurse then it will stack overflow.
 // #[test]
 // fn infinite_recursion()
@@ -200,3 +314,415 @@ fn cleanup_ordering()
 // }
 
 //-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Default)]
+struct TreeCount(usize);
+
+// Reactors accumulate results during a tree, and completion readers consume them in LIFO order when the tree ends.
+#[test]
+fn tree_result_accumulation()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TestReactRecorder>()
+        .init_resource::<TelescopeHistory>();
+    let world = app.world_mut();
+
+    // add reactors
+    world.react(|rc| rc.register_tree_result::<TreeCount>(|acc, count| acc.0 += count.0));
+    for _ in 0..3
+    {
+        world.react(|rc| rc.on(broadcast::<()>(), |mut c: Commands| c.react().accumulate(TreeCount(1))));
+    }
+    world.react(|rc| rc.on(broadcast::<IntEvent>(),
+        |mut c: Commands|
+        {
+            c.react().on_tree_complete(
+                |count: TreeCount, world: &mut World|
+                {
+                    world.resource_mut::<TelescopeHistory>().push(1);
+                    world.resource_mut::<TestReactRecorder>().0 += count.0;
+                }
+            );
+            c.react().broadcast(());
+            c.react().on_tree_complete(
+                |count: TreeCount, world: &mut World|
+                {
+                    world.resource_mut::<TelescopeHistory>().push(2);
+                    world.resource_mut::<TestReactRecorder>().0 += count.0 * 10;
+                }
+            );
+        }
+    ));
+
+    // run tree
+    world.syscall(0, send_broadcast);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 30);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![2, 1]);
+    assert_reaction_tree_drained(world);
+
+    // results are reset per tree
+    world.syscall(0, send_broadcast);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 60);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// A reactor that triggers itself multiple times reads the data of each deferred reaction when that reaction runs,
+// even when deferred reactions of the same reactor are nested.
+#[test]
+fn recursive_same_reactor_reads_in_order()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TelescopeHistory>();
+    let world = app.world_mut();
+
+    // add reactor
+    world.react(|rc| rc.on(broadcast::<usize>(),
+        |mut c: Commands, event: BroadcastEvent<usize>, mut history: ResMut<TelescopeHistory>|
+        {
+            let val = *event.read();
+            history.push(val);
+            match val
+            {
+                0 =>
+                {
+                    c.react().broadcast(1usize);
+                    c.react().broadcast(2usize);
+                    c.react().broadcast(3usize);
+                }
+                1 => c.react().broadcast(10usize),
+                _ => (),
+            }
+        }
+    ));
+
+    // outer event, then inner events in order (telescoping into the events sent by inner reactions)
+    world.broadcast(0usize);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![0, 1, 10, 2, 3]);
+    assert_reaction_tree_drained(world);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// Callbacks queued in the tree run in order with reactions scheduled around them.
+#[test]
+fn queue_in_tree_ordering()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TelescopeHistory>();
+    let world = app.world_mut();
+
+    // add reactors
+    world.react(|rc| rc.on(broadcast::<usize>(),
+        |event: BroadcastEvent<usize>, mut history: ResMut<TelescopeHistory>| history.push(*event.read())
+    ));
+    world.react(|rc| rc.on(broadcast::<IntEvent>(),
+        |mut c: Commands|
+        {
+            c.react().broadcast(1usize);
+            c.react().queue_in_tree(|world| world.resource_mut::<TelescopeHistory>().push(100));
+            c.react().broadcast(2usize);
+        }
+    ));
+
+    // the queued callback runs between the inner reactions
+    world.broadcast(IntEvent(0));
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1, 100, 2]);
+    assert_reaction_tree_drained(world);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Resource, Default)]
+struct ReactorErrors(Vec<String>);
+
+// Errors from fallible reactors are passed to the error handler without aborting the tree.
+#[test]
+fn fallible_reactor_error_handler()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TelescopeHistory>()
+        .init_resource::<ReactorErrors>();
+    let world = app.world_mut();
+
+    // add reactors
+    world.react(|rc| rc.on(broadcast::<usize>(),
+        |event: BroadcastEvent<usize>, mut history: ResMut<TelescopeHistory>| history.push(*event.read())
+    ));
+    world.react(|rc| rc.on_fallible(broadcast::<IntEvent>(),
+        |event: BroadcastEvent<IntEvent>, mut c: Commands| -> Result<(), String>
+        {
+            let event = event.read().0;
+            c.react().broadcast(event);
+            if event % 2 == 1 { return Err(format!("odd {event}")); }
+            Ok(())
+        },
+        |err: &String, world: &mut World| world.resource_mut::<ReactorErrors>().0.push(err.clone())
+    ));
+
+    // no error
+    world.broadcast(IntEvent(2));
+    assert!(world.resource::<ReactorErrors>().0.is_empty());
+
+    // error is recorded, and the reaction scheduled by the failing reactor still runs
+    world.broadcast(IntEvent(3));
+    assert_eq!(world.resource::<ReactorErrors>().0, vec![String::from("odd 3")]);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![2, 3]);
+    assert_reaction_tree_drained(world);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// Stepped trees run one reaction per step in telescoping order, and must be finished or aborted.
+#[test]
+fn stepped_tree_cascade()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TelescopeHistory>();
+    let world = app.world_mut();
+
+    // add reactors: IntEvent -> usize -> ()
+    let a = world.spawn_system_command(
+        |mut c: Commands, mut history: ResMut<TelescopeHistory>|
+        {
+            history.push(1);
+            c.react().broadcast(2usize);
+        }
+    );
+    let b = world.spawn_system_command(
+        |event: BroadcastEvent<usize>, mut c: Commands, mut history: ResMut<TelescopeHistory>|
+        {
+            history.push(*event.read());
+            c.react().broadcast(());
+        }
+    );
+    let c = world.spawn_system_command(
+        |mut history: ResMut<TelescopeHistory>| history.push(3)
+    );
+    world.react(|rc| rc.with(broadcast::<IntEvent>(), a, ReactorMode::Persistent));
+    world.react(|rc| rc.with(broadcast::<usize>(), b, ReactorMode::Persistent));
+    world.react(|rc| rc.with(broadcast::<()>(), c, ReactorMode::Persistent));
+
+    // nothing runs until stepped
+    let mut tree = world.begin_stepped_tree();
+    world.broadcast(IntEvent(0));
+    assert!(world.resource::<TelescopeHistory>().is_empty());
+    assert!(!tree.is_done(world));
+
+    // each step runs one reaction of the cascade
+    for (step, reactor) in [a, b, c].into_iter().enumerate()
+    {
+        let info = tree.step(world).unwrap();
+        assert_eq!(info.reactor, Some(reactor));
+        assert!(matches!(info.trigger, Some(ReactorType::Broadcast(_))));
+        assert_eq!(**world.resource::<TelescopeHistory>(), (1..=step + 1).collect::<Vec<_>>());
+    }
+    assert!(tree.step(world).is_none());
+    assert!(tree.is_done(world));
+    tree.finish(world);
+    assert_reaction_tree_drained(world);
+
+    // aborting discards the rest of the cascade
+    world.resource_mut::<TelescopeHistory>().clear();
+    let mut tree = world.begin_stepped_tree();
+    world.broadcast(IntEvent(0));
+    tree.step(world).unwrap();
+    tree.abort(world);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1]);
+    assert_reaction_tree_drained(world);
+
+    // reactions run normally after the stepped tree ends
+    world.resource_mut::<TelescopeHistory>().clear();
+    world.broadcast(IntEvent(0));
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1, 2, 3]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// Pipeline stages receive the previous stage's output within the same tree.
+#[test]
+fn two_stage_pipeline()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TelescopeHistory>();
+    let world = app.world_mut();
+
+    // add pipeline
+    let token = world.react(|rc| rc.pipeline()
+        .then(|event: BroadcastEvent<usize>| *event.read() * 10)
+        .then(|In(value): In<usize>, mut history: ResMut<TelescopeHistory>| history.push(value + 1))
+        .on(broadcast::<usize>())
+    );
+    let first = SystemCommand::from(token.clone());
+    let stages = world.get::<Children>(*first).unwrap().to_vec();
+    assert_eq!(stages.len(), 1);
+
+    // each trigger runs both stages before the tree ends
+    world.broadcast(1usize);
+    world.broadcast(2usize);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![11, 21]);
+    assert_reaction_tree_drained(world);
+
+    // revoking the pipeline despawns all stages
+    world.react(|rc| rc.revoke(token));
+    garbage_collect_entities(world);
+    assert!(world.get_entity(*first).is_err());
+    assert!(stages.iter().all(|stage| world.get_entity(*stage).is_err()));
+    world.broadcast(3usize);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![11, 21]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// A reactor can reschedule itself into the next tree without an external trigger.
+#[test]
+fn reactor_reschedules_next_tree()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TelescopeHistory>();
+    let world = app.world_mut();
+
+    // reactor reschedules itself until it has run three times
+    world.react(|rc| rc.on(broadcast::<usize>(),
+        |
+            mut c       : Commands,
+            event       : BroadcastEvent<usize>,
+            run_count   : ReactionRunCount,
+            mut history : ResMut<TelescopeHistory>
+        |
+        {
+            // rescheduled runs have no event data
+            history.push(event.try_read().copied().unwrap_or_default());
+            if run_count.get() < 3 { c.react().reschedule_current_next_tree(); }
+        }
+    ));
+    world.react(|rc| rc.on(broadcast::<()>(),
+        |mut history: ResMut<TelescopeHistory>| history.push(100)
+    ));
+
+    // the rescheduled run waits for the next tree
+    world.broadcast(1usize);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1]);
+
+    // the next tree runs the rescheduled reactor first
+    world.broadcast(());
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1, 0, 100]);
+    assert_reaction_tree_drained(world);
+
+    // without another tree, the rescheduled reactor runs in First
+    app.update();
+    assert_eq!(**app.world().resource::<TelescopeHistory>(), vec![1, 0, 100, 0]);
+
+    // the reactor stopped rescheduling itself
+    app.update();
+    app.update();
+    assert_eq!(**app.world().resource::<TelescopeHistory>(), vec![1, 0, 100, 0]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Component)]
+struct CascadeStage(usize);
+
+// Settling repeats detection passes until reactions stop cascading.
+#[test]
+fn settle_multi_stage_cascade()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin);
+    let world = app.world_mut();
+
+    // each detected change advances the stage, which is detected in the next pass
+    world.react(|rc| rc.on(bevy_component_changed::<CascadeStage>(),
+        |event: BevyComponentEvent<CascadeStage>, mut stages: Query<&mut CascadeStage>|
+        {
+            let mut stage = stages.get_mut(event.entity()).unwrap();
+            if stage.0 < 3 { stage.0 += 1; }
+        }
+    ));
+    assert_eq!(world.settle_reactions(10), 0);
+
+    // passes: 0 -> 1, 1 -> 2, 2 -> 3, 3 (no change)
+    let entity = world.spawn(CascadeStage(0)).id();
+    assert_eq!(world.settle_reactions(10), 4);
+    assert_eq!(world.get::<CascadeStage>(entity).unwrap().0, 3);
+    assert_eq!(world.settle_reactions(10), 0);
+    assert_reaction_tree_drained(world);
+
+    // hitting the cap leaves the cascade unfinished
+    let entity = world.spawn(CascadeStage(0)).id();
+    assert_eq!(world.settle_reactions(2), 2);
+    assert_eq!(world.get::<CascadeStage>(entity).unwrap().0, 2);
+    assert_eq!(world.settle_reactions(10), 2);
+    assert_eq!(world.get::<CascadeStage>(entity).unwrap().0, 3);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// Continuations run after the reactions to the events a reactor sent.
+#[test]
+fn then_after_queued_interleaves_reactions()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TelescopeHistory>();
+    let world = app.world_mut();
+
+    // add reactors
+    world.react(|rc| rc.on(broadcast::<usize>(),
+        |event: BroadcastEvent<usize>, mut history: ResMut<TelescopeHistory>|
+        {
+            history.push(*event.read());
+        }
+    ));
+    world.react(|rc| rc.on(broadcast::<u8>(),
+        |mut c: Commands, mut history: ResMut<TelescopeHistory>|
+        {
+            for i in 1..=3 { c.react().broadcast(i as usize); }
+            history.push(99);
+        }
+    ));
+    world.react(|rc| rc.on(broadcast::<u16>(),
+        |mut c: Commands|
+        {
+            for i in 1..=3 { c.react().broadcast(i as usize); }
+            c.react().then_after_queued(|mut history: ResMut<TelescopeHistory>| history.push(99));
+        }
+    ));
+
+    // without a continuation, the reactor's work happens before the reactions it triggered
+    world.broadcast(0u8);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![99, 1, 2, 3]);
+
+    // with a continuation, it runs after the reactions
+    world.resource_mut::<TelescopeHistory>().clear();
+    world.broadcast(0u16);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1, 2, 3, 99]);
+
+    // a reactor that always queues a continuation terminates, and the continuation is cleaned up
+    let entity_count = world.entities().len();
+    world.resource_mut::<TelescopeHistory>().clear();
+    world.broadcast(0u16);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1, 2, 3, 99]);
+    garbage_collect_entities(world);
+    assert_eq!(world.entities().len(), entity_count);
+    assert_reaction_tree_drained(world);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -6,6 +6,8 @@ use crate::*;
 use bevy::prelude::*;
 
 //standard shortcuts
+use std::any::TypeId;
+use std::sync::{Arc, Mutex};
 
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
@@ -31,6 +33,26 @@ fn on_broadcast_or_resource(mut c: Commands) -> RevokeToken
         update_test_recorder_with_broadcast_and_resource)
 }
 
+fn race_broadcast_and_resource(mut c: Commands) -> RevokeToken
+{
+    c.react().once_race((broadcast::<IntEvent>(), resource_mutation::<TestReactRes>()),
+        |winner: WinningTrigger, event: BroadcastEvent<IntEvent>, mut history: ResMut<TelescopeHistory>|
+        {
+            if winner.is(broadcast::<IntEvent>())
+            {
+                assert!(!winner.is(resource_mutation::<TestReactRes>()));
+                history.push(event.read().0);
+            }
+            else
+            {
+                assert!(winner.is(resource_mutation::<TestReactRes>()));
+                assert!(event.is_empty());
+                history.push(0);
+            }
+        }
+    )
+}
+
 fn on_resource_mutation(mut c: Commands) -> RevokeToken
 {
     c.react().on_revokable(resource_mutation::<TestReactRes>(), update_test_recorder_with_resource)
@@ -286,3 +308,91 @@ fn revoke_multiple_reactors()
 }
 
 //-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn once_race_broadcast_vs_resource()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .insert_react_resource(TestReactRes::default())
+        .init_resource::<TelescopeHistory>();
+    let world = app.world_mut();
+
+    // resource mutation wins
+    let token = world.syscall((), race_broadcast_and_resource);
+    world.syscall(1, update_react_res);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![0]);
+
+    // the losing trigger was revoked
+    world.syscall(222, send_broadcast);
+    world.syscall(1, update_react_res);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![0]);
+    garbage_collect_entities(world);
+    assert!(world.get_entity(*SystemCommand::from(token)).is_err());
+
+    // broadcast wins
+    world.syscall((), race_broadcast_and_resource);
+    world.syscall(222, send_broadcast);
+    world.syscall(1, update_react_res);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![0, 222]);
+    assert_reaction_tree_drained(world);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn reactor_triggers_lists_all_triggers()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .insert_react_resource(TestReactRes::default());
+    let world = app.world_mut();
+    let entity = world.spawn_empty().id();
+
+    // reactor records its triggers and which one fired
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let seen_clone = seen.clone();
+    let token = world.react(|rc| rc.on_revokable(
+        (
+            broadcast::<IntEvent>(),
+            resource_mutation::<TestReactRes>(),
+            entity_mutation::<TestComponent>(entity),
+            despawn(entity),
+        ),
+        move |triggers: ReactorTriggers, winner: WinningTrigger|
+        {
+            let mut triggers = triggers.get();
+            triggers.sort_by_key(|t| format!("{t:?}"));
+            seen_clone.lock().unwrap().push((winner.get(), triggers));
+        }
+    ));
+
+    // the full set of triggers is visible regardless of which trigger fired
+    let mut expected = vec![
+        ReactorType::Broadcast(TypeId::of::<IntEvent>()),
+        ReactorType::ResourceMutation(TypeId::of::<TestReactRes>()),
+        ReactorType::EntityMutation(entity, TypeId::of::<TestComponent>()),
+        ReactorType::Despawn(entity),
+    ];
+    expected.sort_by_key(|t| format!("{t:?}"));
+
+    world.syscall(1, send_broadcast);
+    world.syscall(1, update_react_res);
+    let seen_runs = seen.lock().unwrap().clone();
+    assert_eq!(seen_runs.len(), 2);
+    assert_eq!(seen_runs[0].0, Some(ReactorType::Broadcast(TypeId::of::<IntEvent>())));
+    assert_eq!(seen_runs[1].0, Some(ReactorType::ResourceMutation(TypeId::of::<TestReactRes>())));
+    assert_eq!(seen_runs[0].1, expected);
+    assert_eq!(seen_runs[1].1, expected);
+
+    // the set is empty outside reactors
+    let outside = world.syscall((), |triggers: ReactorTriggers| triggers.get());
+    assert!(outside.is_empty());
+
+    world.react(|rc| rc.revoke(token));
+    assert_reaction_tree_drained(world);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
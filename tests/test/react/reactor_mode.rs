@@ -6,7 +6,7 @@ use crate::*;
 use bevy::prelude::*;
 
 //standard shortcuts
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::sync::atomic::{AtomicU32, Ordering};
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -463,3 +463,600 @@ fn revokable_reactor_dies_when_revoked_with_multiple_tokens()
 }
 
 //-------------------------------------------------------------------------------------------------------------------
+
+// tagged: all reactors with a tag are revoked together and cleaned up
+#[test]
+fn tagged_reactors_revoked_by_tag()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .insert_react_resource(TestReactRes::default())
+        .init_resource::<TestReactRecorder>();
+    let world = app.world_mut();
+
+    // prep target entity
+    let target = world.spawn_empty().id();
+    world.syscall((target, TestComponent(0)), insert_on_test_entity);
+
+    // register reactors
+    world.syscall((),
+        move |mut c: Commands|
+        {
+            c.react().on_tagged("group", resource_mutation::<TestReactRes>(),
+                |mut recorder: ResMut<TestReactRecorder>| { recorder.0 += 1; }
+            );
+            c.react().on_tagged("group", entity_mutation::<TestComponent>(target),
+                |mut recorder: ResMut<TestReactRecorder>| { recorder.0 += 10; }
+            );
+            c.react().on_tagged(ReactorTag::of::<TestComponent>(), resource_mutation::<TestReactRes>(),
+                |mut recorder: ResMut<TestReactRecorder>| { recorder.0 += 100; }
+            );
+        }
+    );
+    let count_tagged = |world: &mut World| world.query::<&ReactorTag>().iter(world).count();
+    assert_eq!(count_tagged(world), 3);
+
+    // trigger reactors
+    world.syscall(1, update_react_res);
+    world.syscall((target, TestComponent(1)), update_test_entity);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 111);
+
+    // revoke by tag
+    world.react(|rc| rc.revoke_tagged("group"));
+    garbage_collect_entities(world);
+    assert_eq!(count_tagged(world), 1);
+
+    // only the reactor with a different tag runs
+    world.syscall(2, update_react_res);
+    world.syscall((target, TestComponent(2)), update_test_entity);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 211);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// Dropping a reactor guard revokes the reactor.
+#[test]
+fn scoped_reactor_dropped_with_guard()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .insert_react_resource(TestReactRes::default())
+        .init_resource::<TestReactRecorder>();
+    let world = app.world_mut();
+
+    // prep target entity
+    let target = world.spawn_empty().id();
+    world.syscall((target, TestComponent(0)), insert_on_test_entity);
+
+    // register reactor
+    let guard = world.react(|rc| rc.on_scoped_handle(
+        (resource_mutation::<TestReactRes>(), entity_mutation::<TestComponent>(target)),
+        |mut recorder: ResMut<TestReactRecorder>| { recorder.0 += 1; }
+    ));
+    let reactor = guard.id();
+
+    // reactor runs while the guard is alive
+    world.syscall(1, update_react_res);
+    world.syscall((target, TestComponent(1)), update_test_entity);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 2);
+    assert_eq!(reactors_targeting(world, target), vec![reactor]);
+
+    // dropping the guard despawns the reactor and revokes its triggers
+    drop(guard);
+    garbage_collect_entities(world);
+    assert!(world.get_entity(*reactor).is_err());
+    assert!(reactors_targeting(world, target).is_empty());
+
+    world.syscall(2, update_react_res);
+    world.syscall((target, TestComponent(2)), update_test_entity);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 2);
+    assert_reaction_tree_drained(world);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// Keyed reactors can be looked up, run, and replaced by key.
+#[test]
+fn keyed_reactor_lookup_and_replace()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .insert_react_resource(TestReactRes::default())
+        .init_resource::<TestReactRecorder>();
+    let world = app.world_mut();
+
+    // register reactors (keys of different types are different keys)
+    world.react(|rc| rc.register_keyed_reactor("counter", resource_mutation::<TestReactRes>(),
+        |mut recorder: ResMut<TestReactRecorder>| { recorder.0 += 1; }
+    ));
+    world.react(|rc| rc.register_keyed_reactor(1u32, broadcast::<()>(),
+        |mut recorder: ResMut<TestReactRecorder>| { recorder.0 += 10; }
+    ));
+    let reactor = world.resource::<KeyedReactors>().get(&"counter").unwrap();
+    assert!(world.resource::<KeyedReactors>().get(&1u32).is_some());
+    assert!(world.resource::<KeyedReactors>().get(&1u64).is_none());
+    assert!(world.resource::<KeyedReactors>().get(&"other").is_none());
+
+    // trigger and run by key
+    world.syscall(1, update_react_res);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 1);
+    world.react(|rc| rc.run_keyed_reactor("counter"));
+    assert_eq!(world.resource::<TestReactRecorder>().0, 2);
+    world.react(|rc| rc.run_keyed_reactor(1u32));
+    assert_eq!(world.resource::<TestReactRecorder>().0, 12);
+
+    // missing key (no effect)
+    world.react(|rc| rc.run_keyed_reactor(1u64));
+    assert_eq!(world.resource::<TestReactRecorder>().0, 12);
+
+    // re-register (old reactor revoked)
+    world.react(|rc| rc.register_keyed_reactor("counter", resource_mutation::<TestReactRes>(),
+        |mut recorder: ResMut<TestReactRecorder>| { recorder.0 += 100; }
+    ));
+    garbage_collect_entities(world);
+    assert!(world.get_entity(*reactor).is_err());
+    assert_ne!(world.resource::<KeyedReactors>().get(&"counter"), Some(reactor));
+
+    world.syscall(2, update_react_res);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 112);
+    world.react(|rc| rc.run_keyed_reactor("counter"));
+    assert_eq!(world.resource::<TestReactRecorder>().0, 212);
+    assert_reaction_tree_drained(world);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// Raw reactors can implement custom run/cleanup/revoke logic.
+#[test]
+fn raw_reactor_runs_twice_then_revokes()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TelescopeHistory>();
+    let world = app.world_mut();
+
+    // register a reactor that revokes itself after its second run
+    let token_slot: Arc<Mutex<Option<RevokeToken>>> = Arc::new(Mutex::new(None));
+    let token_slot_inner = token_slot.clone();
+    let mut system = RawCallbackSystem::new(
+        |event: BroadcastEvent<usize>, mut history: ResMut<TelescopeHistory>| history.push(*event.read())
+    );
+    let mut runs = 0;
+    let token = world.react(|rc| rc.on_raw(broadcast::<usize>(),
+        move |world: &mut World, cleanup: SystemCommandCleanup|
+        {
+            system.run_with_cleanup(world, (), move |w| cleanup.run(w));
+            runs += 1;
+            if runs < 2 { return; }
+            let Some(token) = token_slot_inner.lock().unwrap().take() else { return; };
+            world.react(|rc| rc.revoke(token));
+        },
+        ReactorMode::Revokable
+    )).unwrap();
+    let reactor = *SystemCommand::from(token.clone());
+    *token_slot.lock().unwrap() = Some(token);
+
+    // the reactor runs twice then is revoked
+    world.broadcast(1usize);
+    world.broadcast(2usize);
+    world.broadcast(3usize);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1, 2]);
+
+    garbage_collect_entities(world);
+    assert!(world.get_entity(reactor).is_err());
+    assert_reaction_tree_drained(world);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Records reactor initialization in the [`TelescopeHistory`] when used as a `Local`.
+struct InitProbe;
+
+impl FromWorld for InitProbe
+{
+    fn from_world(world: &mut World) -> Self
+    {
+        world.resource_mut::<TelescopeHistory>().push(0);
+        Self
+    }
+}
+
+// eager: reactor is initialized at registration instead of on the first trigger
+#[test]
+fn eager_reactor_initialized_at_registration()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TelescopeHistory>();
+    let world = app.world_mut();
+
+    // lazy reactor isn't initialized until it runs
+    let token = world.react(|rc| rc.on_revokable(broadcast::<()>(),
+        |_: Local<InitProbe>, mut history: ResMut<TelescopeHistory>| history.push(1)
+    ));
+    assert_eq!(**world.resource::<TelescopeHistory>(), Vec::<usize>::new());
+    world.broadcast(());
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![0, 1]);
+    world.react(|rc| rc.revoke(token));
+    world.resource_mut::<TelescopeHistory>().clear();
+
+    // eager reactor is initialized when registered
+    let token = world.react(|rc| rc.on_eager(broadcast::<()>(),
+        |_: Local<InitProbe>, mut history: ResMut<TelescopeHistory>| history.push(1)
+    ));
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![0]);
+
+    // the first trigger only runs the reactor
+    world.broadcast(());
+    world.broadcast(());
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![0, 1, 1]);
+
+    // revoking cleans up the reactor
+    world.react(|rc| rc.revoke(token.clone()));
+    garbage_collect_entities(world);
+    assert!(world.get_entity(*SystemCommand::from(token)).is_err());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// owned: all reactors owned by an entity are revoked when it despawns
+#[test]
+fn owned_reactors_revoked_on_owner_despawn()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TelescopeHistory>();
+    let world = app.world_mut();
+    let owner = world.spawn_empty().id();
+    let other = world.spawn_empty().id();
+
+    // owned reactors with triggers unrelated to the owner
+    let token_a = world.react(|rc| rc.on_owned_by(owner, broadcast::<()>(),
+        |mut history: ResMut<TelescopeHistory>| history.push(1)
+    ));
+    let token_b = world.react(|rc| rc.on_owned_by(owner, broadcast::<()>(),
+        |mut history: ResMut<TelescopeHistory>| history.push(2)
+    ));
+    let token_c = world.react(|rc| rc.on_owned_by(other, broadcast::<()>(),
+        |mut history: ResMut<TelescopeHistory>| history.push(3)
+    ));
+    world.broadcast(());
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1, 2, 3]);
+
+    // despawning the owner revokes its reactors without running them
+    world.despawn(owner);
+    schedule_removal_and_despawn_reactors(world);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1, 2, 3]);
+    world.broadcast(());
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1, 2, 3, 3]);
+
+    // owned reactors are cleaned up
+    garbage_collect_entities(world);
+    assert!(world.get_entity(*SystemCommand::from(token_a)).is_err());
+    assert!(world.get_entity(*SystemCommand::from(token_b)).is_err());
+    assert!(world.get_entity(*SystemCommand::from(token_c)).is_ok());
+    assert_reaction_tree_drained(world);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// scoped: all reactors scoped to an entity are revoked when it despawns
+#[test]
+fn scoped_reactors_revoked_on_parent_despawn()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TelescopeHistory>();
+    let world = app.world_mut();
+    let parent = world.spawn_empty().id();
+    let other = world.spawn_empty().id();
+
+    // scoped reactors with triggers unrelated to the parent
+    let token_a = world.react(|rc| rc.on_scoped(parent, broadcast::<()>(),
+        |mut history: ResMut<TelescopeHistory>| history.push(1)
+    ));
+    let token_b = world.react(|rc| rc.on_scoped(parent, broadcast::<()>(),
+        |mut history: ResMut<TelescopeHistory>| history.push(2)
+    ));
+    let token_c = world.react(|rc| rc.on_scoped(other, broadcast::<()>(),
+        |mut history: ResMut<TelescopeHistory>| history.push(3)
+    ));
+    world.broadcast(());
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1, 2, 3]);
+
+    // despawning the parent revokes its reactors without running them
+    world.despawn(parent);
+    schedule_removal_and_despawn_reactors(world);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1, 2, 3]);
+    world.broadcast(());
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1, 2, 3, 3]);
+
+    // scoped reactors are cleaned up
+    garbage_collect_entities(world);
+    assert!(world.get_entity(*SystemCommand::from(token_a)).is_err());
+    assert!(world.get_entity(*SystemCommand::from(token_b)).is_err());
+    assert!(world.get_entity(*SystemCommand::from(token_c)).is_ok());
+    assert_reaction_tree_drained(world);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// scoped: reactors scoped to a parent share one despawn reactor and are revoked when the parent despawns
+#[test]
+fn scoped_reactors_share_parent_despawn_reactor()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .insert_react_resource(TestReactRes::default())
+        .init_resource::<TelescopeHistory>();
+    let world = app.world_mut();
+    let parent = world.spawn_empty().id();
+
+    // scope broadcast and resource reactors to the parent
+    let token_a = world.react(|rc| rc.on_scoped(parent, broadcast::<()>(),
+        |mut history: ResMut<TelescopeHistory>| history.push(1)
+    ));
+    let token_b = world.react(|rc| rc.on_scoped(parent, resource_mutation::<TestReactRes>(),
+        |mut history: ResMut<TelescopeHistory>| history.push(2)
+    ));
+    let despawn_reactors = world.syscall(parent,
+        |In(parent): In<Entity>, diagnostics: ReactDiagnostics|
+        diagnostics.reactor_count_for(ReactorType::Despawn(parent))
+    );
+    assert_eq!(despawn_reactors, 1);
+    world.broadcast(());
+    world.syscall((), |mut c: Commands, mut res: ReactResMut<TestReactRes>| { res.get_mut(&mut c).0 += 1; });
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1, 2]);
+
+    // despawning the parent revokes the scoped reactors without running them
+    world.despawn(parent);
+    schedule_removal_and_despawn_reactors(world);
+    world.broadcast(());
+    world.syscall((), |mut c: Commands, mut res: ReactResMut<TestReactRes>| { res.get_mut(&mut c).0 += 1; });
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1, 2]);
+
+    // scoped reactors are cleaned up
+    garbage_collect_entities(world);
+    assert!(world.get_entity(*SystemCommand::from(token_a)).is_err());
+    assert!(world.get_entity(*SystemCommand::from(token_b)).is_err());
+    let counts = world.syscall((), |diagnostics: ReactDiagnostics| diagnostics.reactor_counts());
+    assert_eq!(counts.total(), 0);
+    assert_reaction_tree_drained(world);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// scoped: revoking all scoped reactors early also revokes the parent's shared despawn reactor
+#[test]
+fn scoped_reactors_revoked_early_release_parent_despawn_reactor()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TelescopeHistory>();
+    let world = app.world_mut();
+    let parent = world.spawn_empty().id();
+    let despawn_reactor_count = |world: &mut World| world.syscall(parent,
+        |In(parent): In<Entity>, diagnostics: ReactDiagnostics|
+        diagnostics.reactor_count_for(ReactorType::Despawn(parent))
+    );
+
+    // scope two reactors to the parent
+    let token_a = world.react(|rc| rc.on_scoped(parent, broadcast::<()>(),
+        |mut history: ResMut<TelescopeHistory>| history.push(1)
+    ));
+    let token_b = world.react(|rc| rc.on_scoped(parent, broadcast::<()>(),
+        |mut history: ResMut<TelescopeHistory>| history.push(2)
+    ));
+    assert_eq!(despawn_reactor_count(world), 1);
+
+    // revoking one reactor keeps the despawn reactor for the other
+    world.react(|rc| rc.revoke(token_a.clone()));
+    assert_eq!(despawn_reactor_count(world), 1);
+    world.broadcast(());
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![2]);
+
+    // revoking the last reactor revokes the despawn reactor
+    world.react(|rc| rc.revoke(token_b.clone()));
+    assert_eq!(despawn_reactor_count(world), 0);
+
+    // everything is cleaned up while the parent is still alive
+    garbage_collect_entities(world);
+    assert!(world.get_entity(parent).is_ok());
+    assert!(world.get_entity(*SystemCommand::from(token_a)).is_err());
+    assert!(world.get_entity(*SystemCommand::from(token_b)).is_err());
+    let counts = world.syscall((), |diagnostics: ReactDiagnostics| diagnostics.reactor_counts());
+    assert_eq!(counts.total(), 0);
+    assert_reaction_tree_drained(world);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// scoped: reactors scoped to a missing parent are revoked immediately
+#[test]
+fn scoped_reactor_revoked_if_parent_missing()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TelescopeHistory>();
+    let world = app.world_mut();
+    let parent = world.spawn_empty().id();
+    world.despawn(parent);
+
+    // the reactor never runs
+    let token = world.react(|rc| rc.on_scoped(parent, broadcast::<()>(),
+        |mut history: ResMut<TelescopeHistory>| history.push(1)
+    ));
+    world.broadcast(());
+    assert!(world.resource::<TelescopeHistory>().is_empty());
+
+    // the reactor is cleaned up
+    garbage_collect_entities(world);
+    assert!(world.get_entity(*SystemCommand::from(token)).is_err());
+    let counts = world.syscall((), |diagnostics: ReactDiagnostics| diagnostics.reactor_counts());
+    assert_eq!(counts.total(), 0);
+    assert_reaction_tree_drained(world);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// Cleanup one-shot reactors run once and despawn without a revoke token, and their triggers are dropped when they
+// next fire.
+#[test]
+fn once_cleanup_runs_once_and_unregisters()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .insert_react_resource(TestReactRes::default())
+        .init_resource::<TelescopeHistory>();
+    let world = app.world_mut();
+
+    // register a one-shot reactor on two triggers
+    world.react(|rc| rc.once_cleanup((broadcast::<()>(), resource_mutation::<TestReactRes>()),
+        |mut history: ResMut<TelescopeHistory>| history.push(1)
+    ));
+    let counts = world.syscall((), |diagnostics: ReactDiagnostics| diagnostics.reactor_counts());
+    assert_eq!(counts.total(), 2);
+
+    // the reactor only runs on the first trigger
+    world.broadcast(());
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1]);
+    let counts = world.syscall((), |diagnostics: ReactDiagnostics| diagnostics.reactor_counts());
+    assert_eq!(counts.total(), 2);
+    world.broadcast(());
+    world.syscall((), |mut c: Commands, mut res: ReactResMut<TestReactRes>| { res.get_mut(&mut c).0 += 1; });
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1]);
+
+    // the triggers were dropped when they fired
+    let counts = world.syscall((), |diagnostics: ReactDiagnostics| diagnostics.reactor_counts());
+    assert_eq!(counts.total(), 0);
+    assert_reaction_tree_drained(world);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// boxed: reactors can be registered from boxed systems
+#[test]
+fn boxed_reactor_runs_on_trigger()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TelescopeHistory>();
+    let world = app.world_mut();
+
+    // make a boxed system at runtime
+    let make_reactor = |value: usize| -> bevy::ecs::system::BoxedSystem<(), ()>
+    {
+        Box::new(IntoSystem::into_system(
+            move |mut count: Local<usize>, mut history: ResMut<TelescopeHistory>|
+            {
+                *count += 1;
+                history.push(value + *count);
+            }
+        ))
+    };
+    let token = world.react(|rc| rc.on_boxed(broadcast::<()>(), make_reactor(10), ReactorMode::Revokable)).unwrap();
+    world.react(|rc| rc.on_boxed(broadcast::<()>(), make_reactor(20), ReactorMode::Cleanup));
+
+    // the boxed reactors keep their state between runs
+    world.broadcast(());
+    world.broadcast(());
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![11, 21, 12, 22]);
+
+    // revoking works like normal reactors
+    world.react(|rc| rc.revoke(token));
+    world.broadcast(());
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![11, 21, 12, 22, 23]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// Reactors can revoke themselves with revoke_current.
+#[test]
+fn revoke_current_from_reactor()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TelescopeHistory>();
+    let world = app.world_mut();
+    let entity = world.spawn_empty().id();
+
+    // a persistent reactor that revokes itself on its second run
+    let reactor = world.react(|rc| rc.on_persistent((broadcast::<()>(), entity_event::<()>(entity)),
+        |mut c: Commands, mut history: ResMut<TelescopeHistory>|
+        {
+            history.push(1);
+            if history.len() == 2 { c.react().revoke_current(); }
+        }
+    ));
+    world.broadcast(());
+    world.entity_event(entity, ());
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1, 1]);
+
+    // the reactor no longer runs
+    world.broadcast(());
+    world.entity_event(entity, ());
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1, 1]);
+    assert!(world.get_entity(*reactor).is_err());
+    assert!(reactors_targeting(world, entity).is_empty());
+
+    // revoking outside a reactor does nothing
+    world.react(|rc| rc.revoke_current());
+    assert_reaction_tree_drained(world);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// exclusive: reactors with world access apply their commands before the next reactor runs
+#[test]
+fn exclusive_reactor_commands_trigger_reactions()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TelescopeHistory>();
+    let world = app.world_mut();
+
+    // exclusive reactor spawns an entity with a reactive component
+    let token = world.react(|rc| rc.on_exclusive(broadcast::<()>(),
+        |world: &mut World|
+        {
+            let entity = world.spawn_empty().id();
+            world.commands().react().insert(entity, TestComponent(0));
+            world.resource_mut::<TelescopeHistory>().push(1);
+        },
+        ReactorMode::Revokable
+    )).unwrap();
+    world.react(|rc| rc.on_persistent(insertion::<TestComponent>(),
+        |mut history: ResMut<TelescopeHistory>| history.push(2)
+    ));
+    world.react(|rc| rc.on_persistent(broadcast::<()>(),
+        |mut history: ResMut<TelescopeHistory>| history.push(3)
+    ));
+
+    // insertion reactions run before the next broadcast reactor
+    world.broadcast(());
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1, 2, 3]);
+    let mut query = world.query::<&React<TestComponent>>();
+    assert_eq!(query.iter(world).count(), 1);
+
+    // revoke
+    world.react(|rc| rc.revoke(token));
+    world.broadcast(());
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1, 2, 3, 3]);
+    assert_reaction_tree_drained(world);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
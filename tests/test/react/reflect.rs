@@ -0,0 +1,97 @@
+//local shortcuts
+use bevy_cobweb::prelude::*;
+use crate::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+
+//standard shortcuts
+use std::any::TypeId;
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(ReactComponent, Reflect, Default)]
+#[reflect(ReactComponent)]
+struct ReflectedComponent(usize);
+
+#[derive(ReactResource, Reflect, Default)]
+#[reflect(ReactResource)]
+struct ReflectedResource(usize);
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+// Reflected edits to react components trigger mutation reactions.
+#[test]
+fn reflect_component_apply()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TelescopeHistory>()
+        .register_type::<ReflectedComponent>();
+    let world = app.world_mut();
+    let entity = world.spawn_empty().id();
+    world.react(|rc| rc.insert(entity, ReflectedComponent(1)));
+
+    // add reactor
+    world.react(|rc| rc.on(entity_mutation::<ReflectedComponent>(entity),
+        |event: MutationEvent<ReflectedComponent>, values: Reactive<ReflectedComponent>,
+            mut history: ResMut<TelescopeHistory>|
+        {
+            history.push(values.get(event.entity()).unwrap().0);
+        }
+    ));
+
+    // look up the type data
+    let type_data = world.resource::<AppTypeRegistry>()
+        .read()
+        .get_type_data::<ReflectReactComponent>(TypeId::of::<ReflectedComponent>())
+        .unwrap()
+        .clone();
+    let reflected = type_data.reflect(world, entity).unwrap();
+    assert_eq!(reflected.downcast_ref::<ReflectedComponent>().unwrap().0, 1);
+
+    // apply an edit
+    assert!(type_data.apply(world, entity, &ReflectedComponent(5)));
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![5]);
+
+    // mismatched values and missing components are rejected
+    assert!(!type_data.apply(world, entity, &String::from("6")));
+    let empty = world.spawn_empty().id();
+    assert!(!type_data.apply(world, empty, &ReflectedComponent(7)));
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![5]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// Reflected edits to react resources trigger mutation reactions.
+#[test]
+fn reflect_resource_apply()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TelescopeHistory>()
+        .init_react_resource::<ReflectedResource>()
+        .register_type::<ReflectedResource>();
+    let world = app.world_mut();
+
+    // add reactor
+    world.react(|rc| rc.on(resource_mutation::<ReflectedResource>(),
+        |resource: ReactRes<ReflectedResource>, mut history: ResMut<TelescopeHistory>| history.push(resource.0)
+    ));
+
+    // apply an edit
+    let type_data = world.resource::<AppTypeRegistry>()
+        .read()
+        .get_type_data::<ReflectReactResource>(TypeId::of::<ReflectedResource>())
+        .unwrap()
+        .clone();
+    assert!(type_data.apply(world, &ReflectedResource(3)));
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![3]);
+    assert_eq!(type_data.reflect(world).unwrap().downcast_ref::<ReflectedResource>().unwrap().0, 3);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
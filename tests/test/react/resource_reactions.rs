@@ -6,6 +6,55 @@ use crate::*;
 use bevy::prelude::*;
 
 //standard shortcuts
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(ReactResource, Default)]
+struct TestMapRes(HashMap<usize, usize>);
+
+#[derive(Resource, Default)]
+struct TestPlainRes(usize);
+
+#[derive(ReactResource, Default)]
+struct TestSourceA(usize);
+
+#[derive(ReactResource, Default)]
+struct TestSourceB(usize);
+
+#[derive(ReactResource, Default, PartialEq, Debug)]
+struct TestSum(usize);
+
+#[derive(ReactResource, Default)]
+struct TestConfig
+{
+    speed: usize,
+}
+
+#[derive(ReactComponent, PartialEq, Debug)]
+struct TestSpeed(usize);
+
+#[derive(ReactResource, Default)]
+struct TestGate(bool);
+
+#[derive(ReactResource, Default)]
+struct TestPair
+{
+    a: usize,
+    b: usize,
+}
+
+#[derive(ReactResource, Default)]
+#[react(track_fields)]
+struct TestTracked
+{
+    a: usize,
+    b: usize,
+    c: usize,
+}
 
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
@@ -104,3 +153,598 @@ fn revoke_once_reactor()
 }
 
 //-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn resource_modify_conditional_reaction()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_react_resource::<TestMapRes>()
+        .init_resource::<TestReactRecorder>();
+    let world = app.world_mut();
+
+    // add reactor
+    world.syscall((),
+        |mut c: Commands|
+        {
+            c.react().on(resource_mutation::<TestMapRes>(),
+                |mut recorder: ResMut<TestReactRecorder>, map: ReactRes<TestMapRes>|
+                {
+                    recorder.0 = map.0.len();
+                }
+            );
+        }
+    );
+    let insert = |In((key, value)): In<(usize, usize)>, mut c: Commands, mut map: ReactResMut<TestMapRes>| -> bool
+    {
+        map.modify(&mut c, |map| map.0.insert(key, value) != Some(value))
+    };
+
+    // insert new entry (reaction)
+    assert!(world.syscall((1, 1), insert));
+    assert_eq!(world.resource::<TestReactRecorder>().0, 1);
+
+    // insert same entry (no reaction)
+    world.resource_mut::<TestReactRecorder>().0 = 0;
+    assert!(!world.syscall((1, 1), insert));
+    assert_eq!(world.resource::<TestReactRecorder>().0, 0);
+
+    // change entry (reaction)
+    assert!(world.syscall((1, 2), insert));
+    assert_eq!(world.resource::<TestReactRecorder>().0, 1);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn bevy_resource_changed_reactions()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TestReactRecorder>();
+    app.update();
+
+    // add reactor (resource doesn't exist yet)
+    let token = app.world_mut().syscall((),
+        |mut c: Commands| -> RevokeToken
+        {
+            c.react().on_revokable(bevy_resource_changed::<TestPlainRes>(),
+                |mut recorder: ResMut<TestReactRecorder>, res: Res<TestPlainRes>|
+                {
+                    recorder.0 += res.0;
+                }
+            )
+        }
+    );
+    app.update();
+    assert_eq!(app.world().resource::<TestReactRecorder>().0, 0);
+
+    // insert resource (reaction)
+    app.world_mut().insert_resource(TestPlainRes(1));
+    app.update();
+    assert_eq!(app.world().resource::<TestReactRecorder>().0, 1);
+
+    // no change (no reaction)
+    app.update();
+    assert_eq!(app.world().resource::<TestReactRecorder>().0, 1);
+
+    // change resource multiple times in one frame (one reaction)
+    app.world_mut().resource_mut::<TestPlainRes>().0 = 10;
+    app.world_mut().resource_mut::<TestPlainRes>().0 = 20;
+    app.update();
+    assert_eq!(app.world().resource::<TestReactRecorder>().0, 21);
+
+    // revoke reactor
+    app.world_mut().syscall(token, revoke_reactor);
+    app.world_mut().resource_mut::<TestPlainRes>().0 = 100;
+    app.update();
+    assert_eq!(app.world().resource::<TestReactRecorder>().0, 21);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn resource_get_mut_read_only()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .insert_react_resource(TestReactRes::default())
+        .init_resource::<TestReactRecorder>();
+    let world = app.world_mut();
+
+    // count mutation reactions
+    world.react(|rc| rc.on(resource_mutation::<TestReactRes>(), |mut recorder: ResMut<TestReactRecorder>| recorder.0 += 1));
+
+    // read through the mutable handle (no reaction)
+    let val = world.syscall((), |mut c: Commands, mut res: ReactResMut<TestReactRes>| res.get_mut(&mut c).0);
+    assert_eq!(val, 0);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 0);
+
+    // write through the mutable handle (reaction)
+    world.syscall((), |mut c: Commands, mut res: ReactResMut<TestReactRes>| res.get_mut(&mut c).0 = 1);
+    assert_eq!(world.react_resource::<TestReactRes>().0, 1);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 1);
+
+    // read then write multiple times through one handle (one reaction)
+    world.syscall((), |mut c: Commands, mut res: ReactResMut<TestReactRes>|
+    {
+        let mut guard = res.get_mut(&mut c);
+        let val = guard.0;
+        guard.0 = val + 1;
+        guard.0 += 1;
+    });
+    assert_eq!(world.react_resource::<TestReactRes>().0, 3);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 2);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn resource_change_guard_batches_reactions()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .insert_react_resource(TestPair::default())
+        .init_resource::<TestReactRecorder>();
+    let world = app.world_mut();
+
+    // count mutation reactions
+    world.react(|rc| rc.on(resource_mutation::<TestPair>(), |mut recorder: ResMut<TestReactRecorder>| recorder.0 += 1));
+
+    // mutate two fields through one guard (one reaction)
+    world.syscall((), |mut c: Commands, mut res: ReactResMut<TestPair>|
+    {
+        let mut rc = c.react();
+        let mut guard = res.begin_change(&mut rc);
+        guard.a = 1;
+        guard.b = 2;
+        drop(guard);
+    });
+    assert_eq!(world.react_resource::<TestPair>().a, 1);
+    assert_eq!(world.react_resource::<TestPair>().b, 2);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 1);
+
+    // read through the guard (no reaction)
+    let sum = world.syscall((), |mut c: Commands, mut res: ReactResMut<TestPair>|
+    {
+        let mut rc = c.react();
+        let guard = res.begin_change(&mut rc);
+        guard.a + guard.b
+    });
+    assert_eq!(sum, 3);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 1);
+
+    // forget the guard (no reaction)
+    world.syscall((), |mut c: Commands, mut res: ReactResMut<TestPair>|
+    {
+        let mut rc = c.react();
+        let mut guard = res.begin_change(&mut rc);
+        guard.a = 10;
+        std::mem::forget(guard);
+    });
+    assert_eq!(world.react_resource::<TestPair>().a, 10);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 1);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn derived_resource_sum()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_react_resource::<TestSourceA>()
+        .init_react_resource::<TestSourceB>()
+        .init_react_resource::<TestSum>()
+        .init_resource::<TestReactRecorder>();
+    let world = app.world_mut();
+
+    // derive sum and count its mutations
+    let token = world.react(|rc| rc.derive_resource(
+        (resource_mutation::<TestSourceA>(), resource_mutation::<TestSourceB>()),
+        |a: ReactRes<TestSourceA>, b: ReactRes<TestSourceB>| TestSum(a.0 + b.0)
+    ));
+    world.react(|rc| rc.on(resource_mutation::<TestSum>(), |mut recorder: ResMut<TestReactRecorder>| recorder.0 += 1));
+
+    // mutate sources (recompute)
+    world.syscall((), |mut c: Commands, mut a: ReactResMut<TestSourceA>| a.get_mut(&mut c).0 = 1);
+    assert_eq!(*world.react_resource::<TestSum>(), TestSum(1));
+    assert_eq!(world.resource::<TestReactRecorder>().0, 1);
+    world.syscall((), |mut c: Commands, mut b: ReactResMut<TestSourceB>| b.get_mut(&mut c).0 = 2);
+    assert_eq!(*world.react_resource::<TestSum>(), TestSum(3));
+    assert_eq!(world.resource::<TestReactRecorder>().0, 2);
+
+    // mutation that doesn't change the sum (no sum reaction)
+    world.syscall((), |mut c: Commands, mut a: ReactResMut<TestSourceA>| a.get_mut(&mut c).0 = 1);
+    assert_eq!(*world.react_resource::<TestSum>(), TestSum(3));
+    assert_eq!(world.resource::<TestReactRecorder>().0, 2);
+
+    // revoke (no recompute)
+    world.syscall(token, revoke_reactor);
+    world.syscall((), |mut c: Commands, mut a: ReactResMut<TestSourceA>| a.get_mut(&mut c).0 = 10);
+    assert_eq!(*world.react_resource::<TestSum>(), TestSum(3));
+    assert_eq!(world.resource::<TestReactRecorder>().0, 2);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn component_bound_to_config()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_react_resource::<TestConfig>()
+        .init_resource::<TestReactRecorder>();
+    let world = app.world_mut();
+
+    let entity = world.spawn_empty().id();
+    world.syscall((), move |mut c: Commands| c.react().insert(entity, TestSpeed(0)));
+
+    // bind the component and count extractions and component mutations
+    let extractions = Arc::new(AtomicU32::new(0u32));
+    let extractions_inner = extractions.clone();
+    world.react(|rc| rc.bind_component_to_config(entity,
+        move |config: &TestConfig|
+        {
+            extractions_inner.fetch_add(1, Ordering::Relaxed);
+            TestSpeed(config.speed)
+        }
+    ));
+    world.react(|rc| rc.on(entity_mutation::<TestSpeed>(entity),
+        |mut recorder: ResMut<TestReactRecorder>| recorder.0 += 1
+    ));
+
+    // mutate config (component updated)
+    world.syscall((), |mut c: Commands, mut config: ReactResMut<TestConfig>| config.get_mut(&mut c).speed = 5);
+    assert_eq!(*world.get::<React<TestSpeed>>(entity).unwrap().get(), TestSpeed(5));
+    assert_eq!(extractions.load(Ordering::Relaxed), 1);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 1);
+
+    // mutation that doesn't change the extracted value (no component reaction)
+    world.syscall((), |mut c: Commands, mut config: ReactResMut<TestConfig>| config.get_mut(&mut c).speed = 5);
+    assert_eq!(extractions.load(Ordering::Relaxed), 2);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 1);
+
+    // despawn the entity (binding revoked)
+    world.despawn(entity);
+    schedule_removal_and_despawn_reactors(world);
+    world.syscall((), |mut c: Commands, mut config: ReactResMut<TestConfig>| config.get_mut(&mut c).speed = 7);
+    assert_eq!(extractions.load(Ordering::Relaxed), 2);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn world_trigger_resource_mutation()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .insert_react_resource(TestReactRes(5))
+        .init_resource::<TestReactRecorder>();
+    let world = app.world_mut();
+
+    // add reactor
+    world.syscall((), on_resource_mutation);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 0);
+
+    // trigger without mutating (reaction)
+    world.trigger_resource_mutation::<TestReactRes>();
+    assert_eq!(world.resource::<TestReactRecorder>().0, 5);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn gated_reactor_runs_only_when_enabled()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TestReactRecorder>();
+    let world = app.world_mut();
+
+    // add reactor
+    world.react(|rc| rc.on_gated(|gate: &TestGate| gate.0, broadcast::<IntEvent>(),
+        |mut recorder: ResMut<TestReactRecorder>| recorder.0 += 1
+    ));
+
+    // gate missing (no reaction)
+    world.broadcast(IntEvent(0));
+    assert_eq!(world.resource::<TestReactRecorder>().0, 0);
+
+    // gate off (no reaction)
+    world.insert_react_resource(TestGate(false));
+    world.broadcast(IntEvent(0));
+    assert_eq!(world.resource::<TestReactRecorder>().0, 0);
+    assert_reaction_tree_drained(world);
+
+    // gate on (reactions)
+    world.react_resource_mut_noreact::<TestGate>().0 = true;
+    world.broadcast(IntEvent(0));
+    world.broadcast(IntEvent(0));
+    assert_eq!(world.resource::<TestReactRecorder>().0, 2);
+
+    // gate off again (no reaction)
+    world.react_resource_mut_noreact::<TestGate>().0 = false;
+    world.broadcast(IntEvent(0));
+    assert_eq!(world.resource::<TestReactRecorder>().0, 2);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn resource_field_mutation_mask()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TelescopeHistory>()
+        .insert_react_resource(TestTracked::default());
+    let world = app.world_mut();
+    assert_eq!(TestTrackedFields::ALL, TestTracked::all_fields());
+    assert_eq!(TestTrackedFields::ALL.bits(), 0b111);
+
+    // add reactor
+    world.react(|rc| rc.on(resource_mutation::<TestTracked>(),
+        |event: ResourceMutationEvent<TestTracked>, mut history: ResMut<TelescopeHistory>|
+        {
+            history.push(event.fields().bits() as usize);
+        }
+    ));
+
+    // mutate one field
+    world.syscall((),
+        |mut c: Commands, mut tracked: ReactResMut<TestTracked>|
+        {
+            tracked.get_field_mut(&mut c, TestTrackedFields::B).b += 1;
+        }
+    );
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![0b010]);
+    assert_eq!(world.react_resource::<TestTracked>().b, 1);
+
+    // mutations before the reactions are scheduled are coalesced
+    world.syscall((),
+        |mut c: Commands, mut tracked: ReactResMut<TestTracked>|
+        {
+            tracked.get_field_mut(&mut c, TestTrackedFields::A).a += 1;
+            tracked.get_field_mut(&mut c, TestTrackedFields::C).c += 1;
+        }
+    );
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![0b010, 0b101]);
+
+    // untracked mutations report all fields
+    world.syscall((),
+        |mut c: Commands, mut tracked: ReactResMut<TestTracked>|
+        {
+            tracked.get_mut(&mut c).a += 1;
+        }
+    );
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![0b010, 0b101, 0b111]);
+    assert_reaction_tree_drained(world);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn resource_mutation_source_tags()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TelescopeHistory>()
+        .insert_react_resource(TestReactRes::default());
+    let world = app.world_mut();
+
+    // add reactor
+    world.react(|rc| rc.on(resource_mutation::<TestReactRes>(),
+        |source: ResourceMutationSource<TestReactRes>, mut history: ResMut<TelescopeHistory>|
+        {
+            let id = match source.tag()
+            {
+                Some(ChangeTag("input")) => 1,
+                Some(ChangeTag("network")) => 2,
+                _ => 0,
+            };
+            history.push(id);
+        }
+    ));
+
+    // tagged mutations
+    world.syscall(ChangeTag("input"),
+        |In(tag): In<ChangeTag>, mut c: Commands, mut res: ReactResMut<TestReactRes>|
+        {
+            res.get_mut_tagged(&mut c.react(), tag).0 += 1;
+        }
+    );
+    world.syscall(ChangeTag("network"),
+        |In(tag): In<ChangeTag>, mut c: Commands, mut res: ReactResMut<TestReactRes>|
+        {
+            res.get_mut_tagged(&mut c.react(), tag).0 += 1;
+        }
+    );
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1, 2]);
+    assert_eq!(world.react_resource::<TestReactRes>().0, 2);
+
+    // untagged mutation
+    world.syscall((),
+        |mut c: Commands, mut res: ReactResMut<TestReactRes>|
+        {
+            res.get_mut(&mut c).0 += 1;
+        }
+    );
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1, 2, 0]);
+    assert_reaction_tree_drained(world);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(ReactResource, Default, Clone)]
+struct TestBalanceA(usize);
+
+#[derive(ReactResource, Default, Clone)]
+struct TestBalanceB(usize);
+
+// Transactions only apply changes and trigger reactions if they succeed.
+#[test]
+fn resource_transaction_commit_and_rollback()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TelescopeHistory>()
+        .insert_react_resource(TestBalanceA(10))
+        .insert_react_resource(TestBalanceB(0));
+    let world = app.world_mut();
+
+    // add reactor that records the sum of both balances
+    world.react(|rc| rc.on((resource_mutation::<TestBalanceA>(), resource_mutation::<TestBalanceB>()),
+        |a: ReactRes<TestBalanceA>, b: ReactRes<TestBalanceB>, mut history: ResMut<TelescopeHistory>|
+        {
+            history.push(a.0 + b.0);
+        }
+    ));
+
+    // failed transfer: nothing is applied
+    let result = world.transaction(|ctx| {
+        ctx.get_mut::<TestBalanceA>().unwrap().0 -= 5;
+        ctx.get_mut::<TestBalanceB>().unwrap().0 += 5;
+        assert_eq!(ctx.get::<TestBalanceA>().unwrap().0, 5);
+        Err("rejected")
+    });
+    assert_eq!(result, Err("rejected"));
+    assert_eq!(world.react_resource::<TestBalanceA>().0, 10);
+    assert_eq!(world.react_resource::<TestBalanceB>().0, 0);
+    assert_eq!(**world.resource::<TelescopeHistory>(), Vec::<usize>::new());
+
+    // successful transfer: reactors see both changes
+    let result: Result<(), ()> = world.transaction(|ctx| {
+        ctx.get_mut::<TestBalanceA>().unwrap().0 -= 3;
+        ctx.get_mut::<TestBalanceB>().unwrap().0 += 3;
+        Ok(())
+    });
+    assert_eq!(result, Ok(()));
+    assert_eq!(world.react_resource::<TestBalanceA>().0, 7);
+    assert_eq!(world.react_resource::<TestBalanceB>().0, 3);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![10, 10]);
+    assert_reaction_tree_drained(world);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(ReactResource, Default, PartialEq, Clone)]
+struct TestChangedRes(usize);
+
+fn set_changed_res(In(value): In<usize>, mut c: Commands, mut res: ReactResMut<TestChangedRes>)
+{
+    res.get_mut(&mut c).0 = value;
+}
+
+// Resource change reactors only run when a mutation changes the resource's value.
+#[test]
+fn resource_value_changed_skips_equal_values()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .insert_react_resource(TestChangedRes(0))
+        .init_resource::<TelescopeHistory>();
+    let world = app.world_mut();
+
+    // add reactors
+    world.react(|rc| rc.on_persistent(resource_value_changed::<TestChangedRes>(),
+        |res: ReactRes<TestChangedRes>, mut history: ResMut<TelescopeHistory>| history.push(res.0)
+    ));
+    world.react(|rc| rc.on_persistent(resource_mutation::<TestChangedRes>(),
+        |res: ReactRes<TestChangedRes>, mut history: ResMut<TelescopeHistory>| history.push(res.0 * 10)
+    ));
+
+    // mutating to the same value only runs mutation reactors
+    world.syscall(0, set_changed_res);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![0]);
+
+    // mutating to a new value runs both
+    world.syscall(1, set_changed_res);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![0, 1, 10]);
+    world.syscall(1, set_changed_res);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![0, 1, 10, 10]);
+
+    // the snapshot follows the latest value
+    world.syscall(0, set_changed_res);
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![0, 1, 10, 10, 0, 0]);
+    assert_reaction_tree_drained(world);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// A resource initialized with a reactor triggers the reactor once on startup.
+#[test]
+fn init_react_resource_with_reactor_triggers_on_startup()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TestPlainRes>()
+        .init_react_resource_with_reactor(TestSourceA(5),
+            |source: ReactRes<TestSourceA>, mut sum: ResMut<TestPlainRes>| sum.0 += source.0
+        );
+    assert_eq!(app.world().resource::<TestPlainRes>().0, 0);
+
+    // the reactor runs once on startup
+    app.update();
+    assert_eq!(app.world().resource::<TestPlainRes>().0, 5);
+    app.update();
+    assert_eq!(app.world().resource::<TestPlainRes>().0, 5);
+
+    // the reactor runs on mutations
+    app.world_mut().syscall((),
+        |mut c: Commands, mut source: ReactResMut<TestSourceA>| source.get_mut(&mut c).0 = 1
+    );
+    assert_eq!(app.world().resource::<TestPlainRes>().0, 6);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// Reactors waiting for a resource buffer early triggers until the resource is inserted.
+#[test]
+fn when_ready_reactor_replays_early_triggers()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TelescopeHistory>();
+    let world = app.world_mut();
+
+    // reactors that read the resource
+    world.react(|rc| rc.on_when_ready::<TestSourceA, _, _>(broadcast::<()>(),
+        |source: ReactRes<TestSourceA>, mut history: ResMut<TelescopeHistory>| history.push(source.0)
+    ));
+    world.react(|rc| rc.on_when_ready_with::<TestSourceA, _, _>(EarlyTriggers::ReplayOnce, broadcast::<()>(),
+        |source: ReactRes<TestSourceA>, mut history: ResMut<TelescopeHistory>| history.push(source.0 + 10)
+    ));
+    world.react(|rc| rc.on_when_ready_with::<TestSourceA, _, _>(EarlyTriggers::Drop, broadcast::<()>(),
+        |source: ReactRes<TestSourceA>, mut history: ResMut<TelescopeHistory>| history.push(source.0 + 20)
+    ));
+
+    // triggers before the resource exists are buffered
+    world.broadcast(());
+    world.broadcast(());
+    assert_eq!(**world.resource::<TelescopeHistory>(), Vec::<usize>::new());
+
+    // inserting the resource replays buffered triggers
+    world.react(|rc| rc.commands().insert_react_resource(TestSourceA(1)));
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1, 11, 1]);
+
+    // later triggers run normally
+    world.broadcast(());
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![1, 11, 1, 1, 11, 21]);
+    assert_reaction_tree_drained(world);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
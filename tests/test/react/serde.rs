@@ -0,0 +1,125 @@
+//local shortcuts
+use bevy_cobweb::prelude::*;
+use crate::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+//standard shortcuts
+
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(ReactComponent, Serialize, Deserialize, Default, Debug, PartialEq)]
+struct NetPosition
+{
+    x: usize,
+    y: usize,
+}
+
+#[derive(ReactResource, Serialize, Deserialize, Default, Debug, PartialEq)]
+struct NetScore(usize);
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn make_app() -> App
+{
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TelescopeHistory>();
+    app
+}
+
+fn record_position(world: &mut World, entity: Entity)
+{
+    world.react(|rc| rc.on((entity_insertion::<NetPosition>(entity), entity_mutation::<NetPosition>(entity)),
+        move |values: Reactive<NetPosition>, mut history: ResMut<TelescopeHistory>|
+        {
+            let position = values.get(entity).unwrap();
+            history.push(position.x * 10 + position.y);
+        }
+    ));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// Serialized react components applied on another world trigger reactions there.
+#[test]
+fn serde_component_round_trip()
+{
+    // sender
+    let mut sender = make_app();
+    let sender_world = sender.world_mut();
+    let source = sender_world.spawn_empty().id();
+    sender_world.react(|rc| rc.insert(source, NetPosition{ x: 1, y: 2 }));
+    let bytes = serialize_react::<NetPosition>(sender_world, source).unwrap();
+    let empty = sender_world.spawn_empty().id();
+    assert!(serialize_react::<NetPosition>(sender_world, empty).is_err());
+
+    // receiver: missing component is inserted (insertion reaction)
+    let mut receiver = make_app();
+    let world = receiver.world_mut();
+    let target = world.spawn_empty().id();
+    record_position(world, target);
+    world.react(|rc| rc.apply_serialized::<NetPosition>(target, &bytes)).unwrap();
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![12]);
+
+    // receiver: existing component is mutated (mutation reaction)
+    let sender_world = sender.world_mut();
+    sender_world.syscall((), move |mut c: Commands, mut values: ReactiveMut<NetPosition>|
+    {
+        values.get_mut(&mut c, source).unwrap().y = 3;
+    });
+    let bytes = serialize_react::<NetPosition>(sender_world, source).unwrap();
+    let world = receiver.world_mut();
+    world.react(|rc| rc.apply_serialized::<NetPosition>(target, &bytes)).unwrap();
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![12, 13]);
+    assert_eq!(*world.get::<React<NetPosition>>(target).unwrap().get(), NetPosition{ x: 1, y: 3 });
+
+    // unknown entities are ignored
+    let missing = world.spawn_empty().id();
+    world.despawn(missing);
+    world.react(|rc| rc.apply_serialized::<NetPosition>(missing, &bytes)).unwrap();
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![12, 13]);
+
+    // bad bytes are rejected
+    assert!(world.react(|rc| rc.apply_serialized::<NetPosition>(target, b"not json")).is_err());
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![12, 13]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// Serialized react resources applied on another world trigger reactions there.
+#[test]
+fn serde_resource_round_trip()
+{
+    // sender
+    let mut sender = make_app();
+    let sender_world = sender.world_mut();
+    assert!(serialize_react_resource::<NetScore>(sender_world).is_err());
+    sender_world.insert_react_resource(NetScore(7));
+    let bytes = serialize_react_resource::<NetScore>(sender_world).unwrap();
+
+    // receiver: missing resource is inserted
+    let mut receiver = make_app();
+    let world = receiver.world_mut();
+    world.react(|rc| rc.on(resource_mutation::<NetScore>(),
+        |score: ReactRes<NetScore>, mut history: ResMut<TelescopeHistory>| history.push(score.0)
+    ));
+    world.react(|rc| rc.apply_serialized_resource::<NetScore>(&bytes)).unwrap();
+    assert_eq!(*world.react_resource::<NetScore>(), NetScore(7));
+    assert!(world.resource::<TelescopeHistory>().is_empty());
+
+    // receiver: existing resource is mutated (mutation reaction)
+    let sender_world = sender.world_mut();
+    sender_world.react_resource_mut_noreact::<NetScore>().0 = 9;
+    let bytes = serialize_react_resource::<NetScore>(sender_world).unwrap();
+    let world = receiver.world_mut();
+    world.react(|rc| rc.apply_serialized_resource::<NetScore>(&bytes)).unwrap();
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![9]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,282 @@
+//local shortcuts
+use bevy_cobweb::prelude::*;
+use crate::*;
+
+//third-party shortcuts
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+//standard shortcuts
+
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+const NUM_SEEDS: u64 = 16;
+const NUM_ENTITIES: usize = 4;
+const NUM_REACTORS: usize = 24;
+const NUM_ACTIONS: usize = 40;
+const RECURSION_BUDGET: usize = 16;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Seeded xorshift RNG so failures can be reproduced from the seed.
+#[derive(Resource)]
+struct StressRng(u64);
+
+impl StressRng
+{
+    fn new(seed: u64) -> Self
+    {
+        // Mix the seed so small seeds don't produce similar streams.
+        Self(seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1)
+    }
+
+    fn next(&mut self) -> u64
+    {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: usize) -> usize
+    {
+        (self.next() % n as u64) as usize
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Number of reactors registered for each trigger.
+#[derive(Resource, Default)]
+struct StressRegistry
+{
+    entities   : Vec<Entity>,
+    broadcast  : usize,
+    resource   : usize,
+    event      : HashMap<Entity, usize>,
+    mutation   : HashMap<Entity, usize>,
+}
+
+/// Expected and observed reactions.
+#[derive(Resource, Default)]
+struct StressLog
+{
+    expected : usize,
+    received : usize,
+}
+
+/// Number of actions reactors may still trigger in the current tree.
+#[derive(Resource, Default)]
+struct StressBudget(usize);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Copy, Clone)]
+enum StressReactor
+{
+    Broadcast,
+    Resource,
+    Event(Entity),
+    Mutation(Entity),
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(SystemParam)]
+struct StressState<'w, 's>
+{
+    c          : Commands<'w, 's>,
+    rng        : ResMut<'w, StressRng>,
+    registry   : Res<'w, StressRegistry>,
+    log        : ResMut<'w, StressLog>,
+    budget     : ResMut<'w, StressBudget>,
+    components : ReactiveMut<'w, 's, TestComponent>,
+    resource   : ReactResMut<'w, TestReactRes>,
+}
+
+impl StressState<'_, '_>
+{
+    /// Fires a random trigger and records how many reactions it should cause.
+    fn random_action(&mut self)
+    {
+        let registry = &self.registry;
+        let entity = registry.entities[self.rng.below(registry.entities.len())];
+        match self.rng.below(4)
+        {
+            0 =>
+            {
+                self.log.expected += registry.broadcast;
+                self.c.react().broadcast(IntEvent(usize::MAX));
+            }
+            1 =>
+            {
+                self.log.expected += registry.resource;
+                self.resource.get_mut(&mut self.c).0 += 1;
+            }
+            2 =>
+            {
+                self.log.expected += registry.event.get(&entity).copied().unwrap_or_default();
+                self.c.react().entity_event(entity, IntEvent(entity.index() as usize));
+            }
+            _ =>
+            {
+                self.log.expected += registry.mutation.get(&entity).copied().unwrap_or_default();
+                self.components.get_mut(&mut self.c, entity).unwrap().0 += 1;
+            }
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Top-level action that starts a new tree of system commands.
+fn stress_action(mut state: StressState)
+{
+    state.budget.0 = RECURSION_BUDGET;
+    state.random_action();
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Shared reactor body: records the reaction and possibly triggers a nested action.
+fn stress_react(In(recursive): In<bool>, mut state: StressState)
+{
+    state.log.received += 1;
+    if !recursive || state.budget.0 == 0 || state.rng.below(2) == 0 { return; }
+    state.budget.0 -= 1;
+    state.random_action();
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn register_stress_reactor(world: &mut World, reactor: StressReactor, recursive: bool)
+{
+    match reactor
+    {
+        StressReactor::Broadcast =>
+        {
+            world.resource_mut::<StressRegistry>().broadcast += 1;
+            world.react(|rc| rc.on(broadcast::<IntEvent>(),
+                (move |broadcast: BroadcastEvent<IntEvent>, entity_event: EntityEvent<IntEvent>|
+                {
+                    // Events are only visible to the reactors they target.
+                    assert_eq!(broadcast.read().0, usize::MAX);
+                    assert!(entity_event.is_empty());
+                    recursive
+                }).pipe(stress_react)
+            ));
+        }
+        StressReactor::Resource =>
+        {
+            world.resource_mut::<StressRegistry>().resource += 1;
+            world.react(|rc| rc.on(resource_mutation::<TestReactRes>(),
+                (move |broadcast: BroadcastEvent<IntEvent>, entity_event: EntityEvent<IntEvent>|
+                {
+                    assert!(broadcast.is_empty());
+                    assert!(entity_event.is_empty());
+                    recursive
+                }).pipe(stress_react)
+            ));
+        }
+        StressReactor::Event(target) =>
+        {
+            *world.resource_mut::<StressRegistry>().event.entry(target).or_default() += 1;
+            world.react(|rc| rc.on(entity_event::<IntEvent>(target),
+                (move |broadcast: BroadcastEvent<IntEvent>, entity_event: EntityEvent<IntEvent>|
+                {
+                    assert!(broadcast.is_empty());
+                    let (entity, event) = entity_event.read();
+                    assert_eq!(entity, target);
+                    assert_eq!(event.0, target.index() as usize);
+                    recursive
+                }).pipe(stress_react)
+            ));
+        }
+        StressReactor::Mutation(target) =>
+        {
+            *world.resource_mut::<StressRegistry>().mutation.entry(target).or_default() += 1;
+            world.react(|rc| rc.on(entity_mutation::<TestComponent>(target),
+                (move |event: MutationEvent<TestComponent>, broadcast: BroadcastEvent<IntEvent>|
+                {
+                    assert_eq!(event.entity(), target);
+                    assert!(broadcast.is_empty());
+                    recursive
+                }).pipe(stress_react)
+            ));
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn run_stress_seed(seed: u64)
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .insert_resource(StressRng::new(seed))
+        .init_resource::<StressRegistry>()
+        .init_resource::<StressLog>()
+        .init_resource::<StressBudget>()
+        .insert_react_resource(TestReactRes::default());
+    let world = app.world_mut();
+
+    for _ in 0..NUM_ENTITIES
+    {
+        let entity = world.spawn_empty().id();
+        world.syscall((entity, TestComponent(0)), insert_on_test_entity);
+        world.resource_mut::<StressRegistry>().entities.push(entity);
+    }
+
+    // register a random mix of reactors
+    for _ in 0..NUM_REACTORS
+    {
+        let mut rng = world.resource_mut::<StressRng>();
+        let entity_idx = rng.below(NUM_ENTITIES);
+        let kind = rng.below(4);
+        let recursive = rng.below(3) == 0;
+        let entity = world.resource::<StressRegistry>().entities[entity_idx];
+        let reactor = match kind
+        {
+            0 => StressReactor::Broadcast,
+            1 => StressReactor::Resource,
+            2 => StressReactor::Event(entity),
+            _ => StressReactor::Mutation(entity),
+        };
+        register_stress_reactor(world, reactor, recursive);
+    }
+
+    // fire random triggers
+    for action in 0..NUM_ACTIONS
+    {
+        world.syscall((), stress_action);
+
+        // Every expected reaction ran exactly once, and all reaction data was cleaned up after its last reader.
+        let log = world.resource::<StressLog>();
+        assert_eq!(log.expected, log.received, "seed {seed}, action {action}");
+        assert_reaction_tree_drained(world);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+// Randomized mix of reactors and triggers (including recursive reactors). Failures report the seed.
+//
+// Invariants:
+// - Each trigger runs every reactor registered for it exactly once.
+// - Broadcast and entity events are only visible to the reactors they target.
+// - After each tree, no reaction data or reaction state is left over (see `assert_reaction_tree_drained`).
+#[test]
+fn telescoping_stress()
+{
+    for seed in 0..NUM_SEEDS
+    {
+        run_stress_seed(seed);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
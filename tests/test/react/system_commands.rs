@@ -151,3 +151,80 @@ fn system_command_recursion()
 }
 
 //-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn system_command_self_reschedules()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TestReactRecorder>();
+    let world = app.world_mut();
+
+    // reactor that reschedules itself until it has run three times
+    let command = world.spawn_system_command_self(
+        |command: SystemCommand, world: &mut World, cleanup: SystemCommandCleanup|
+        {
+            cleanup.run(world);
+            let mut recorder = world.resource_mut::<TestReactRecorder>();
+            recorder.0 += 1;
+            if recorder.0.is_multiple_of(3) { return; }
+            world.commands().queue(command);
+        }
+    );
+    world.react(|rc| rc.with(broadcast::<()>(), command, ReactorMode::Persistent));
+
+    // broadcast (reactor runs three times)
+    world.broadcast(());
+    assert_eq!(world.resource::<TestReactRecorder>().0, 3);
+
+    // broadcast again
+    world.broadcast(());
+    assert_eq!(world.resource::<TestReactRecorder>().0, 6);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn reactor_run_count()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TelescopeHistory>();
+    let world = app.world_mut();
+
+    // reactor that does something different on its first run
+    let token = world.react(|rc| rc.on_revokable(broadcast::<()>(),
+        |run_count: ReactionRunCount, mut history: ResMut<TelescopeHistory>|
+        {
+            if run_count.is_first() { history.push(100); }
+            history.push(run_count.get() as usize);
+        }
+    ));
+
+    // nested reactors see their own counts
+    world.react(|rc| rc.on(broadcast::<usize>(),
+        |mut c: Commands, run_count: ReactionRunCount|
+        {
+            assert_eq!(run_count.get(), 1);
+            c.react().broadcast(());
+        }
+    ));
+
+    world.broadcast(());
+    world.broadcast(0usize);
+    world.broadcast(());
+    assert_eq!(**world.resource::<TelescopeHistory>(), vec![100, 1, 2, 3]);
+    let command = SystemCommand::from(token);
+    let total = world.syscall(command,
+        |In(command): In<SystemCommand>, run_count: ReactionRunCount| run_count.get_for(command)
+    );
+    assert_eq!(total, 3);
+
+    // outside system commands
+    assert_eq!(world.syscall((), |run_count: ReactionRunCount| run_count.get()), 0);
+    assert_reaction_tree_drained(world);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,100 @@
+//local shortcuts
+use bevy_cobweb::prelude::*;
+use crate::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+
+//standard shortcuts
+use std::time::Duration;
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+const INTERVAL: Duration = Duration::from_millis(100);
+
+fn register_throttled_reactor(world: &mut World, throttle: Throttle) -> RevokeToken
+{
+    world.react(|rc| rc.on_throttled(throttle, broadcast::<IntEvent>(),
+        |mut history: ResMut<TelescopeHistory>| history.push(0)
+    ))
+}
+
+fn advance(world: &mut World, duration: Duration)
+{
+    world.resource_mut::<Time>().advance_by(duration);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// Leading throttles run on the first trigger of each interval and drop the rest.
+#[test]
+fn throttled_reactor_leading()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TelescopeHistory>();
+    let world = app.world_mut();
+    register_throttled_reactor(world, Throttle::leading(INTERVAL));
+
+    // rapid triggers: only the first runs
+    for _ in 0..5
+    {
+        world.broadcast(IntEvent(0));
+        advance(world, Duration::from_millis(10));
+    }
+    assert_eq!(world.resource::<TelescopeHistory>().len(), 1);
+    assert_reaction_tree_drained(world);
+
+    // the interval elapsed: the next trigger runs
+    advance(world, INTERVAL);
+    world.broadcast(IntEvent(0));
+    world.broadcast(IntEvent(0));
+    assert_eq!(world.resource::<TelescopeHistory>().len(), 2);
+
+    // no trailing run
+    app.update();
+    assert_eq!(app.world().resource::<TelescopeHistory>().len(), 2);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// Trailing throttles collapse triggers into one run once the interval elapses.
+#[test]
+fn throttled_reactor_trailing()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TelescopeHistory>();
+    let world = app.world_mut();
+    register_throttled_reactor(world, Throttle::trailing(INTERVAL));
+
+    // rapid triggers: nothing runs until the next update
+    for _ in 0..5
+    {
+        world.broadcast(IntEvent(0));
+    }
+    assert_eq!(world.resource::<TelescopeHistory>().len(), 0);
+    app.update();
+    assert_eq!(app.world().resource::<TelescopeHistory>().len(), 1);
+
+    // more triggers: the trailing run waits for the interval
+    let world = app.world_mut();
+    world.broadcast(IntEvent(0));
+    world.broadcast(IntEvent(0));
+    advance(world, Duration::from_millis(50));
+    app.update();
+    assert_eq!(app.world().resource::<TelescopeHistory>().len(), 1);
+    advance(app.world_mut(), INTERVAL);
+    app.update();
+    assert_eq!(app.world().resource::<TelescopeHistory>().len(), 2);
+
+    // no triggers: no runs
+    advance(app.world_mut(), INTERVAL);
+    app.update();
+    assert_eq!(app.world().resource::<TelescopeHistory>().len(), 2);
+}
+
+//-------------------------------------------------------------------------------------------------------------------